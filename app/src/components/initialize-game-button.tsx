@@ -2,6 +2,7 @@ import { useState } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { Button } from "./ui/button";
 import { useProgram } from "@/hooks/useProgram";
+import { GAME_ID } from "@/lib/constants";
 
 export const InitializeGameButton = () => {
   const { publicKey } = useWallet();
@@ -18,7 +19,7 @@ export const InitializeGameButton = () => {
       const latestBlockHash = await connection.getLatestBlockhash();
 
       const signature = await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: publicKey,
         })
@@ -2,6 +2,7 @@ import { useState } from "react";
 import { useConnection, useWallet } from "@solana/wallet-adapter-react";
 import { Button } from "./ui/button";
 import { useProgram } from "@/hooks/useProgram";
+import { GAME_ID } from "@/lib/constants";
 import { useBlockrunners } from "@/hooks/useBlockrunners";
 
 export const JoinGameButton = () => {
@@ -22,7 +23,7 @@ export const JoinGameButton = () => {
       const latestBlockHash = await connection.getLatestBlockhash();
 
       const signature = await program.methods
-        .joinGame()
+        .joinGame(GAME_ID)
         .accounts({
           player: publicKey,
         })
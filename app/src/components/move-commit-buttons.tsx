@@ -9,6 +9,7 @@ import * as sb from "@switchboard-xyz/on-demand";
 import { PathDirection } from "@/lib/types";
 import { Keypair, Transaction } from "@solana/web3.js";
 import { setupQueue } from "@/lib/utils";
+import { GAME_ID } from "@/lib/constants";
 
 interface MoveCommitButtonsProps {
   nextMoveCost: number;
@@ -65,7 +66,7 @@ export const MoveCommitButtons = ({
 
         // Create the move commit instruction with mock randomness account
         const blockrunnersRequestIx = await program.methods
-          .moveCommit(direction, cardUsage)
+          .moveCommit(GAME_ID, direction, cardUsage)
           .accounts({
             player: publicKey,
             randomnessAccount: rngKeypair.publicKey,
@@ -155,7 +156,7 @@ export const MoveCommitButtons = ({
 
       // Blockrunners program request instruction
       const blockrunnersRequestIx = await program.methods
-        .moveCommit(direction, cardUsage)
+        .moveCommit(GAME_ID, direction, cardUsage)
         .accounts({
           player: publicKey,
           randomnessAccount: randomnessAccount.pubkey,
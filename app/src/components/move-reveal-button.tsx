@@ -5,6 +5,7 @@ import { useProgram, useSwitchboardProgramPromise } from "@/hooks/useProgram";
 import { useBlockrunners } from "@/hooks/useBlockrunners";
 import * as sb from "@switchboard-xyz/on-demand";
 import { Transaction } from "@solana/web3.js";
+import { GAME_ID } from "@/lib/constants";
 
 export const MoveRevealButton = () => {
   const wallet = useAnchorWallet();
@@ -38,7 +39,7 @@ export const MoveRevealButton = () => {
 
         // Create only the blockrunners reveal instruction (no switchboard reveal needed)
         const blockrunnersRevealIx = await program.methods
-          .moveReveal()
+          .moveReveal(GAME_ID)
           .accounts({
             player: publicKey,
             randomnessAccount: randomnessAccountAddress,
@@ -92,7 +93,7 @@ export const MoveRevealButton = () => {
 
       // Blockrunners program reveal instruction
       const blockrunnersRevealIx = await program.methods
-        .moveReveal()
+        .moveReveal(GAME_ID)
         .accounts({
           player: publicKey,
           randomnessAccount: randomnessAccountAddress,
@@ -2,17 +2,27 @@ import { useState, useEffect, ReactNode } from "react";
 import { useConnection, useAnchorWallet } from "@solana/wallet-adapter-react";
 import { BN } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
-import { EMPTY_CARD_USAGE, gameStatePDA, getPlayerStatePDA } from "../lib/constants";
+import {
+  EMPTY_CARD_USAGE,
+  GAME_ID,
+  gameStatePDA,
+  getFeedPagePDA,
+  getPlayerFeedPDA,
+  getPlayerStatePDA,
+} from "../lib/constants";
 import type {
   CardUsage,
+  FeedEntry,
+  FeedPage,
   GameState,
+  PlayerFeed,
   PlayerState,
-  SocialFeedEvent,
   SocialFeedEventInState,
 } from "../lib/types";
 import { BlockrunnersContext } from "../hooks/useBlockrunners";
 import { useProgram } from "@/hooks/useProgram";
 import { generateId } from "@/lib/utils";
+import { feedMessage } from "@/lib/feed";
 import { AbilityCard } from "@/lib/types";
 
 function BlockrunnersProvider({ children }: { children: ReactNode }) {
@@ -70,30 +80,30 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [playerState?.randomnessAccount]);
 
-  const addToFeed = (events: SocialFeedEvent[]) => {
+  // Entries are identified by their feed and sequence number, which never repeat
+  const addToFeed = (feed: "game" | "player", entries: FeedEntry[]) => {
+    const events = entries
+      .map((entry) => ({
+        key: `${feed}-${entry.sequence.toString()}`,
+        message: feedMessage(entry.event.payload),
+        timestamp: entry.event.timestamp.toNumber(),
+      }))
+      .filter((event): event is { key: string; message: string; timestamp: number } =>
+        Boolean(event.message)
+      );
     if (events.length === 0) return;
 
     setSocialFeed((prevFeed) => {
       if (prevFeed.length === 0) {
-        return events.map((event) => ({
-          id: generateId(),
-          message: event.message,
-          timestamp: event.timestamp.toNumber(),
-          isNew: true,
-        }));
+        return events.map((event) => ({ id: generateId(), ...event, isNew: true }));
       }
 
-      // Filter out events that already exist in the feed (same message and timestamp)
-      const existingEventKeys = new Set(
-        prevFeed.map((item) => `${item.message}-${item.timestamp}`)
-      );
+      // Filter out events that already exist in the feed
+      const existingEventKeys = new Set(prevFeed.map((item) => item.key));
 
       const newEvents = events
-        .filter((event) => {
-          const eventKey = `${event.message}-${event.timestamp.toNumber()}`;
-          return !existingEventKeys.has(eventKey);
-        })
-        .sort((a, b) => a.timestamp.toNumber() - b.timestamp.toNumber());
+        .filter((event) => !existingEventKeys.has(event.key))
+        .sort((a, b) => a.timestamp - b.timestamp);
 
       // If no new events, return the existing feed
       if (newEvents.length === 0) {
@@ -102,12 +112,7 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
 
       const newFeed = [
         ...prevFeed.map((item) => ({ ...item, isNew: false })),
-        ...newEvents.map((event) => ({
-          id: generateId(),
-          message: event.message,
-          timestamp: event.timestamp.toNumber(),
-          isNew: true,
-        })),
+        ...newEvents.map((event) => ({ id: generateId(), ...event, isNew: true })),
       ];
 
       // Sort by timestamp to maintain chronological order
@@ -150,9 +155,6 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
       );
       console.log("GameState changed", decodedGameState);
       setGameState(decodedGameState);
-
-      // Extract social feed events from GameState and add to feed
-      addToFeed(decodedGameState.gameEvents);
     });
 
     // Fetch GameState PDA initially
@@ -162,7 +164,6 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
         if (data) {
           console.log("GameState fetched", data);
           setGameState(data as GameState);
-          addToFeed(data.gameEvents);
         } else {
           console.log("GameState does not exist yet - may need initialization");
         }
@@ -178,6 +179,41 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, [connection]);
 
+  // Get the game feed from the page the game is writing to, which moves on once it is full
+  const feedPageIndex = gameState?.feedPage.toString();
+  useEffect(() => {
+    if (!program || feedPageIndex === undefined) return;
+
+    const feedPagePDA = getFeedPagePDA(new BN(feedPageIndex));
+
+    // Set up subscription to the FeedPage PDA
+    const feedPageSubscriptionId = connection.onAccountChange(feedPagePDA, (accountInfo) => {
+      const decodedFeedPage = program.coder.accounts.decode<FeedPage>(
+        "feedPage",
+        accountInfo.data
+      );
+      addToFeed("game", decodedFeedPage.entries);
+    });
+
+    // Fetch FeedPage PDA initially, the authority opens pages before the game reaches them
+    program.account.feedPage
+      .fetchNullable(feedPagePDA)
+      .then((data) => {
+        if (data) {
+          addToFeed("game", data.entries);
+        }
+      })
+      .catch((error) => {
+        console.log("FeedPage fetch error", error.message);
+      });
+
+    return () => {
+      connection.removeAccountChangeListener(feedPageSubscriptionId);
+    };
+
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [connection, feedPageIndex]);
+
   // Get PlayerState upon public key change
   useEffect(() => {
     setPlayerState(null);
@@ -197,9 +233,6 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
       );
       console.log("PlayerState changed", decodedPlayerState);
       setPlayerState(decodedPlayerState);
-
-      // Extract social feed events from PlayerState and add to feed
-      addToFeed(decodedPlayerState.playerEvents);
     });
 
     // Fetch PlayerState PDA initially
@@ -209,7 +242,6 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
         if (data) {
           console.log("PlayerState fetched", data);
           setPlayerState(data as PlayerState);
-          addToFeed(data.playerEvents);
         } else {
           console.log("PlayerState does not exist yet - may need initialization");
         }
@@ -218,9 +250,41 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
         console.log("PlayerState fetch error", error.message);
       });
 
+    // The player's events are kept in a separate PlayerFeed PDA
+    const feedPDA = getPlayerFeedPDA(wallet.publicKey);
+    const playerFeedEntries = (playerFeed: PlayerFeed): FeedEntry[] => {
+      // Once the feed is full, the oldest entry is at the head
+      const slots = playerFeed.slots.slice(0, playerFeed.len);
+      return [...slots.slice(playerFeed.head), ...slots.slice(0, playerFeed.head)].map((slot) =>
+        program.coder.types.decode<FeedEntry>("feedEntry", Buffer.from(slot.data))
+      );
+    };
+
+    // Set up subscription to PlayerFeed PDA
+    const feedSubscriptionId = connection.onAccountChange(feedPDA, (accountInfo) => {
+      const decodedPlayerFeed = program.coder.accounts.decode<PlayerFeed>(
+        "playerFeed",
+        accountInfo.data
+      );
+      addToFeed("player", playerFeedEntries(decodedPlayerFeed));
+    });
+
+    // Fetch PlayerFeed PDA initially
+    program.account.playerFeed
+      .fetchNullable(feedPDA)
+      .then((data) => {
+        if (data) {
+          addToFeed("player", playerFeedEntries(data as PlayerFeed));
+        }
+      })
+      .catch((error) => {
+        console.log("PlayerFeed fetch error", error.message);
+      });
+
     // Cleanup subscriptions
     return () => {
       connection.removeAccountChangeListener(playerSubscriptionId);
+      connection.removeAccountChangeListener(feedSubscriptionId);
     };
 
     // eslint-disable-next-line react-hooks/exhaustive-deps
@@ -235,16 +299,16 @@ function BlockrunnersProvider({ children }: { children: ReactNode }) {
       return;
     }
 
-    if (!gameState || !gameState.authority) {
-      console.error("Purchase ciphers: Game state or admin authority not found");
+    if (!gameState) {
+      console.error("Purchase ciphers: Game state not found");
       return;
     }
 
     program.methods
-      .purchaseCiphers(new BN(amount))
+      .purchaseCiphers(GAME_ID, new BN(amount))
       .accounts({
         player: wallet.publicKey,
-        adminWallet: gameState.authority,
+        adminWallet: gameState.feeRecipient,
       })
       .rpc()
       .then((tx) => {
@@ -1,7 +1,7 @@
 import { PublicKey } from "@solana/web3.js";
 import { createContext, useContext } from "react";
 import { gameStatePDA } from "../lib/constants";
-import { GameState, PlayerState, CardUsage } from "../lib/types";
+import { GameState, PlayerState, CardUsage, SocialFeedEventInState } from "../lib/types";
 import { AbilityCard } from "@/lib/types";
 
 // Define the types for React context
@@ -13,10 +13,8 @@ interface BlockrunnersContextType {
   // UI state
   selectedCards: AbilityCard[];
   setSelectedCards: (cards: AbilityCard[]) => void;
-  socialFeed: { id: string; message: string; timestamp: number; isNew: boolean }[];
-  setSocialFeed: (
-    feed: { id: string; message: string; timestamp: number; isNew: boolean }[]
-  ) => void;
+  socialFeed: SocialFeedEventInState[];
+  setSocialFeed: (feed: SocialFeedEventInState[]) => void;
   // Card usage for blockchain transactions
   cardUsage: CardUsage;
   setCardUsage: (cardUsage: CardUsage) => void;
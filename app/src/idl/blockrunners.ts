@@ -14,21 +14,20 @@ export type Blockrunners = {
   },
   "instructions": [
     {
-      "name": "initializeGame",
+      "name": "acceptAuthority",
       "discriminator": [
-        44,
-        62,
-        102,
-        247,
-        126,
-        208,
-        130,
-        215
+        107,
+        86,
+        198,
+        91,
+        33,
+        12,
+        107,
+        160
       ],
       "accounts": [
         {
-          "name": "admin",
-          "writable": true,
+          "name": "newAuthority",
           "signer": true
         },
         {
@@ -50,33 +49,37 @@ export type Blockrunners = {
                   116,
                   101
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "initializePlayer",
+      "name": "cancelMove",
       "discriminator": [
+        162,
         79,
-        249,
-        88,
-        177,
-        220,
-        62,
-        56,
-        128
+        212,
+        46,
+        238,
+        216,
+        224,
+        80
       ],
       "accounts": [
         {
           "name": "player",
-          "writable": true,
           "signer": true
         },
         {
@@ -109,66 +112,44 @@ export type Blockrunners = {
           }
         },
         {
-          "name": "gameState",
+          "name": "playerFeed",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  112,
+                  108,
                   97,
-                  109,
+                  121,
                   101,
+                  114,
                   95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  102,
+                  101,
+                  101,
+                  100
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "joinGame",
-      "discriminator": [
-        107,
-        112,
-        18,
-        38,
-        56,
-        173,
-        60,
-        128
-      ],
-      "accounts": [
-        {
-          "name": "player",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "playerState",
-          "writable": true,
+          "name": "gameState",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  103,
                   97,
-                  121,
+                  109,
                   101,
-                  114,
                   95,
                   115,
                   116,
@@ -178,15 +159,14 @@ export type Blockrunners = {
                 ]
               },
               {
-                "kind": "account",
-                "path": "player"
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "gameState",
-          "writable": true,
+          "name": "gameConfig",
           "pda": {
             "seeds": [
               {
@@ -197,50 +177,60 @@ export type Blockrunners = {
                   109,
                   101,
                   95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
+        },
+        {
+          "name": "randomnessAccount"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "moveCommit",
+      "name": "closeFeedPage",
       "discriminator": [
-        83,
-        26,
-        171,
-        77,
-        137,
-        39,
-        119,
-        210
+        63,
+        227,
+        88,
+        6,
+        15,
+        27,
+        253,
+        197
       ],
       "accounts": [
         {
-          "name": "player",
+          "name": "authority",
           "signer": true
         },
         {
-          "name": "playerState",
-          "writable": true,
+          "name": "gameState",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  112,
-                  108,
+                  103,
                   97,
-                  121,
+                  109,
                   101,
-                  114,
                   95,
                   115,
                   116,
@@ -250,68 +240,72 @@ export type Blockrunners = {
                 ]
               },
               {
-                "kind": "account",
-                "path": "player"
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "gameState",
+          "name": "feedPage",
+          "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
-                  97,
-                  109,
+                  102,
+                  101,
                   101,
+                  100,
                   95,
-                  115,
-                  116,
+                  112,
                   97,
-                  116,
+                  103,
                   101
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "arg",
+                "path": "pageIndex"
               }
             ]
           }
         },
         {
-          "name": "randomnessAccount"
+          "name": "payer",
+          "docs": [
+            "Gets back the rent it paid for the page"
+          ],
+          "writable": true
         }
       ],
       "args": [
         {
-          "name": "direction",
-          "type": {
-            "defined": {
-              "name": "pathDirection"
-            }
-          }
+          "name": "gameId",
+          "type": "u64"
         },
         {
-          "name": "cardUsage",
-          "type": {
-            "defined": {
-              "name": "cardUsage"
-            }
-          }
+          "name": "pageIndex",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "moveReveal",
+      "name": "closePlayer",
       "discriminator": [
-        107,
-        207,
-        143,
-        156,
-        197,
-        217,
-        108,
-        61
+        225,
+        227,
+        204,
+        63,
+        32,
+        122,
+        58,
+        227
       ],
       "accounts": [
         {
@@ -349,59 +343,40 @@ export type Blockrunners = {
           }
         },
         {
-          "name": "gameState",
+          "name": "playerFeed",
           "writable": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  103,
+                  112,
+                  108,
                   97,
-                  109,
+                  121,
                   101,
+                  114,
                   95,
-                  115,
-                  116,
-                  97,
-                  116,
-                  101
+                  102,
+                  101,
+                  101,
+                  100
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
               }
             ]
           }
         },
         {
-          "name": "randomnessAccount"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "purchaseCiphers",
-      "discriminator": [
-        99,
-        179,
-        154,
-        118,
-        166,
-        190,
-        214,
-        168
-      ],
-      "accounts": [
-        {
-          "name": "player",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "playerState",
+          "name": "playerArchive",
+          "docs": [
+            "Pass the archive to keep the lifetime statistics, leave it out to drop them"
+          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -414,10 +389,12 @@ export type Blockrunners = {
                   101,
                   114,
                   95,
-                  115,
-                  116,
                   97,
-                  116,
+                  114,
+                  99,
+                  104,
+                  105,
+                  118,
                   101
                 ]
               },
@@ -429,8 +406,37 @@ export type Blockrunners = {
           }
         },
         {
-          "name": "gameState",
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "forfeitCiphers",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "fundReserve",
+      "discriminator": [
+        17,
+        82,
+        71,
+        222,
+        117,
+        210,
+        58,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "funder",
           "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameState",
           "pda": {
             "seeds": [
               {
@@ -447,48 +453,1934 @@ export type Blockrunners = {
                   116,
                   101
                 ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
               }
             ]
           }
         },
         {
-          "name": "adminWallet",
-          "writable": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
+          "name": "reserveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
           "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "gameState",
+      "name": "getNextStepPrice",
       "discriminator": [
-        144,
-        94,
+        250,
+        45,
+        75,
+        200,
+        3,
+        60,
+        197,
+        137
+      ],
+      "accounts": [
+        {
+          "name": "player"
+        },
+        {
+          "name": "playerState",
+          "docs": [
+            "The price depends on the position, which only means something in the player's game"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ],
+      "returns": "u64"
+    },
+    {
+      "name": "initializeGame",
+      "discriminator": [
+        44,
+        62,
+        102,
+        247,
+        126,
         208,
-        172,
-        248,
-        99,
-        134,
-        120
+        130,
+        215
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "docs": [
+            "First page of the game feed, later ones are opened with `open_feed_page`"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "const",
+                "value": [
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0,
+                  0
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "prizeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  122,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserveVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
       ]
     },
     {
-      "name": "playerState",
+      "name": "initializePlayer",
       "discriminator": [
+        79,
+        249,
+        88,
+        177,
+        220,
+        62,
         56,
-        3,
-        60,
-        86,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "joinGame",
+      "discriminator": [
+        107,
+        112,
+        18,
+        38,
+        56,
+        173,
+        60,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "docs": [
+            "Current page of the game feed, opened in advance by the game authority"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "game_state.feed_page",
+                "account": "gameState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserveVault",
+          "docs": [
+            "Pays refunds of unspent ciphers"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "moveCommit",
+      "discriminator": [
+        83,
+        26,
+        171,
+        77,
+        137,
+        39,
+        119,
+        210
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "randomnessAccount"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "direction",
+          "type": {
+            "defined": {
+              "name": "pathDirection"
+            }
+          }
+        },
+        {
+          "name": "cardUsage",
+          "type": {
+            "defined": {
+              "name": "cardUsage"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "moveReveal",
+      "discriminator": [
+        107,
+        207,
+        143,
+        156,
+        197,
+        217,
+        108,
+        61
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "docs": [
+            "Current page of the game feed, opened in advance by the game authority"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "game_state.feed_page",
+                "account": "gameState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prizeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  122,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "randomnessAccount"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "openFeedPage",
+      "discriminator": [
+        56,
+        88,
+        252,
+        180,
+        14,
+        129,
+        62,
+        249
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "arg",
+                "path": "pageIndex"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "pageIndex",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "oracleCommit",
+      "discriminator": [
+        136,
+        69,
+        9,
+        207,
+        152,
+        153,
+        211,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "commitment",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "oracleReveal",
+      "discriminator": [
+        250,
+        128,
+        183,
+        235,
+        216,
+        161,
+        158,
+        101
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "secret",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "nextCommitment",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "discriminator": [
+        20,
+        148,
+        236,
+        198,
+        76,
+        119,
+        99,
+        142
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "newAuthority",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "purchaseCiphers",
+      "discriminator": [
+        99,
+        179,
+        154,
+        118,
+        166,
+        190,
+        214,
+        168
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "docs": [
+            "Current page of the game feed, opened in advance by the game authority"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "game_state.feed_page",
+                "account": "gameState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "prizeVault",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  114,
+                  105,
+                  122,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminWallet",
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeemCiphers",
+      "discriminator": [
+        137,
+        251,
+        22,
+        52,
+        225,
+        226,
+        86,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "player",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "playerState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "playerFeed",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  121,
+                  101,
+                  114,
+                  95,
+                  102,
+                  101,
+                  101,
+                  100
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "player"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "reserveVault",
+          "docs": [
+            "Pays the redemption, the prize vault is never touched"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  115,
+                  101,
+                  114,
+                  118,
+                  101,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setFeeRecipient",
+      "discriminator": [
+        227,
+        18,
+        215,
+        42,
+        237,
+        246,
+        151,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeRecipient",
+          "docs": [
+            "Receives the admin share of cipher purchases as plain transfers"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setPause",
+      "discriminator": [
+        63,
+        32,
+        154,
+        2,
+        56,
+        103,
+        79,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "feedPage",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101,
+                  100,
+                  95,
+                  112,
+                  97,
+                  103,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              },
+              {
+                "kind": "account",
+                "path": "game_state.feed_page",
+                "account": "gameState"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "paused",
+          "type": {
+            "defined": {
+              "name": "pauseFlags"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateConfig",
+      "discriminator": [
+        29,
+        158,
+        252,
+        191,
+        10,
+        83,
+        219,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "signer": true
+        },
+        {
+          "name": "gameState",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  115,
+                  116,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        },
+        {
+          "name": "gameConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  103,
+                  97,
+                  109,
+                  101,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "arg",
+                "path": "gameId"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "gameId",
+          "type": "u64"
+        },
+        {
+          "name": "params",
+          "type": {
+            "defined": {
+              "name": "gameConfigParams"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "feedPage",
+      "discriminator": [
+        143,
+        92,
+        53,
+        48,
+        80,
+        50,
+        152,
+        125
+      ]
+    },
+    {
+      "name": "gameConfig",
+      "discriminator": [
+        45,
+        146,
+        146,
+        33,
+        170,
+        69,
+        96,
+        133
+      ]
+    },
+    {
+      "name": "gameState",
+      "discriminator": [
+        144,
+        94,
+        208,
+        172,
+        248,
+        99,
+        134,
+        120
+      ]
+    },
+    {
+      "name": "playerArchive",
+      "discriminator": [
+        14,
+        159,
+        241,
+        2,
+        204,
+        204,
+        159,
+        203
+      ]
+    },
+    {
+      "name": "playerFeed",
+      "discriminator": [
+        233,
+        243,
+        0,
+        72,
+        108,
+        19,
+        21,
+        141
+      ]
+    },
+    {
+      "name": "playerState",
+      "discriminator": [
+        56,
+        3,
+        60,
+        86,
         174,
         16,
         244,
@@ -497,6 +2389,45 @@ export type Blockrunners = {
     }
   ],
   "events": [
+    {
+      "name": "authorityChanged",
+      "discriminator": [
+        31,
+        19,
+        174,
+        152,
+        4,
+        82,
+        215,
+        226
+      ]
+    },
+    {
+      "name": "feeRecipientChanged",
+      "discriminator": [
+        219,
+        2,
+        90,
+        130,
+        185,
+        40,
+        160,
+        57
+      ]
+    },
+    {
+      "name": "gameConfigChanged",
+      "discriminator": [
+        76,
+        229,
+        12,
+        174,
+        39,
+        63,
+        101,
+        32
+      ]
+    },
     {
       "name": "socialFeedEvent",
       "discriminator": [
@@ -558,200 +2489,1470 @@ export type Blockrunners = {
       "msg": "Unknown Error"
     },
     {
-      "code": 6009,
-      "name": "noCardsLeft",
-      "msg": "No cards left to use"
+      "code": 6009,
+      "name": "noCardsLeft",
+      "msg": "No cards left to use"
+    },
+    {
+      "code": 6010,
+      "name": "invalidMove",
+      "msg": "Invalid move"
+    },
+    {
+      "code": 6011,
+      "name": "invalidCardSelection",
+      "msg": "Invalid card selection"
+    },
+    {
+      "code": 6012,
+      "name": "notEnoughCiphers",
+      "msg": "Not enough ciphers to make this move"
+    },
+    {
+      "code": 6013,
+      "name": "notAtEndOfPath",
+      "msg": "Player not at end of path yet"
+    },
+    {
+      "code": 6014,
+      "name": "randomnessAccountParsing",
+      "msg": "Failed to parse randomness account"
+    },
+    {
+      "code": 6015,
+      "name": "randomnessAccountParsingReveal",
+      "msg": "Failed to parse randomness account for reveal"
+    },
+    {
+      "code": 6016,
+      "name": "randomnessUnavailable",
+      "msg": "Randomness data is unavailable"
+    },
+    {
+      "code": 6017,
+      "name": "randomnessFinished",
+      "msg": "Randomness finished"
+    },
+    {
+      "code": 6018,
+      "name": "randomnessNotResolved",
+      "msg": "Randomness not resolved"
+    },
+    {
+      "code": 6019,
+      "name": "randomnessStale",
+      "msg": "Randomness is stale"
+    },
+    {
+      "code": 6020,
+      "name": "randomnessExpired",
+      "msg": "Randomness is expired"
+    },
+    {
+      "code": 6021,
+      "name": "unauthorized",
+      "msg": "unauthorized"
+    },
+    {
+      "code": 6022,
+      "name": "moveNotCommitted",
+      "msg": "Move not committed"
+    },
+    {
+      "code": 6023,
+      "name": "playingInDifferentGame",
+      "msg": "Player is playing in a different game"
+    },
+    {
+      "code": 6024,
+      "name": "playerAlreadyJoinedGame",
+      "msg": "Player has already joined the game"
+    },
+    {
+      "code": 6025,
+      "name": "invalidCipherCost",
+      "msg": "Cipher cost must be greater than zero"
+    },
+    {
+      "code": 6026,
+      "name": "invalidPathLength",
+      "msg": "Path length must be greater than zero and within the min and max path length"
+    },
+    {
+      "code": 6027,
+      "name": "invalidMoveSuccessProbability",
+      "msg": "Move success probability must be between 1 and 99"
+    },
+    {
+      "code": 6028,
+      "name": "invalidRevenueSplit",
+      "msg": "Revenue percentages must sum up to 100"
+    },
+    {
+      "code": 6029,
+      "name": "invalidFeedCapacity",
+      "msg": "Feed capacity is out of range"
+    },
+    {
+      "code": 6030,
+      "name": "prizeVaultMismatch",
+      "msg": "Prize vault balance doesn't cover the prize pool"
+    },
+    {
+      "code": 6031,
+      "name": "invalidStepPriceCurve",
+      "msg": "Step price curve is invalid"
+    },
+    {
+      "code": 6032,
+      "name": "invalidPathLengthRule",
+      "msg": "Path length rule is invalid"
+    },
+    {
+      "code": 6033,
+      "name": "invalidStartingHandTiers",
+      "msg": "Starting hand tiers must start at zero and be in ascending order"
+    },
+    {
+      "code": 6034,
+      "name": "invalidMoveTimeout",
+      "msg": "Move timeout must be greater than zero"
+    },
+    {
+      "code": 6035,
+      "name": "moveCommitExpired",
+      "msg": "Move commitment has expired, cancel it to make a new move"
+    },
+    {
+      "code": 6036,
+      "name": "moveCommitNotExpired",
+      "msg": "Move commitment can only be cancelled after it expires"
+    },
+    {
+      "code": 6037,
+      "name": "invalidRandomnessAccountOwner",
+      "msg": "Randomness account is not owned by the Switchboard program"
+    },
+    {
+      "code": 6038,
+      "name": "invalidRandomnessAccountSize",
+      "msg": "Randomness account has an invalid size"
+    },
+    {
+      "code": 6039,
+      "name": "randomnessAlreadyRevealed",
+      "msg": "Randomness has already been revealed"
+    },
+    {
+      "code": 6040,
+      "name": "invalidRandomnessAccount",
+      "msg": "Randomness account doesn't match the game's randomness provider"
+    },
+    {
+      "code": 6041,
+      "name": "slotHashUnavailable",
+      "msg": "Slot hash is no longer available"
+    },
+    {
+      "code": 6042,
+      "name": "oracleCommitmentMissing",
+      "msg": "Local oracle has no pending commitment"
+    },
+    {
+      "code": 6043,
+      "name": "oracleCommitmentPending",
+      "msg": "Local oracle already has a pending commitment"
+    },
+    {
+      "code": 6044,
+      "name": "invalidOracleSecret",
+      "msg": "Secret doesn't match the local oracle commitment"
+    },
+    {
+      "code": 6045,
+      "name": "scriptedRandomnessFull",
+      "msg": "Scripted randomness queue is full"
+    },
+    {
+      "code": 6046,
+      "name": "notPendingAuthority",
+      "msg": "Signer is not the proposed authority"
+    },
+    {
+      "code": 6047,
+      "name": "gamePaused",
+      "msg": "This action is paused in the game"
+    },
+    {
+      "code": 6048,
+      "name": "invalidCipherCarryOver",
+      "msg": "Cipher refund percentage can't exceed the admin percentage"
+    },
+    {
+      "code": 6049,
+      "name": "unspentCiphers",
+      "msg": "Unspent ciphers belong to another game"
+    },
+    {
+      "code": 6050,
+      "name": "zeroAmount",
+      "msg": "Amount must be greater than zero"
+    },
+    {
+      "code": 6051,
+      "name": "invalidRedeemPercentage",
+      "msg": "Cipher redeem percentage can't exceed the admin percentage"
+    },
+    {
+      "code": 6052,
+      "name": "redemptionDisabled",
+      "msg": "Cipher redemption is turned off in this game"
+    },
+    {
+      "code": 6053,
+      "name": "insufficientReserve",
+      "msg": "Reserve vault can't cover the redemption"
+    },
+    {
+      "code": 6054,
+      "name": "movePending",
+      "msg": "Committed move must be revealed or cancelled first"
+    },
+    {
+      "code": 6055,
+      "name": "unspentCiphersRemaining",
+      "msg": "Unspent ciphers must be redeemed or explicitly forfeited first"
+    },
+    {
+      "code": 6056,
+      "name": "feedPageInUse",
+      "msg": "The game still writes to this feed page"
+    },
+    {
+      "code": 6057,
+      "name": "feeRecipientNotRentExempt",
+      "msg": "Fee recipient must be a rent-exempt system account"
+    },
+    {
+      "code": 6058,
+      "name": "feedPageOutdated",
+      "msg": "The game already moved on from this feed page"
+    },
+    {
+      "code": 6059,
+      "name": "playerNotInGame",
+      "msg": "Player hasn't joined this game"
+    }
+  ],
+  "types": [
+    {
+      "name": "achievement",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "ghostProtocol"
+          },
+          {
+            "name": "dataHoarder"
+          },
+          {
+            "name": "consensusBreaker"
+          },
+          {
+            "name": "cipherLord"
+          }
+        ]
+      }
+    },
+    {
+      "name": "authorityChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "oldAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "card",
+      "docs": [
+        "Ability cards a runner can hold and use on a move"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "shield"
+          },
+          {
+            "name": "doubler"
+          },
+          {
+            "name": "swift"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "invalidMove",
-      "msg": "Invalid move"
+      "name": "cardCounts",
+      "docs": [
+        "Number of cards of each type held by a runner"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shield",
+            "type": "u8"
+          },
+          {
+            "name": "doubler",
+            "type": "u8"
+          },
+          {
+            "name": "swift",
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "invalidCardSelection",
-      "msg": "Invalid card selection"
+      "name": "cardUsage",
+      "docs": [
+        "Cards used on a move"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "shield",
+            "type": "bool"
+          },
+          {
+            "name": "doubler",
+            "type": "bool"
+          },
+          {
+            "name": "swift",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "notEnoughCiphers",
-      "msg": "Not enough ciphers to make this move"
+      "name": "cipherCarryOver",
+      "docs": [
+        "What happens to the ciphers a player didn't spend when their game is won by someone else"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "forfeit"
+          },
+          {
+            "name": "carryOver"
+          },
+          {
+            "name": "refund",
+            "fields": [
+              {
+                "name": "refundPercentage",
+                "type": "u8"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "notAtEndOfPath",
-      "msg": "Player not at end of path yet"
+      "name": "feeRecipientChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldFeeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "newFeeRecipient",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "randomnessAccountParsing",
-      "msg": "Failed to parse randomness account"
+      "name": "feedEntry",
+      "docs": [
+        "A feed event together with its position in the feed's history"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sequence",
+            "docs": [
+              "Number of events recorded in the feed before this one"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "event",
+            "type": {
+              "defined": {
+                "name": "socialFeedEvent"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "randomnessAccountParsingReveal",
-      "msg": "Failed to parse randomness account for reveal"
+      "name": "feedPage",
+      "docs": [
+        "A page of the game feed's history. The game writes its feed events to its current page until",
+        "the page holds `FEED_PAGE_EVENTS` events, then moves on to the next page index. Pages are",
+        "opened in advance by the game authority, so players never pay their rent."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "type": "u64"
+          },
+          {
+            "name": "pageIndex",
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Store bump to save compute"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "payer",
+            "docs": [
+              "Account that paid the rent, refunded when the page is closed"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "feedEntry"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "randomnessUnavailable",
-      "msg": "Randomness data is unavailable"
+      "name": "feedPayload",
+      "docs": [
+        "What happened, with the values clients need to render the message in their own words.",
+        "",
+        "New variants are only ever appended, so clients keep decoding the events they know."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "playerJoined",
+            "fields": [
+              {
+                "name": "player",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "ciphersPurchased",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              },
+              {
+                "name": "cost",
+                "type": "u64"
+              },
+              {
+                "name": "ciphers",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "prizePoolSurge",
+            "fields": [
+              {
+                "name": "increasePercentage",
+                "type": "u64"
+              },
+              {
+                "name": "prizePool",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "advanced",
+            "fields": [
+              {
+                "name": "position",
+                "type": "u8"
+              },
+              {
+                "name": "cardUsage",
+                "type": {
+                  "defined": {
+                    "name": "cardUsage"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "shieldSaved",
+            "fields": [
+              {
+                "name": "position",
+                "type": "u8"
+              },
+              {
+                "name": "cardUsage",
+                "type": {
+                  "defined": {
+                    "name": "cardUsage"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "reset",
+            "fields": [
+              {
+                "name": "fromPosition",
+                "type": "u8"
+              },
+              {
+                "name": "cardUsage",
+                "type": {
+                  "defined": {
+                    "name": "cardUsage"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "cardsCollected",
+            "fields": [
+              {
+                "name": "cards",
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "card"
+                    }
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "cardsRejected",
+            "fields": [
+              {
+                "name": "count",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "startingHandDealt",
+            "fields": [
+              {
+                "name": "cards",
+                "type": {
+                  "defined": {
+                    "name": "cardCounts"
+                  }
+                }
+              },
+              {
+                "name": "prizePool",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "personalBest",
+            "fields": [
+              {
+                "name": "position",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "winStreakRecord",
+            "fields": [
+              {
+                "name": "streak",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "milestoneReached",
+            "fields": [
+              {
+                "name": "percentage",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "systemIntrusion",
+            "fields": [
+              {
+                "name": "percentage",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "stepPriceChanged",
+            "fields": [
+              {
+                "name": "previous",
+                "type": "u64"
+              },
+              {
+                "name": "next",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "achievementUnlocked",
+            "fields": [
+              {
+                "name": "achievement",
+                "type": {
+                  "defined": {
+                    "name": "achievement"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "prizeDistributing"
+          },
+          {
+            "name": "gameWon",
+            "fields": [
+              {
+                "name": "player",
+                "type": "pubkey"
+              },
+              {
+                "name": "steps",
+                "type": "u8"
+              },
+              {
+                "name": "prize",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "missionComplete",
+            "fields": [
+              {
+                "name": "streak",
+                "type": "u64"
+              },
+              {
+                "name": "prize",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "pathLengthChanged",
+            "fields": [
+              {
+                "name": "previous",
+                "type": "u8"
+              },
+              {
+                "name": "next",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "moveTimedOut",
+            "fields": [
+              {
+                "name": "position",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "moveCancelledInLockdown",
+            "fields": [
+              {
+                "name": "position",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "pauseChanged",
+            "fields": [
+              {
+                "name": "paused",
+                "type": {
+                  "defined": {
+                    "name": "pauseFlags"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "ciphersCarriedOver",
+            "fields": [
+              {
+                "name": "ciphers",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "ciphersForfeited",
+            "fields": [
+              {
+                "name": "ciphers",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "ciphersRefunded",
+            "fields": [
+              {
+                "name": "ciphers",
+                "type": "u64"
+              },
+              {
+                "name": "refund",
+                "type": "u64"
+              },
+              {
+                "name": "owed",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "ciphersRedeemed",
+            "fields": [
+              {
+                "name": "amount",
+                "type": "u64"
+              },
+              {
+                "name": "payout",
+                "type": "u64"
+              },
+              {
+                "name": "ciphers",
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "randomnessFinished",
-      "msg": "Randomness finished"
+      "name": "feedSlot",
+      "docs": [
+        "A feed entry, Borsh-encoded into a fixed number of bytes"
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "data",
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "randomnessNotResolved",
-      "msg": "Randomness not resolved"
+      "name": "gameConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "params",
+            "docs": [
+              "Parameters the game is currently played with"
+            ],
+            "type": {
+              "defined": {
+                "name": "gameConfigParams"
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Store bump to save compute"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "randomnessStale",
-      "msg": "Randomness is stale"
+      "name": "gameConfigChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldParams",
+            "type": {
+              "defined": {
+                "name": "gameConfigParams"
+              }
+            }
+          },
+          {
+            "name": "newParams",
+            "type": {
+              "defined": {
+                "name": "gameConfigParams"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "randomnessExpired",
-      "msg": "Randomness is expired"
+      "name": "gameConfigParams",
+      "docs": [
+        "Tunable game parameters, as passed to `update_config`"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "cipherCost",
+            "docs": [
+              "Price of one cipher in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "baseMoveCost",
+            "docs": [
+              "Base cost of a move in ciphers"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cardCost",
+            "docs": [
+              "Cost of using one card in ciphers"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "swiftDiscount",
+            "docs": [
+              "Ciphers saved on a move by using the Swift card"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stepPriceCurve",
+            "docs": [
+              "How the base move cost grows with the position on the path"
+            ],
+            "type": {
+              "defined": {
+                "name": "stepPriceCurve"
+              }
+            }
+          },
+          {
+            "name": "pathLength",
+            "docs": [
+              "The length of the path for new games"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pathLengthRule",
+            "docs": [
+              "How the path length changes between games, bounded by the min and max path length"
+            ],
+            "type": {
+              "defined": {
+                "name": "pathLengthRule"
+              }
+            }
+          },
+          {
+            "name": "minPathLength",
+            "type": "u8"
+          },
+          {
+            "name": "maxPathLength",
+            "type": "u8"
+          },
+          {
+            "name": "moveSuccessProbability",
+            "docs": [
+              "Move success probability as a percentage (out of 100)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "moveTimeoutSlots",
+            "docs": [
+              "Number of slots after which a pending move expires"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "randomnessProvider",
+            "docs": [
+              "Where the randomness of moves comes from"
+            ],
+            "type": {
+              "defined": {
+                "name": "randomnessProvider"
+              }
+            }
+          },
+          {
+            "name": "prizePoolPercentage",
+            "docs": [
+              "Revenue distribution percentages, must sum up to 100"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "adminPercentage",
+            "type": "u8"
+          },
+          {
+            "name": "maxFeedEvents",
+            "docs": [
+              "Number of events kept in the game and player feeds"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "startingHandTiers",
+            "docs": [
+              "Starting hands by prize pool size, in ascending order of `min_prize_pool`"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "startingHandTier"
+                  }
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "cipherCarryOver",
+            "docs": [
+              "What happens to unspent ciphers when a new game starts"
+            ],
+            "type": {
+              "defined": {
+                "name": "cipherCarryOver"
+              }
+            }
+          },
+          {
+            "name": "redeemPercentage",
+            "docs": [
+              "Percentage of the price paid for the ciphers `redeem_ciphers` pays back from the reserve",
+              "vault, 0 turns redemptions off. Can't exceed the admin percentage, so players can't",
+              "profit from buying and redeeming and the prize pool is never touched."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "unauthorized",
-      "msg": "unauthorized"
+      "name": "gameState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gameId",
+            "docs": [
+              "Identifier of this game instance, part of the PDA seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAuthority",
+            "docs": [
+              "Key proposed with `propose_authority`, becomes the authority once it signs `accept_authority`"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "feeRecipient",
+            "docs": [
+              "Account receiving the admin share of cipher purchases"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Player actions the authority halted with `set_pause`"
+            ],
+            "type": {
+              "defined": {
+                "name": "pauseFlags"
+              }
+            }
+          },
+          {
+            "name": "prizePool",
+            "docs": [
+              "The current prize pool amount in lamports, held by the prize vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "prizeVaultBump",
+            "docs": [
+              "Bump of the prize vault PDA, used to sign payouts"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserveVaultBump",
+            "docs": [
+              "Bump of the reserve vault PDA, used to sign refunds"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pathLength",
+            "docs": [
+              "The length of the path players need to navigate"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "start",
+            "docs": [
+              "The Unix timestamp when the current game started"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "pathSeed",
+            "docs": [
+              "Seed of the hidden left/right path for the current game"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "feedSequence",
+            "docs": [
+              "Sequence number of the next game feed event, also the number of events ever announced"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feedPage",
+            "docs": [
+              "Index of the feed page the game feed is written to"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "oracleCommitment",
+            "docs": [
+              "Hash of the secret the local oracle reveals next"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "oracleReveal",
+            "docs": [
+              "The last secret revealed by the local oracle"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "oracleReveal"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "moveNotCommitted",
-      "msg": "Move not committed"
+      "name": "oracleReveal",
+      "docs": [
+        "A local oracle secret together with the commitment it opens"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commitment",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "secret",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "playingInDifferentGame",
-      "msg": "Player is playing in a different game"
+      "name": "pathDirection",
+      "docs": [
+        "Direction of a step on the path"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "left"
+          },
+          {
+            "name": "right"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "playerAlreadyJoinedGame",
-      "msg": "Player has already joined the game"
-    }
-  ],
-  "types": [
-    {
-      "name": "card",
+      "name": "pathLengthRule",
+      "docs": [
+        "How the path length of a new game is derived from the game that was just won"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "shield"
+            "name": "fixed"
           },
           {
-            "name": "doubler"
+            "name": "prizePool",
+            "fields": [
+              {
+                "name": "lamportsPerStep",
+                "type": "u64"
+              }
+            ]
           },
           {
-            "name": "swift"
+            "name": "winSpeed",
+            "fields": [
+              {
+                "name": "targetDuration",
+                "type": "i64"
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "cardCounts",
+      "name": "pauseFlags",
+      "docs": [
+        "Player actions that can be halted, e.g. while a bug or randomness issue is investigated"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "shield",
+            "name": "purchases",
+            "type": "bool"
+          },
+          {
+            "name": "commits",
+            "type": "bool"
+          },
+          {
+            "name": "reveals",
+            "docs": [
+              "Committed moves can't be revealed, their owners can cancel them for free instead"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "joins",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "playerArchive",
+      "docs": [
+        "Lifetime statistics of the player accounts a player closed, kept for leaderboards and",
+        "achievements after the much larger `PlayerState` is gone"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "player",
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Store bump to save compute"
+            ],
             "type": "u8"
           },
           {
-            "name": "doubler",
+            "name": "accountsClosed",
+            "docs": [
+              "Number of player accounts merged into this archive"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "firstLogin",
+            "type": "i64"
+          },
+          {
+            "name": "lastLogin",
+            "type": "i64"
+          },
+          {
+            "name": "gamesWon",
+            "type": "u64"
+          },
+          {
+            "name": "totalCiphersBought",
+            "type": "u64"
+          },
+          {
+            "name": "bestPosition",
             "type": "u8"
           },
           {
-            "name": "swift",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "cardUsage",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "shield",
+            "name": "totalResets",
+            "type": "u64"
+          },
+          {
+            "name": "totalSteps",
+            "type": "u64"
+          },
+          {
+            "name": "cardsCollected",
+            "type": "u64"
+          },
+          {
+            "name": "shieldsUsed",
+            "type": "u64"
+          },
+          {
+            "name": "systemBreaches",
+            "type": "u64"
+          },
+          {
+            "name": "bestWinStreak",
+            "type": "u64"
+          },
+          {
+            "name": "ghostProtocol",
+            "type": "bool"
+          },
+          {
+            "name": "dataHoarder",
             "type": "bool"
           },
           {
-            "name": "doubler",
+            "name": "consensusBreaker",
             "type": "bool"
           },
           {
-            "name": "swift",
+            "name": "cipherLord",
             "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "gameState",
+      "name": "playerFeed",
+      "docs": [
+        "The latest events of a player in a fixed-capacity ring buffer. The account is read and",
+        "written in place, and once the feed is full new events overwrite the oldest one."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authority",
-            "docs": [
-              "Authority"
-            ],
+            "name": "player",
             "type": "pubkey"
           },
           {
-            "name": "prizePool",
+            "name": "nextSequence",
             "docs": [
-              "The current prize pool amount in lamports"
+              "Sequence number of the next event, which is also the number of events ever recorded"
             ],
             "type": "u64"
           },
           {
-            "name": "pathLength",
+            "name": "len",
             "docs": [
-              "The length of the path players need to navigate"
+              "Number of slots in use"
             ],
             "type": "u8"
           },
           {
-            "name": "start",
+            "name": "head",
             "docs": [
-              "The Unix timestamp when the current game started"
+              "Index of the oldest entry once the feed is full, overwritten by the next event"
             ],
-            "type": "i64"
+            "type": "u8"
           },
           {
-            "name": "gameEvents",
+            "name": "bump",
+            "docs": [
+              "Store bump to save compute"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "padding",
             "type": {
-              "vec": {
-                "defined": {
-                  "name": "socialFeedEvent"
-                }
-              }
+              "array": [
+                "u8",
+                5
+              ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "pathDirection",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "left"
           },
           {
-            "name": "right"
+            "name": "slots",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "feedSlot"
+                  }
+                },
+                20
+              ]
+            }
           }
         ]
       }
@@ -768,6 +3969,15 @@ export type Blockrunners = {
             ],
             "type": "u64"
           },
+          {
+            "name": "cipherPrice",
+            "docs": [
+              "Average price in lamports the owned ciphers were bought at, rounded down. Redemptions and",
+              "refunds are paid from it, so they never exceed what was paid into the game, whatever the",
+              "cipher cost is now."
+            ],
+            "type": "u64"
+          },
           {
             "name": "cards",
             "docs": [
@@ -793,19 +4003,6 @@ export type Blockrunners = {
             ],
             "type": "u8"
           },
-          {
-            "name": "playerEvents",
-            "docs": [
-              "Social feed events history"
-            ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "socialFeedEvent"
-                }
-              }
-            }
-          },
           {
             "name": "firstLogin",
             "docs": [
@@ -879,6 +4076,15 @@ export type Blockrunners = {
             "name": "cipherLord",
             "type": "bool"
           },
+          {
+            "name": "gameId",
+            "docs": [
+              "The id of the game this player is part of"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
           {
             "name": "gameStart",
             "docs": [
@@ -906,6 +4112,33 @@ export type Blockrunners = {
               "option": "u64"
             }
           },
+          {
+            "name": "randomnessProvider",
+            "docs": [
+              "The randomness provider the move was committed with"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "randomnessProvider"
+                }
+              }
+            }
+          },
+          {
+            "name": "randomnessCommitment",
+            "docs": [
+              "The local oracle commitment the move is bound to"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
           {
             "name": "randomnessValue",
             "docs": [
@@ -940,6 +4173,33 @@ export type Blockrunners = {
                 }
               }
             }
+          },
+          {
+            "name": "moveCost",
+            "docs": [
+              "Ciphers paid when the move was committed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "randomnessProvider",
+      "docs": [
+        "Source of the randomness that decides the outcome of a move"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "switchboard"
+          },
+          {
+            "name": "slotHash"
+          },
+          {
+            "name": "localOracle"
           }
         ]
       }
@@ -949,6 +4209,13 @@ export type Blockrunners = {
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout of the event, `FEED_EVENT_VERSION` for the events written by this program"
+            ],
+            "type": "u8"
+          },
           {
             "name": "eventType",
             "type": {
@@ -958,8 +4225,12 @@ export type Blockrunners = {
             }
           },
           {
-            "name": "message",
-            "type": "string"
+            "name": "payload",
+            "type": {
+              "defined": {
+                "name": "feedPayload"
+              }
+            }
           },
           {
             "name": "timestamp",
@@ -1023,12 +4294,112 @@ export type Blockrunners = {
           },
           {
             "name": "protocolFragment"
+          },
+          {
+            "name": "systemLockdown"
+          },
+          {
+            "name": "ciphersSettled"
+          },
+          {
+            "name": "ciphersRedeemed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "startingHandTier",
+      "docs": [
+        "Starting hand dealt when the prize pool holds at least `min_prize_pool` lamports"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minPrizePool",
+            "type": "u64"
+          },
+          {
+            "name": "cards",
+            "type": {
+              "defined": {
+                "name": "cardCounts"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stepPriceCurve",
+      "docs": [
+        "How the base price of a step grows with the runner's position on the path"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "flat"
+          },
+          {
+            "name": "linear",
+            "fields": [
+              {
+                "name": "increment",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "exponential",
+            "fields": [
+              {
+                "name": "growthPercentage",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "tiered",
+            "fields": [
+              {
+                "name": "tierLength",
+                "type": "u8"
+              },
+              {
+                "name": "prices",
+                "type": {
+                  "array": [
+                    "u64",
+                    8
+                  ]
+                }
+              }
+            ]
           }
         ]
       }
     }
   ],
   "constants": [
+    {
+      "name": "adminPercentage",
+      "type": "u8",
+      "value": "12"
+    },
+    {
+      "name": "baseMoveCost",
+      "docs": [
+        "Move costs in ciphers"
+      ],
+      "type": "u64",
+      "value": "1"
+    },
+    {
+      "name": "cardCost",
+      "type": "u64",
+      "value": "1"
+    },
     {
       "name": "cipherCost",
       "type": "u64",
@@ -1039,6 +4410,36 @@ export type Blockrunners = {
       "type": "u8",
       "value": "8"
     },
+    {
+      "name": "feedEventVersion",
+      "docs": [
+        "Layout version of the social feed events. Versions count down from 0xFF whenever the layout",
+        "changes, out of the range of the event types legacy events start with."
+      ],
+      "type": "u8",
+      "value": "255"
+    },
+    {
+      "name": "feedPageEvents",
+      "docs": [
+        "Number of events after which the game feed moves on to the next page"
+      ],
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "feedPageSeed",
+      "docs": [
+        "History of the game feed, one account per page"
+      ],
+      "type": "bytes",
+      "value": "[102, 101, 101, 100, 95, 112, 97, 103, 101]"
+    },
+    {
+      "name": "gameConfigSeed",
+      "type": "bytes",
+      "value": "[103, 97, 109, 101, 95, 99, 111, 110, 102, 105, 103]"
+    },
     {
       "name": "gameStateSeed",
       "type": "bytes",
@@ -1059,11 +4460,24 @@ export type Blockrunners = {
       "type": "u8",
       "value": "20"
     },
+    {
+      "name": "maxPathLength",
+      "type": "u8",
+      "value": "50"
+    },
     {
       "name": "maxRandomnessValues",
       "type": "u8",
       "value": "32"
     },
+    {
+      "name": "minPathLength",
+      "docs": [
+        "Bounds of the path length when it changes between games"
+      ],
+      "type": "u8",
+      "value": "5"
+    },
     {
       "name": "moveSuccessProbability",
       "docs": [
@@ -1072,6 +4486,30 @@ export type Blockrunners = {
       "type": "u8",
       "value": "75"
     },
+    {
+      "name": "moveTimeoutSlots",
+      "docs": [
+        "Number of slots after which a pending move can no longer be revealed and can be cancelled"
+      ],
+      "type": "u64",
+      "value": "150"
+    },
+    {
+      "name": "playerArchiveSeed",
+      "docs": [
+        "Lifetime statistics of closed player accounts"
+      ],
+      "type": "bytes",
+      "value": "[112, 108, 97, 121, 101, 114, 95, 97, 114, 99, 104, 105, 118, 101]"
+    },
+    {
+      "name": "playerFeedSeed",
+      "docs": [
+        "Feed of the events that only concern the player"
+      ],
+      "type": "bytes",
+      "value": "[112, 108, 97, 121, 101, 114, 95, 102, 101, 101, 100]"
+    },
     {
       "name": "playerStateSeed",
       "type": "bytes",
@@ -1084,6 +4522,40 @@ export type Blockrunners = {
       ],
       "type": "u8",
       "value": "88"
+    },
+    {
+      "name": "prizeVaultSeed",
+      "type": "bytes",
+      "value": "[112, 114, 105, 122, 101, 95, 118, 97, 117, 108, 116]"
+    },
+    {
+      "name": "redeemPercentage",
+      "docs": [
+        "Percentage of the cipher cost paid back when redeeming ciphers, at most the admin percentage"
+      ],
+      "type": "u8",
+      "value": "10"
+    },
+    {
+      "name": "reserveVaultSeed",
+      "docs": [
+        "Lamports paying for refunds of unspent ciphers, never part of the prize pool"
+      ],
+      "type": "bytes",
+      "value": "[114, 101, 115, 101, 114, 118, 101, 95, 118, 97, 117, 108, 116]"
+    },
+    {
+      "name": "scriptedRandomnessSeed",
+      "docs": [
+        "Test-only queue of randomness values"
+      ],
+      "type": "bytes",
+      "value": "[115, 99, 114, 105, 112, 116, 101, 100, 95, 114, 97, 110, 100, 111, 109, 110, 101, 115, 115]"
+    },
+    {
+      "name": "swiftDiscount",
+      "type": "u64",
+      "value": "2"
     }
   ]
 };
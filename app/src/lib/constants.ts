@@ -7,6 +7,9 @@ import { CardUsage } from "@/lib/types";
 // Program ID
 export const PROGRAM_ID = new PublicKey(IDL.address);
 
+// Id of the game instance the app plays
+export const GAME_ID = new BN(import.meta.env.VITE_GAME_ID ?? 0);
+
 // Number constants from IDL
 export const CIPHER_COST = new BN(getConstantOrThrow("CIPHER_COST")).toNumber();
 export const INITIAL_PATH_LENGTH = Number(getConstantOrThrow("INITIAL_PATH_LENGTH"));
@@ -21,13 +24,26 @@ export const COMMITMENT = "processed";
 // String constants from IDL stored as byte arrays
 export const GAME_STATE_SEED = getStringFromByteArray(getConstantOrThrow("GAME_STATE_SEED"));
 export const PLAYER_STATE_SEED = getStringFromByteArray(getConstantOrThrow("PLAYER_STATE_SEED"));
+export const PLAYER_FEED_SEED = getStringFromByteArray(getConstantOrThrow("PLAYER_FEED_SEED"));
+export const FEED_PAGE_SEED = getStringFromByteArray(getConstantOrThrow("FEED_PAGE_SEED"));
 
 // PDAs
 export const [gameStatePDA] = PublicKey.findProgramAddressSync(
-  [Buffer.from(GAME_STATE_SEED)],
+  [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
   PROGRAM_ID
 );
 
+export const getFeedPagePDA = (pageIndex: BN) => {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(FEED_PAGE_SEED),
+      GAME_ID.toArrayLike(Buffer, "le", 8),
+      pageIndex.toArrayLike(Buffer, "le", 8),
+    ],
+    PROGRAM_ID
+  )[0];
+};
+
 export const getPlayerStatePDA = (publicKey: PublicKey) => {
   return PublicKey.findProgramAddressSync(
     [Buffer.from(PLAYER_STATE_SEED), publicKey.toBuffer()],
@@ -35,6 +51,13 @@ export const getPlayerStatePDA = (publicKey: PublicKey) => {
   )[0];
 };
 
+export const getPlayerFeedPDA = (publicKey: PublicKey) => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PLAYER_FEED_SEED), publicKey.toBuffer()],
+    PROGRAM_ID
+  )[0];
+};
+
 export const CIPHER_PACKAGES = [
   {
     amount: 5,
@@ -0,0 +1,163 @@
+import { PublicKey } from "@solana/web3.js";
+import { Card, CardUsage, FeedPayload } from "@/lib/types";
+
+// The program records what happened as a payload and leaves the wording to clients. These
+// messages match the ones of the Rust client's feed module.
+
+/**
+ * Render a feed payload as its English message
+ * @param payload The payload of a feed event
+ * @returns The message, or null for payloads added after this app was built
+ */
+export function feedMessage(payload: FeedPayload): string | null {
+  if (payload.playerJoined) {
+    return `Player ${shortKey(payload.playerJoined.player, 2)} joining the game!`;
+  }
+  if (payload.ciphersPurchased) {
+    const { amount, cost, ciphers } = payload.ciphersPurchased;
+    return `RESOURCES ACQUIRED: ${amount} computational ciphers purchased for ${cost} lamports. Your current reserves: ${ciphers}`;
+  }
+  if (payload.prizePoolSurge) {
+    const { increasePercentage, prizePool } = payload.prizePoolSurge;
+    return `FUNDING SURGE: Protocol recovery fund increased by ${increasePercentage}% to ${prizePool} lamports. Mission priority escalating.`;
+  }
+  if (payload.advanced) {
+    const { position, cardUsage } = payload.advanced;
+    const cards = cardsUsed(cardUsage, "Doubler (2 cards collected)", true);
+    const message = `Advanced to position ${position}!`;
+    return cards.length === 0 ? message : `${message} Cards used: ${cards.join(", ")}`;
+  }
+  if (payload.shieldSaved) {
+    const { position, cardUsage } = payload.shieldSaved;
+    const cards = cardsUsed(cardUsage, "Doubler", false);
+    const message = `Shield protocol activated! Maintaining position ${position}.`;
+    return cards.length === 0 ? message : `${message}. Also used: ${cards.join(", ")}`;
+  }
+  if (payload.reset) {
+    const { fromPosition, cardUsage } = payload.reset;
+    const cards = cardsUsed(cardUsage, "Doubler", false);
+    const message = `CONSENSUS DETECTED: Connection severed at depth ${fromPosition}. Returning to entry point.`;
+    return cards.length === 0 ? message : `${message} Cards used: ${cards.join(", ")}`;
+  }
+  if (payload.cardsCollected) {
+    const names = payload.cardsCollected.cards.map(cardName);
+    return names.length === 1
+      ? `You have collected a new card: ${names[0]}`
+      : `You have collected ${names.length} new cards: ${names.join(", ")}`;
+  }
+  if (payload.cardsRejected) {
+    const { count } = payload.cardsRejected;
+    return count === 1
+      ? "You've reached the maximum for one card type and couldn't collect 1 card."
+      : `You've reached the maximum for some card types and couldn't collect ${count} cards.`;
+  }
+  if (payload.startingHandDealt) {
+    const { cards, prizePool } = payload.startingHandDealt;
+    return `STARTING HAND: ${cards.shield} Shield, ${cards.doubler} Doubler, ${cards.swift} Swift issued for a ${prizePool} lamport pool.`;
+  }
+  if (payload.personalBest) {
+    return `PERSONAL BEST: New furthest infiltration depth reached: ${payload.personalBest.position} steps!`;
+  }
+  if (payload.winStreakRecord) {
+    return `STREAK RECORD: New personal best win streak of ${payload.winStreakRecord.streak} successful infiltrations!`;
+  }
+  if (payload.milestoneReached) {
+    return `CRITICAL: Runner at ${payload.milestoneReached.percentage}% proximity to protocol recovery point. Consensus defenses intensifying.`;
+  }
+  if (payload.systemIntrusion) {
+    return `SYSTEM INTRUSION: Breached ${payload.systemIntrusion.percentage}% of network defenses. Security protocols adapting...`;
+  }
+  if (payload.stepPriceChanged) {
+    const { previous, next } = payload.stepPriceChanged;
+    return `STEP PRICE SHIFT: Next step costs ${next} ciphers (was ${previous}).`;
+  }
+  if (payload.achievementUnlocked) {
+    const { achievement } = payload.achievementUnlocked;
+    if (achievement.ghostProtocol) {
+      return "ACHIEVEMENT UNLOCKED: Ghost Protocol - Completed a run without detection systems!";
+    }
+    if (achievement.dataHoarder) {
+      return "ACHIEVEMENT UNLOCKED: Data Hoarder - Accumulated 100+ protocol fragments!";
+    }
+    if (achievement.consensusBreaker) {
+      return "ACHIEVEMENT UNLOCKED: Consensus Breaker - Penetrated deep into The Consensus network!";
+    }
+    return "ACHIEVEMENT UNLOCKED: Cipher Lord - Mastered the art of computational resource acquisition!";
+  }
+  if (payload.prizeDistributing) {
+    return "PROTOCOL BREACH SUCCESSFUL: Distributing recovered data fragments!";
+  }
+  if (payload.gameWon) {
+    const { player, steps, prize } = payload.gameWon;
+    return `CONSENSUS BREACH: Runner ${shortKey(player, 4)} extracted protocol fragment after ${steps} steps. Reward: ${prize} lamports`;
+  }
+  if (payload.missionComplete) {
+    const { streak, prize } = payload.missionComplete;
+    return streak.toNumber() > 1
+      ? `MISSION COMPLETE: Protocol fragment secured! Streak: ${streak} | Reward: ${prize} lamports`
+      : `MISSION COMPLETE: Protocol fragment secured! Reward: ${prize} lamports`;
+  }
+  if (payload.pathLengthChanged) {
+    const { previous, next } = payload.pathLengthChanged;
+    return `NETWORK REROUTE: Path to the next protocol fragment spans ${next} steps (was ${previous}).`;
+  }
+  if (payload.moveTimedOut) {
+    return `CONNECTION TIMEOUT: Move from depth ${payload.moveTimedOut.position} cancelled, no ciphers spent.`;
+  }
+  if (payload.moveCancelledInLockdown) {
+    return `SYSTEM LOCKDOWN: Move from depth ${payload.moveCancelledInLockdown.position} cancelled, no ciphers spent.`;
+  }
+  if (payload.pauseChanged) {
+    const { paused } = payload.pauseChanged;
+    const halted = [
+      [paused.purchases, "purchases"],
+      [paused.joins, "joins"],
+      [paused.commits, "moves"],
+      [paused.reveals, "reveals"],
+    ]
+      .filter(([isHalted]) => isHalted)
+      .map(([, action]) => action);
+    return halted.length > 0
+      ? `SYSTEM LOCKDOWN: The Consensus froze ${halted.join(", ")}. Stand by, runners.`
+      : "SYSTEM RESTORED: All pathways are open again. Resume your runs.";
+  }
+  if (payload.ciphersCarriedOver) {
+    return `CIPHERS SECURED: ${payload.ciphersCarriedOver.ciphers} unspent ciphers carried into the new run.`;
+  }
+  if (payload.ciphersForfeited) {
+    return `CIPHERS PURGED: ${payload.ciphersForfeited.ciphers} unspent ciphers were lost with the last run.`;
+  }
+  if (payload.ciphersRefunded) {
+    const { ciphers, refund, owed } = payload.ciphersRefunded;
+    return refund.lt(owed)
+      ? `CIPHERS CASHED OUT: ${ciphers} ciphers refunded for ${refund} of ${owed} lamports, reserve dry.`
+      : `CIPHERS CASHED OUT: ${ciphers} unspent ciphers refunded for ${refund} lamports.`;
+  }
+  if (payload.ciphersRedeemed) {
+    const { amount, payout, ciphers } = payload.ciphersRedeemed;
+    return `CIPHERS LIQUIDATED: ${amount} ciphers redeemed for ${payout} lamports. Your current reserves: ${ciphers}`;
+  }
+  return null;
+}
+
+// Shorten a key to its first `prefix` and last four characters
+function shortKey(key: PublicKey, prefix: number): string {
+  const base58 = key.toBase58();
+  return `${base58.slice(0, prefix)}...${base58.slice(-4)}`;
+}
+
+function cardName(card: Card): string {
+  if (card.shield) return "Shield";
+  if (card.doubler) return "Doubler";
+  return "Swift";
+}
+
+// List the cards used alongside the move's main effect. A Shield only shows up on advances,
+// where it wasn't needed.
+function cardsUsed(cardUsage: CardUsage, doubler: string, shield: boolean): string[] {
+  const cards = [];
+  if (cardUsage.doubler) cards.push(doubler);
+  if (shield && cardUsage.shield) cards.push("Shield");
+  if (cardUsage.swift) cards.push("Swift (cipher refund)");
+  return cards;
+}
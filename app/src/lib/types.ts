@@ -4,6 +4,8 @@ import { IdlAccounts, IdlEvents, IdlTypes } from "@coral-xyz/anchor";
 // Accounts
 export type GameState = IdlAccounts<Blockrunners>["gameState"];
 export type PlayerState = IdlAccounts<Blockrunners>["playerState"];
+export type FeedPage = IdlAccounts<Blockrunners>["feedPage"];
+export type PlayerFeed = IdlAccounts<Blockrunners>["playerFeed"];
 
 // Events
 export type SocialFeedEvent = IdlEvents<Blockrunners>["socialFeedEvent"];
@@ -14,10 +16,13 @@ export type CardUsage = IdlTypes<Blockrunners>["cardUsage"];
 export type PathDirection = IdlTypes<Blockrunners>["pathDirection"];
 export type SocialFeedEventType = IdlTypes<Blockrunners>["socialFeedEventType"];
 export type CardCounts = IdlTypes<Blockrunners>["cardCounts"];
+export type FeedEntry = IdlTypes<Blockrunners>["feedEntry"];
+export type FeedPayload = IdlTypes<Blockrunners>["feedPayload"];
 
 // Custom Frontend Types
 export type SocialFeedEventInState = {
   id: string;
+  key: string;
   message: string;
   timestamp: number;
   isNew: boolean;
@@ -0,0 +1,162 @@
+//! Routing of the read-only HTTP API.
+//!
+//! ```text
+//! GET /games/{game_id}              the game and the instance being played
+//! GET /games/{game_id}/wins         wins of the game, newest first
+//! GET /games/{game_id}/feed         feed events logged in the game, newest first
+//! GET /wins                         wins of all games, newest first
+//! GET /leaderboard?by=RANKING       players by best_position (default), games_won,
+//!                                   total_steps or total_ciphers_bought
+//! GET /players/{player}             lifetime statistics of a player
+//! GET /players/{player}/feed        feed events of a player, newest first
+//! ```
+//!
+//! Lists take `limit` (20 by default, at most 100) and `offset`, and return the offset of the
+//! next page while there is one. The indexed data only changes when the indexer stores a
+//! transaction, so every response carries a weak ETag of the indexer's checkpoint and requests
+//! with a matching `If-None-Match` get `304 Not Modified`.
+
+use blockrunners_indexer::store::Store;
+use serde::Serialize;
+
+use crate::queries::{self, FeedFilter, PageRequest, Ranking, MAX_PAGE_SIZE};
+
+/// A response of the API, the body is JSON
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub etag: String,
+    pub body: String,
+}
+
+/// Answer a GET request for `url`, a path with an optional query string
+pub fn handle(store: &Store, url: &str, if_none_match: Option<&str>) -> Response {
+    let etag = match etag(store) {
+        Ok(etag) => etag,
+        Err(error) => return internal_error(String::new(), error),
+    };
+    if if_none_match.is_some_and(|header| matches_etag(header, &etag)) {
+        return Response {
+            status: 304,
+            etag,
+            body: String::new(),
+        };
+    }
+
+    let connection = store.connection();
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let page = match page_request(query) {
+        Ok(page) => page,
+        Err(message) => return error(400, etag, message),
+    };
+
+    let result = match segments.as_slice() {
+        ["games", game_id] => match game_id.parse() {
+            Ok(game_id) => found(queries::game(connection, game_id)),
+            Err(_) => return error(400, etag, format!("invalid game id {game_id}")),
+        },
+        ["games", game_id, list] => {
+            let Ok(game_id) = game_id.parse() else {
+                return error(400, etag, format!("invalid game id {game_id}"));
+            };
+            match *list {
+                "wins" => json(queries::wins(connection, Some(game_id), page)),
+                "feed" => json(queries::feed(connection, &FeedFilter::Game(game_id), page)),
+                _ => return error(404, etag, "not found".to_string()),
+            }
+        }
+        ["wins"] => json(queries::wins(connection, None, page)),
+        ["leaderboard"] => {
+            let name = parameter(query, "by").unwrap_or("best_position");
+            let Some(ranking) = Ranking::from_name(name) else {
+                return error(400, etag, format!("unknown ranking {name}"));
+            };
+            json(queries::leaderboard(connection, ranking, page))
+        }
+        ["players", player] => found(queries::player(connection, player)),
+        ["players", player, "feed"] => json(queries::feed(
+            connection,
+            &FeedFilter::Player(player.to_string()),
+            page,
+        )),
+        _ => return error(404, etag, "not found".to_string()),
+    };
+
+    match result {
+        Ok(Some(body)) => Response {
+            status: 200,
+            etag,
+            body,
+        },
+        Ok(None) => error(404, etag, "not found".to_string()),
+        Err(query_error) => internal_error(etag, query_error),
+    }
+}
+
+/// Weak ETag of the indexed data, which changes with every transaction the indexer stores
+fn etag(store: &Store) -> rusqlite::Result<String> {
+    Ok(match store.checkpoint()? {
+        Some(checkpoint) => format!("W/\"{}-{}\"", checkpoint.slot, checkpoint.signature),
+        None => "W/\"empty\"".to_string(),
+    })
+}
+
+fn matches_etag(header: &str, etag: &str) -> bool {
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate == etag)
+}
+
+fn page_request(query: &str) -> Result<PageRequest, String> {
+    let mut page = PageRequest::default();
+    if let Some(limit) = parameter(query, "limit") {
+        page.limit = limit
+            .parse()
+            .ok()
+            .filter(|limit| (1..=MAX_PAGE_SIZE).contains(limit))
+            .ok_or_else(|| format!("limit must be between 1 and {MAX_PAGE_SIZE}"))?;
+    }
+    if let Some(offset) = parameter(query, "offset") {
+        page.offset = offset
+            .parse()
+            .map_err(|_| format!("invalid offset {offset}"))?;
+    }
+    Ok(page)
+}
+
+/// Get a parameter of a query string. Values used by the API never need decoding.
+fn parameter<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+fn json<T: Serialize>(result: rusqlite::Result<T>) -> rusqlite::Result<Option<String>> {
+    result.map(|value| Some(serde_json::to_string(&value).expect("response serializes")))
+}
+
+/// A single item, not found if the query returned none
+fn found<T: Serialize>(result: rusqlite::Result<Option<T>>) -> rusqlite::Result<Option<String>> {
+    result
+        .map(|value| value.map(|value| serde_json::to_string(&value).expect("response serializes")))
+}
+
+fn error(status: u16, etag: String, message: String) -> Response {
+    Response {
+        status,
+        etag,
+        body: serde_json::json!({ "error": message }).to_string(),
+    }
+}
+
+fn internal_error(etag: String, query_error: rusqlite::Error) -> Response {
+    eprintln!("error: query failed: {query_error}");
+    error(500, etag, "internal error".to_string())
+}
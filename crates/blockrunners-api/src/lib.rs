@@ -0,0 +1,7 @@
+//! Read-only HTTP API of the Blockrunners indexer.
+//!
+//! Serves game summaries, wins, leaderboards, player profiles and the feed history from the
+//! SQLite database the indexer writes, as paginated JSON.
+
+pub mod api;
+pub mod queries;
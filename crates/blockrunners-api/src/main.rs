@@ -0,0 +1,92 @@
+//! Read-only HTTP API of the Blockrunners indexer.
+//!
+//! Serves the database of a running `blockrunners-indexer`, see the `api` module for the
+//! endpoints.
+//!
+//! ```text
+//! blockrunners-api [--db FILE] [--listen ADDRESS]
+//! ```
+
+use std::process;
+
+use blockrunners_api::api::handle;
+use blockrunners_indexer::store::Store;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+struct Options {
+    db: String,
+    listen: String,
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(2);
+    });
+
+    // The indexer owns the database, the API never creates or changes it
+    let store = Store::open_read_only(&options.db).unwrap_or_else(|error| {
+        eprintln!("error: can't open {}: {error}", options.db);
+        process::exit(1);
+    });
+    let server = Server::http(&options.listen).unwrap_or_else(|error| {
+        eprintln!("error: can't listen on {}: {error}", options.listen);
+        process::exit(1);
+    });
+    println!("listening on {}", options.listen);
+
+    for request in server.incoming_requests() {
+        respond(&store, request);
+    }
+}
+
+fn respond(store: &Store, request: Request) {
+    let response = if *request.method() == Method::Get {
+        let if_none_match = request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv("If-None-Match"))
+            .map(|header| header.value.as_str());
+        let answer = handle(store, request.url(), if_none_match);
+        Response::from_string(answer.body)
+            .with_status_code(answer.status)
+            .with_header(header("Content-Type", "application/json"))
+            .with_header(header("ETag", &answer.etag))
+            // Clients may keep responses but revalidate them with the ETag
+            .with_header(header("Cache-Control", "no-cache"))
+    } else {
+        Response::from_string(r#"{"error":"method not allowed"}"#)
+            .with_status_code(405)
+            .with_header(header("Content-Type", "application/json"))
+            .with_header(header("Allow", "GET"))
+    };
+
+    // The client may have gone away
+    if let Err(error) = request.respond(response) {
+        eprintln!("warning: can't respond: {error}");
+    }
+}
+
+fn header(field: &str, value: &str) -> Header {
+    Header::from_bytes(field, value).expect("valid header")
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut db = "blockrunners.sqlite".to_string();
+    let mut listen = "127.0.0.1:8080".to_string();
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {flag}"))
+        };
+
+        match flag.as_str() {
+            "--db" => db = value()?,
+            "--listen" => listen = value()?,
+            other => return Err(format!("unknown argument {other}")),
+        }
+    }
+
+    Ok(Options { db, listen })
+}
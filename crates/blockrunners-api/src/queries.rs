@@ -0,0 +1,345 @@
+//! Queries over the indexer's store.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+
+/// Most items a page holds
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Which part of a list to return
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            limit: 20,
+            offset: 0,
+        }
+    }
+}
+
+/// A page of a list, `next_offset` is set when there are more items
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_offset: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct GameSummary {
+    pub game_id: u64,
+    pub authority: String,
+    pub created_slot: u64,
+    /// Number of instances of the game that were won
+    pub games_won: u64,
+    pub last_win: Option<Win>,
+    /// The instance being played, since the last win
+    pub current: CurrentGame,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CurrentGame {
+    pub start_slot: u64,
+    pub runners: u64,
+    pub moves: u64,
+    pub ciphers_bought: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Win {
+    pub signature: String,
+    pub game_id: u64,
+    pub player: String,
+    pub steps: u8,
+    pub prize: u64,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+}
+
+/// Lifetime statistics of a player, derived from the indexed history like the matching
+/// `PlayerState` fields
+#[derive(Debug, Serialize)]
+pub struct PlayerProfile {
+    pub player: String,
+    pub first_slot: u64,
+    pub last_slot: u64,
+    pub games_won: u64,
+    pub total_ciphers_bought: u64,
+    pub best_position: u8,
+    pub total_steps: u64,
+    pub total_resets: u64,
+    pub shields_used: u64,
+    pub cards_collected: u64,
+    pub system_breaches: u64,
+    pub best_win_streak: u64,
+    /// Names of the unlocked achievements, in the order they were unlocked
+    pub achievements: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardEntry {
+    pub rank: u32,
+    pub player: String,
+    pub games_won: u64,
+    pub total_ciphers_bought: u64,
+    pub best_position: u8,
+    pub total_steps: u64,
+}
+
+/// What the leaderboard ranks players by
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Ranking {
+    BestPosition,
+    GamesWon,
+    TotalSteps,
+    CiphersBought,
+}
+
+impl Ranking {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "best_position" => Some(Self::BestPosition),
+            "games_won" => Some(Self::GamesWon),
+            "total_steps" => Some(Self::TotalSteps),
+            "total_ciphers_bought" => Some(Self::CiphersBought),
+            _ => None,
+        }
+    }
+
+    fn column(self) -> &'static str {
+        match self {
+            Self::BestPosition => "best_position",
+            Self::GamesWon => "games_won",
+            Self::TotalSteps => "total_steps",
+            Self::CiphersBought => "total_ciphers_bought",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedItem {
+    pub signature: String,
+    pub slot: u64,
+    pub timestamp: i64,
+    pub game_id: Option<u64>,
+    pub player: Option<String>,
+    pub event_type: String,
+    pub version: u8,
+    /// Borsh encoding of the program's `FeedPayload`, base64, absent for legacy events
+    pub payload: Option<String>,
+    pub message: String,
+}
+
+/// Whose feed to read
+#[derive(Clone, Debug)]
+pub enum FeedFilter {
+    Game(u64),
+    Player(String),
+}
+
+pub fn game(connection: &Connection, game_id: u64) -> rusqlite::Result<Option<GameSummary>> {
+    let Some((authority, created_slot)) = connection
+        .query_row(
+            "SELECT authority, slot FROM games WHERE game_id = ?1",
+            [game_id],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, u64>(1)?)),
+        )
+        .optional()?
+    else {
+        return Ok(None);
+    };
+
+    let games_won = connection.query_row(
+        "SELECT count(*) FROM wins WHERE game_id = ?1",
+        [game_id],
+        |row| row.get(0),
+    )?;
+    let last_win = wins(
+        connection,
+        Some(game_id),
+        PageRequest {
+            limit: 1,
+            offset: 0,
+        },
+    )?
+    .items
+    .pop();
+
+    // The next instance starts right after a win
+    let start_slot = last_win
+        .as_ref()
+        .map_or(created_slot, |win| win.slot.saturating_add(1));
+    let current = connection.query_row(
+        "SELECT
+             (SELECT count(DISTINCT player) FROM joins WHERE game_id = ?1 AND slot >= ?2),
+             (SELECT count(*) FROM moves WHERE game_id = ?1 AND commit_slot >= ?2),
+             (SELECT coalesce(sum(amount), 0) FROM purchases WHERE game_id = ?1 AND slot >= ?2)",
+        params![game_id, start_slot],
+        |row| {
+            Ok(CurrentGame {
+                start_slot,
+                runners: row.get(0)?,
+                moves: row.get(1)?,
+                ciphers_bought: row.get(2)?,
+            })
+        },
+    )?;
+
+    Ok(Some(GameSummary {
+        game_id,
+        authority,
+        created_slot,
+        games_won,
+        last_win,
+        current,
+    }))
+}
+
+/// Wins, newest first, of one game or all games
+pub fn wins(
+    connection: &Connection,
+    game_id: Option<u64>,
+    page: PageRequest,
+) -> rusqlite::Result<Page<Win>> {
+    let mut statement = connection.prepare(
+        "SELECT signature, game_id, player, steps, prize, slot, block_time FROM wins
+         WHERE ?1 IS NULL OR game_id = ?1
+         ORDER BY slot DESC, signature
+         LIMIT ?2 OFFSET ?3",
+    )?;
+    let rows = statement.query_map(params![game_id, page.limit + 1, page.offset], |row| {
+        Ok(Win {
+            signature: row.get(0)?,
+            game_id: row.get(1)?,
+            player: row.get(2)?,
+            steps: row.get(3)?,
+            prize: row.get(4)?,
+            slot: row.get(5)?,
+            block_time: row.get(6)?,
+        })
+    })?;
+    Ok(paginate(rows.collect::<rusqlite::Result<_>>()?, page))
+}
+
+pub fn player(connection: &Connection, player: &str) -> rusqlite::Result<Option<PlayerProfile>> {
+    let Some(mut profile) = connection
+        .query_row(
+            "SELECT player, first_slot, last_slot, games_won, total_ciphers_bought,
+                 best_position, total_steps, total_resets, shields_used, cards_collected,
+                 system_breaches, best_win_streak
+             FROM players JOIN player_stats USING (player)
+             WHERE player = ?1",
+            [player],
+            |row| {
+                Ok(PlayerProfile {
+                    player: row.get(0)?,
+                    first_slot: row.get(1)?,
+                    last_slot: row.get(2)?,
+                    games_won: row.get(3)?,
+                    total_ciphers_bought: row.get(4)?,
+                    best_position: row.get(5)?,
+                    total_steps: row.get(6)?,
+                    total_resets: row.get(7)?,
+                    shields_used: row.get(8)?,
+                    cards_collected: row.get(9)?,
+                    system_breaches: row.get(10)?,
+                    best_win_streak: row.get(11)?,
+                    achievements: Vec::new(),
+                })
+            },
+        )
+        .optional()?
+    else {
+        return Ok(None);
+    };
+
+    let mut statement = connection.prepare(
+        "SELECT achievement FROM achievements WHERE player = ?1 ORDER BY slot, achievement",
+    )?;
+    profile.achievements = statement
+        .query_map([player], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(Some(profile))
+}
+
+pub fn leaderboard(
+    connection: &Connection,
+    ranking: Ranking,
+    page: PageRequest,
+) -> rusqlite::Result<Page<LeaderboardEntry>> {
+    let mut statement = connection.prepare(&format!(
+        "SELECT player, games_won, total_ciphers_bought, best_position, total_steps
+         FROM player_stats
+         ORDER BY {} DESC, player
+         LIMIT ?1 OFFSET ?2",
+        ranking.column()
+    ))?;
+    let rows = statement.query_map(params![page.limit + 1, page.offset], |row| {
+        Ok(LeaderboardEntry {
+            rank: 0,
+            player: row.get(0)?,
+            games_won: row.get(1)?,
+            total_ciphers_bought: row.get(2)?,
+            best_position: row.get(3)?,
+            total_steps: row.get(4)?,
+        })
+    })?;
+
+    let mut items = paginate(rows.collect::<rusqlite::Result<_>>()?, page);
+    for (rank, entry) in (page.offset + 1..).zip(items.items.iter_mut()) {
+        entry.rank = rank;
+    }
+    Ok(items)
+}
+
+/// Feed events, newest first
+pub fn feed(
+    connection: &Connection,
+    filter: &FeedFilter,
+    page: PageRequest,
+) -> rusqlite::Result<Page<FeedItem>> {
+    let (condition, value): (&str, rusqlite::types::Value) = match filter {
+        FeedFilter::Game(game_id) => ("game_id = ?1", (*game_id as i64).into()),
+        FeedFilter::Player(player) => ("player = ?1", player.clone().into()),
+    };
+    let mut statement = connection.prepare(&format!(
+        "SELECT signature, slot, timestamp, game_id, player, event_type, version, payload, message
+         FROM feed_events WHERE {condition}
+         ORDER BY slot DESC, signature, instruction_index DESC, event_index DESC
+         LIMIT ?2 OFFSET ?3"
+    ))?;
+    let rows = statement.query_map(params![value, page.limit + 1, page.offset], feed_item)?;
+    Ok(paginate(rows.collect::<rusqlite::Result<_>>()?, page))
+}
+
+fn feed_item(row: &Row) -> rusqlite::Result<FeedItem> {
+    Ok(FeedItem {
+        signature: row.get(0)?,
+        slot: row.get(1)?,
+        timestamp: row.get(2)?,
+        game_id: row.get(3)?,
+        player: row.get(4)?,
+        event_type: row.get(5)?,
+        version: row.get(6)?,
+        payload: row
+            .get::<_, Option<Vec<u8>>>(7)?
+            .map(|payload| STANDARD.encode(payload)),
+        message: row.get(8)?,
+    })
+}
+
+/// Cut the extra item a query fetched to tell whether there's a next page
+fn paginate<T>(mut items: Vec<T>, page: PageRequest) -> Page<T> {
+    let more = items.len() > page.limit as usize;
+    items.truncate(page.limit as usize);
+    Page {
+        next_offset: more.then(|| page.offset + page.limit),
+        items,
+    }
+}
@@ -0,0 +1,267 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, Event};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blockrunners::{
+    state::{Achievement, Card, CardUsage, FeedPayload, PathDirection, SocialFeedEvent},
+    ID,
+};
+use blockrunners_api::api::{handle, Response};
+use blockrunners_client::instructions;
+use blockrunners_indexer::{decode::ProgramTransaction, store::Store};
+use serde_json::Value;
+use solana_sdk::signature::Signature;
+
+const GAME_ID: u64 = 1;
+
+/// A successful transaction whose instructions logged `events`, one list per instruction
+fn transaction(
+    slot: u64,
+    instructions: Vec<Instruction>,
+    events: Vec<Vec<FeedPayload>>,
+) -> ProgramTransaction {
+    let mut logs = Vec::new();
+    for payloads in &events {
+        logs.push(format!("Program {ID} invoke [1]"));
+        for payload in payloads {
+            let event = SocialFeedEvent::new(payload.clone(), slot as i64);
+            logs.push(format!("Program data: {}", STANDARD.encode(event.data())));
+        }
+        logs.push(format!("Program {ID} success"));
+    }
+
+    ProgramTransaction {
+        signature: Signature::new_unique(),
+        slot,
+        block_time: Some(slot as i64),
+        succeeded: true,
+        instructions,
+        logs,
+    }
+}
+
+/// Transactions of `player` joining at `slot` and advancing to each of `positions`, the last
+/// move also logs `finish`
+fn run(
+    slot: u64,
+    player: Pubkey,
+    positions: &[u8],
+    finish: &[FeedPayload],
+) -> Vec<ProgramTransaction> {
+    let mut transactions = vec![transaction(
+        slot,
+        vec![
+            instructions::initialize_player(player),
+            instructions::join_game(player, GAME_ID, 0),
+        ],
+        vec![vec![], vec![FeedPayload::PlayerJoined { player }]],
+    )];
+
+    for (step, &position) in (1..).zip(positions) {
+        let randomness = Pubkey::new_unique();
+        let mut events = vec![
+            FeedPayload::Advanced {
+                position,
+                card_usage: CardUsage::default(),
+            },
+            FeedPayload::CardsCollected {
+                cards: vec![Card::Shield],
+            },
+        ];
+        if step == positions.len() as u64 {
+            events.extend_from_slice(finish);
+        }
+        transactions.push(transaction(
+            slot + 2 * step - 1,
+            vec![instructions::move_commit(
+                player,
+                GAME_ID,
+                randomness,
+                PathDirection::Right,
+                CardUsage::default(),
+            )],
+            vec![vec![]],
+        ));
+        transactions.push(transaction(
+            slot + 2 * step,
+            vec![instructions::move_reveal(player, GAME_ID, randomness, 0)],
+            vec![events],
+        ));
+    }
+    transactions
+}
+
+/// A store where `winner` won the game in one step, then `runner` bought ciphers and advanced
+/// three steps
+fn store(winner: Pubkey, runner: Pubkey) -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    let admin = Pubkey::new_unique();
+
+    let mut transactions = vec![transaction(
+        1,
+        vec![instructions::initialize_game(admin, GAME_ID)],
+        vec![vec![]],
+    )];
+    transactions.extend(run(
+        2,
+        winner,
+        &[1],
+        &[
+            FeedPayload::WinStreakRecord { streak: 1 },
+            FeedPayload::SystemIntrusion { percentage: 100 },
+            FeedPayload::AchievementUnlocked {
+                achievement: Achievement::GhostProtocol,
+            },
+            FeedPayload::GameWon {
+                player: winner,
+                steps: 1,
+                prize: 900,
+            },
+        ],
+    ));
+    transactions.push(transaction(
+        10,
+        vec![instructions::purchase_ciphers(runner, admin, GAME_ID, 5, 0)],
+        vec![vec![FeedPayload::CiphersPurchased {
+            amount: 5,
+            cost: 500,
+            ciphers: 5,
+        }]],
+    ));
+    transactions.extend(run(11, runner, &[1, 2, 3], &[]));
+
+    for transaction in &transactions {
+        store.index(transaction).unwrap();
+    }
+    store
+}
+
+fn get(store: &Store, url: &str) -> Value {
+    let response = handle(store, url, None);
+    assert_eq!(response.status, 200, "{url}: {}", response.body);
+    serde_json::from_str(&response.body).unwrap()
+}
+
+#[test]
+fn game_summarizes_wins_and_the_instance_being_played() {
+    let winner = Pubkey::new_unique();
+    let store = store(winner, Pubkey::new_unique());
+
+    let game = get(&store, "/games/1");
+    assert_eq!(game["games_won"], 1);
+    assert_eq!(game["last_win"]["player"], winner.to_string());
+    assert_eq!(game["last_win"]["prize"], 900);
+    assert_eq!(game["current"]["start_slot"], 5);
+    assert_eq!(game["current"]["runners"], 1);
+    assert_eq!(game["current"]["moves"], 3);
+    assert_eq!(game["current"]["ciphers_bought"], 5);
+
+    assert_eq!(handle(&store, "/games/2", None).status, 404);
+    assert_eq!(handle(&store, "/games/first", None).status, 400);
+}
+
+#[test]
+fn leaderboard_is_ranked_and_paginated() {
+    let winner = Pubkey::new_unique();
+    let runner = Pubkey::new_unique();
+    let store = store(winner, runner);
+
+    let by_position = get(&store, "/leaderboard");
+    assert_eq!(by_position["items"][0]["player"], runner.to_string());
+    assert_eq!(by_position["items"][0]["best_position"], 3);
+    assert_eq!(by_position["next_offset"], Value::Null);
+
+    let first = get(&store, "/leaderboard?by=games_won&limit=1");
+    assert_eq!(first["items"][0]["player"], winner.to_string());
+    assert_eq!(first["items"][0]["rank"], 1);
+    assert_eq!(first["next_offset"], 1);
+
+    let second = get(&store, "/leaderboard?by=games_won&limit=1&offset=1");
+    assert_eq!(second["items"][0]["player"], runner.to_string());
+    assert_eq!(second["items"][0]["rank"], 2);
+    assert_eq!(second["next_offset"], Value::Null);
+
+    assert_eq!(handle(&store, "/leaderboard?by=luck", None).status, 400);
+    assert_eq!(handle(&store, "/leaderboard?limit=101", None).status, 400);
+}
+
+#[test]
+fn player_profile_matches_the_indexed_history() {
+    let winner = Pubkey::new_unique();
+    let runner = Pubkey::new_unique();
+    let store = store(winner, runner);
+
+    let profile = get(&store, &format!("/players/{runner}"));
+    assert_eq!(profile["total_steps"], 3);
+    assert_eq!(profile["best_position"], 3);
+    assert_eq!(profile["cards_collected"], 3);
+    assert_eq!(profile["total_ciphers_bought"], 5);
+    assert_eq!(profile["games_won"], 0);
+    assert_eq!(profile["system_breaches"], 0);
+    assert_eq!(profile["achievements"], serde_json::json!([]));
+
+    let profile = get(&store, &format!("/players/{winner}"));
+    assert_eq!(profile["games_won"], 1);
+    assert_eq!(profile["best_win_streak"], 1);
+    assert_eq!(profile["system_breaches"], 1);
+    assert_eq!(
+        profile["achievements"],
+        serde_json::json!(["GhostProtocol"])
+    );
+
+    let stranger = Pubkey::new_unique();
+    assert_eq!(
+        handle(&store, &format!("/players/{stranger}"), None).status,
+        404
+    );
+}
+
+#[test]
+fn feeds_list_the_newest_events_first() {
+    let winner = Pubkey::new_unique();
+    let runner = Pubkey::new_unique();
+    let store = store(winner, runner);
+
+    let feed = get(&store, &format!("/players/{winner}/feed"));
+    let types: Vec<&str> = feed["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["event_type"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        types,
+        [
+            "GameWon",
+            "ProtocolFragment",
+            "SystemIntrusion",
+            "PersonalBest",
+            "PlayerCardCollected",
+            "PlayerMoved",
+            "PlayerJoined"
+        ]
+    );
+
+    let game_feed = get(&store, "/games/1/feed?limit=2");
+    assert_eq!(game_feed["items"][0]["slot"], 17);
+    assert_eq!(game_feed["next_offset"], 2);
+}
+
+#[test]
+fn unchanged_data_is_not_modified() {
+    let mut store = store(Pubkey::new_unique(), Pubkey::new_unique());
+
+    let Response { etag, .. } = handle(&store, "/wins", None);
+    assert!(etag.starts_with("W/\"17-"));
+    let cached = handle(&store, "/wins", Some(&etag));
+    assert_eq!((cached.status, cached.body.as_str()), (304, ""));
+
+    store
+        .index(&transaction(
+            18,
+            vec![instructions::initialize_player(Pubkey::new_unique())],
+            vec![vec![]],
+        ))
+        .unwrap();
+    let changed = handle(&store, "/wins", Some(&etag));
+    assert_eq!(changed.status, 200);
+    assert_ne!(changed.etag, etag);
+}
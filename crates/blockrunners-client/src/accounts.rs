@@ -0,0 +1,34 @@
+use anchor_lang::{AccountDeserialize, Result};
+use blockrunners::state::{
+    FeedPage, GameConfig, GameState, PlayerFeed, PlayerState, ScriptedRandomness,
+};
+
+/// Decode the data of a game state account
+pub fn decode_game_state(data: &[u8]) -> Result<GameState> {
+    GameState::try_deserialize(&mut &data[..])
+}
+
+/// Decode the data of a game config account
+pub fn decode_game_config(data: &[u8]) -> Result<GameConfig> {
+    GameConfig::try_deserialize(&mut &data[..])
+}
+
+/// Decode the data of a page of a game's feed history
+pub fn decode_feed_page(data: &[u8]) -> Result<FeedPage> {
+    FeedPage::try_deserialize(&mut &data[..])
+}
+
+/// Decode the data of a player state account
+pub fn decode_player_state(data: &[u8]) -> Result<PlayerState> {
+    PlayerState::try_deserialize(&mut &data[..])
+}
+
+/// Decode the data of a player feed account, its entries are read with `PlayerFeed::entries`
+pub fn decode_player_feed(data: &[u8]) -> Result<PlayerFeed> {
+    PlayerFeed::try_deserialize(&mut &data[..])
+}
+
+/// Decode the data of a scripted randomness queue
+pub fn decode_scripted_randomness(data: &[u8]) -> Result<ScriptedRandomness> {
+    ScriptedRandomness::try_deserialize(&mut &data[..])
+}
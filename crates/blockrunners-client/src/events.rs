@@ -0,0 +1,50 @@
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blockrunners::state::{
+    AuthorityChanged, FeeRecipientChanged, GameConfigChanged, SocialFeedEvent,
+};
+
+use crate::feed::VersionedFeedEvent;
+
+/// Prefix of the log lines `emit!` writes events to
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// Decode all events of type `T` from the log messages of a transaction.
+///
+/// Lines that aren't event data, or are events of another type or program, are skipped.
+pub fn decode_events<T: AnchorDeserialize + Discriminator>(logs: &[String]) -> Vec<T> {
+    event_data::<T>(logs)
+        .filter_map(|data| T::try_from_slice(&data).ok())
+        .collect()
+}
+
+/// Decode the social feed events from the log messages of a transaction, including the ones
+/// logged in older layouts
+pub fn decode_social_feed_events(logs: &[String]) -> Vec<VersionedFeedEvent> {
+    event_data::<SocialFeedEvent>(logs)
+        .filter_map(|data| VersionedFeedEvent::decode(&data))
+        .collect()
+}
+
+/// Decode the config changes from the log messages of a transaction
+pub fn decode_config_changes(logs: &[String]) -> Vec<GameConfigChanged> {
+    decode_events(logs)
+}
+
+/// Decode the authority transfers from the log messages of a transaction
+pub fn decode_authority_changes(logs: &[String]) -> Vec<AuthorityChanged> {
+    decode_events(logs)
+}
+
+/// Decode the fee recipient changes from the log messages of a transaction
+pub fn decode_fee_recipient_changes(logs: &[String]) -> Vec<FeeRecipientChanged> {
+    decode_events(logs)
+}
+
+/// Get the data of the logged events of type `T`, without their discriminator
+fn event_data<T: Discriminator>(logs: &[String]) -> impl Iterator<Item = Vec<u8>> + '_ {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix(PROGRAM_DATA))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|data| Some(data.strip_prefix(T::DISCRIMINATOR)?.to_vec()))
+}
@@ -0,0 +1,308 @@
+//! Rendering of the social feed.
+//!
+//! The program records what happened as a [`FeedPayload`] and leaves the wording to clients.
+//! [`feed_message`] renders the payloads in the game's own English voice; a translation
+//! matches on the payload the same way.
+
+use anchor_lang::{
+    prelude::{borsh, Pubkey},
+    AnchorDeserialize,
+};
+use blockrunners::{
+    constants::FEED_EVENT_VERSION,
+    state::{Achievement, CardUsage, FeedPayload, SocialFeedEvent, SocialFeedEventType},
+};
+
+/// A feed event the program logged before events carried a version, with the message
+/// formatted on-chain
+#[derive(AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct LegacySocialFeedEvent {
+    pub event_type: SocialFeedEventType,
+    pub message: String,
+    pub timestamp: i64,
+}
+
+/// A feed event in any of the layouts the program has logged
+#[derive(Clone, Debug)]
+pub enum VersionedFeedEvent {
+    Legacy(LegacySocialFeedEvent),
+    Current(SocialFeedEvent),
+}
+
+impl VersionedFeedEvent {
+    /// Decode the data of a logged feed event, without its discriminator
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        // Legacy events start with the event type instead of the version, which is never as
+        // high as a version
+        if data.first() == Some(&FEED_EVENT_VERSION) {
+            SocialFeedEvent::try_from_slice(data)
+                .ok()
+                .map(Self::Current)
+        } else {
+            LegacySocialFeedEvent::try_from_slice(data)
+                .ok()
+                .map(Self::Legacy)
+        }
+    }
+
+    pub fn event_type(&self) -> SocialFeedEventType {
+        match self {
+            Self::Legacy(event) => event.event_type,
+            Self::Current(event) => event.event_type,
+        }
+    }
+
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            Self::Legacy(event) => event.timestamp,
+            Self::Current(event) => event.timestamp,
+        }
+    }
+
+    /// Get the event's message, as formatted on-chain for legacy events
+    pub fn message(&self) -> String {
+        match self {
+            Self::Legacy(event) => event.message.clone(),
+            Self::Current(event) => feed_message(event),
+        }
+    }
+}
+
+/// Render a feed event as its English message
+pub fn feed_message(event: &SocialFeedEvent) -> String {
+    payload_message(&event.payload)
+}
+
+/// Render a feed payload as its English message
+pub fn payload_message(payload: &FeedPayload) -> String {
+    match payload {
+        FeedPayload::PlayerJoined { player } => {
+            format!("Player {} joining the game!", short_key(player, 2))
+        }
+        FeedPayload::CiphersPurchased {
+            amount,
+            cost,
+            ciphers,
+        } => format!(
+            "RESOURCES ACQUIRED: {} computational ciphers purchased for {} lamports. Your current reserves: {}",
+            amount, cost, ciphers
+        ),
+        FeedPayload::PrizePoolSurge {
+            increase_percentage,
+            prize_pool,
+        } => format!(
+            "FUNDING SURGE: Protocol recovery fund increased by {}% to {} lamports. Mission priority escalating.",
+            increase_percentage, prize_pool
+        ),
+        FeedPayload::Advanced {
+            position,
+            card_usage,
+        } => {
+            let message = format!("Advanced to position {}!", position);
+            let cards = cards_used(card_usage, "Doubler (2 cards collected)", true);
+            if cards.is_empty() {
+                message
+            } else {
+                format!("{} Cards used: {}", message, cards.join(", "))
+            }
+        }
+        FeedPayload::ShieldSaved {
+            position,
+            card_usage,
+        } => {
+            let message = format!(
+                "Shield protocol activated! Maintaining position {}.",
+                position
+            );
+            let cards = cards_used(card_usage, "Doubler", false);
+            if cards.is_empty() {
+                message
+            } else {
+                format!("{}. Also used: {}", message, cards.join(", "))
+            }
+        }
+        FeedPayload::Reset {
+            from_position,
+            card_usage,
+        } => {
+            let message = format!(
+                "CONSENSUS DETECTED: Connection severed at depth {}. Returning to entry point.",
+                from_position
+            );
+            let cards = cards_used(card_usage, "Doubler", false);
+            if cards.is_empty() {
+                message
+            } else {
+                format!("{} Cards used: {}", message, cards.join(", "))
+            }
+        }
+        FeedPayload::CardsCollected { cards } => {
+            if cards.len() == 1 {
+                format!("You have collected a new card: {:?}", cards[0])
+            } else {
+                let cards_str = cards
+                    .iter()
+                    .map(|card| format!("{:?}", card))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                format!("You have collected {} new cards: {}", cards.len(), cards_str)
+            }
+        }
+        FeedPayload::CardsRejected { count } => {
+            if *count == 1 {
+                "You've reached the maximum for one card type and couldn't collect 1 card."
+                    .to_string()
+            } else {
+                format!(
+                    "You've reached the maximum for some card types and couldn't collect {} cards.",
+                    count
+                )
+            }
+        }
+        FeedPayload::StartingHandDealt { cards, prize_pool } => format!(
+            "STARTING HAND: {} Shield, {} Doubler, {} Swift issued for a {} lamport pool.",
+            cards.shield, cards.doubler, cards.swift, prize_pool
+        ),
+        FeedPayload::PersonalBest { position } => format!(
+            "PERSONAL BEST: New furthest infiltration depth reached: {} steps!",
+            position
+        ),
+        FeedPayload::WinStreakRecord { streak } => format!(
+            "STREAK RECORD: New personal best win streak of {} successful infiltrations!",
+            streak
+        ),
+        FeedPayload::MilestoneReached { percentage } => format!(
+            "CRITICAL: Runner at {}% proximity to protocol recovery point. Consensus defenses intensifying.",
+            percentage
+        ),
+        FeedPayload::SystemIntrusion { percentage } => format!(
+            "SYSTEM INTRUSION: Breached {}% of network defenses. Security protocols adapting...",
+            percentage
+        ),
+        FeedPayload::StepPriceChanged { previous, next } => format!(
+            "STEP PRICE SHIFT: Next step costs {} ciphers (was {}).",
+            next, previous
+        ),
+        FeedPayload::AchievementUnlocked { achievement } => match achievement {
+            Achievement::GhostProtocol => "ACHIEVEMENT UNLOCKED: Ghost Protocol - Completed a run without detection systems!",
+            Achievement::DataHoarder => "ACHIEVEMENT UNLOCKED: Data Hoarder - Accumulated 100+ protocol fragments!",
+            Achievement::ConsensusBreaker => "ACHIEVEMENT UNLOCKED: Consensus Breaker - Penetrated deep into The Consensus network!",
+            Achievement::CipherLord => "ACHIEVEMENT UNLOCKED: Cipher Lord - Mastered the art of computational resource acquisition!",
+        }
+        .to_string(),
+        FeedPayload::PrizeDistributing => {
+            "PROTOCOL BREACH SUCCESSFUL: Distributing recovered data fragments!".to_string()
+        }
+        FeedPayload::GameWon {
+            player,
+            steps,
+            prize,
+        } => format!(
+            "CONSENSUS BREACH: Runner {} extracted protocol fragment after {} steps. Reward: {} lamports",
+            short_key(player, 4),
+            steps,
+            prize
+        ),
+        FeedPayload::MissionComplete { streak, prize } => {
+            if *streak > 1 {
+                format!(
+                    "MISSION COMPLETE: Protocol fragment secured! Streak: {} | Reward: {} lamports",
+                    streak, prize
+                )
+            } else {
+                format!(
+                    "MISSION COMPLETE: Protocol fragment secured! Reward: {} lamports",
+                    prize
+                )
+            }
+        }
+        FeedPayload::PathLengthChanged { previous, next } => format!(
+            "NETWORK REROUTE: Path to the next protocol fragment spans {} steps (was {}).",
+            next, previous
+        ),
+        FeedPayload::MoveTimedOut { position } => format!(
+            "CONNECTION TIMEOUT: Move from depth {} cancelled, no ciphers spent.",
+            position
+        ),
+        FeedPayload::MoveCancelledInLockdown { position } => format!(
+            "SYSTEM LOCKDOWN: Move from depth {} cancelled, no ciphers spent.",
+            position
+        ),
+        FeedPayload::PauseChanged { paused } => {
+            if paused.is_paused() {
+                let halted = [
+                    (paused.purchases, "purchases"),
+                    (paused.joins, "joins"),
+                    (paused.commits, "moves"),
+                    (paused.reveals, "reveals"),
+                ]
+                .iter()
+                .filter(|(halted, _)| *halted)
+                .map(|(_, action)| *action)
+                .collect::<Vec<_>>()
+                .join(", ");
+                format!(
+                    "SYSTEM LOCKDOWN: The Consensus froze {}. Stand by, runners.",
+                    halted
+                )
+            } else {
+                "SYSTEM RESTORED: All pathways are open again. Resume your runs.".to_string()
+            }
+        }
+        FeedPayload::CiphersCarriedOver { ciphers } => format!(
+            "CIPHERS SECURED: {} unspent ciphers carried into the new run.",
+            ciphers
+        ),
+        FeedPayload::CiphersForfeited { ciphers } => format!(
+            "CIPHERS PURGED: {} unspent ciphers were lost with the last run.",
+            ciphers
+        ),
+        FeedPayload::CiphersRefunded {
+            ciphers,
+            refund,
+            owed,
+        } => {
+            if refund < owed {
+                format!(
+                    "CIPHERS CASHED OUT: {} ciphers refunded for {} of {} lamports, reserve dry.",
+                    ciphers, refund, owed
+                )
+            } else {
+                format!(
+                    "CIPHERS CASHED OUT: {} unspent ciphers refunded for {} lamports.",
+                    ciphers, refund
+                )
+            }
+        }
+        FeedPayload::CiphersRedeemed {
+            amount,
+            payout,
+            ciphers,
+        } => format!(
+            "CIPHERS LIQUIDATED: {} ciphers redeemed for {} lamports. Your current reserves: {}",
+            amount, payout, ciphers
+        ),
+    }
+}
+
+/// Shorten a key to its first `prefix` and last four characters
+fn short_key(key: &Pubkey, prefix: usize) -> String {
+    let key = key.to_string();
+    format!("{}...{}", &key[..prefix], &key[key.len() - 4..])
+}
+
+/// List the cards used alongside the move's main effect. A Shield only shows up on advances,
+/// where it wasn't needed.
+fn cards_used(card_usage: &CardUsage, doubler: &'static str, shield: bool) -> Vec<&'static str> {
+    let mut cards = Vec::new();
+    if card_usage.doubler {
+        cards.push(doubler);
+    }
+    if shield && card_usage.shield {
+        cards.push("Shield");
+    }
+    if card_usage.swift {
+        cards.push("Swift (cipher refund)");
+    }
+    cards
+}
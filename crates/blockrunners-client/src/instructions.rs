@@ -0,0 +1,392 @@
+//! Builders for the instructions of the program.
+//!
+//! Every builder derives the PDAs from the game id and the signer, so callers only pass the
+//! keys that can't be derived. `randomness_account` is the account matching the randomness
+//! provider of the game: a Switchboard randomness account, the slot hashes sysvar
+//! ([`anchor_lang::solana_program::sysvar::slot_hashes::ID`]) or the game state account for the
+//! local oracle ([`crate::pda::game_state`]). Instructions that write to the game feed take
+//! the index of the game's current feed page, [`blockrunners::state::GameState::feed_page`].
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    system_program, InstructionData, ToAccountMetas,
+};
+use blockrunners::{
+    accounts, instruction,
+    state::{CardUsage, GameConfigParams, PathDirection, PauseFlags},
+    ID,
+};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Create game `game_id` with `admin` as its authority
+pub fn initialize_game(admin: Pubkey, game_id: u64) -> Instruction {
+    build(
+        accounts::InitializeGame {
+            admin,
+            game_state: pda::game_state(game_id),
+            game_config: pda::game_config(game_id),
+            feed_page: pda::feed_page(game_id, 0),
+            prize_vault: pda::prize_vault(game_id),
+            reserve_vault: pda::reserve_vault(game_id),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeGame { game_id },
+    )
+}
+
+/// Replace the config of game `game_id`
+pub fn update_config(authority: Pubkey, game_id: u64, params: GameConfigParams) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            authority,
+            game_state: pda::game_state(game_id),
+            game_config: pda::game_config(game_id),
+        },
+        instruction::UpdateConfig { game_id, params },
+    )
+}
+
+/// Propose `new_authority` as the next authority of game `game_id`, `None` withdraws the proposal
+pub fn propose_authority(
+    authority: Pubkey,
+    game_id: u64,
+    new_authority: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            authority,
+            game_state: pda::game_state(game_id),
+        },
+        instruction::ProposeAuthority {
+            game_id,
+            new_authority,
+        },
+    )
+}
+
+/// Take over game `game_id` as its proposed authority
+pub fn accept_authority(new_authority: Pubkey, game_id: u64) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            new_authority,
+            game_state: pda::game_state(game_id),
+        },
+        instruction::AcceptAuthority { game_id },
+    )
+}
+
+/// Send the admin share of cipher purchases in game `game_id` to `fee_recipient`
+pub fn set_fee_recipient(authority: Pubkey, game_id: u64, fee_recipient: Pubkey) -> Instruction {
+    build(
+        accounts::SetFeeRecipient {
+            authority,
+            game_state: pda::game_state(game_id),
+            fee_recipient,
+        },
+        instruction::SetFeeRecipient { game_id },
+    )
+}
+
+/// Halt or resume the player actions of game `game_id`
+pub fn set_pause(
+    authority: Pubkey,
+    game_id: u64,
+    paused: PauseFlags,
+    feed_page: u64,
+) -> Instruction {
+    build(
+        accounts::SetPause {
+            authority,
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, feed_page),
+            system_program: system_program::ID,
+        },
+        instruction::SetPause { game_id, paused },
+    )
+}
+
+/// Add `amount` lamports from `funder` to the reserve vault of game `game_id`
+pub fn fund_reserve(funder: Pubkey, game_id: u64, amount: u64) -> Instruction {
+    build(
+        accounts::FundReserve {
+            funder,
+            game_state: pda::game_state(game_id),
+            reserve_vault: pda::reserve_vault(game_id),
+            system_program: system_program::ID,
+        },
+        instruction::FundReserve { game_id, amount },
+    )
+}
+
+/// Open page `page_index` of the feed of game `game_id` in advance, paid by the authority
+pub fn open_feed_page(authority: Pubkey, game_id: u64, page_index: u64) -> Instruction {
+    build(
+        accounts::OpenFeedPage {
+            authority,
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, page_index),
+            system_program: system_program::ID,
+        },
+        instruction::OpenFeedPage {
+            game_id,
+            page_index,
+        },
+    )
+}
+
+/// Close a feed page the game moved on from, refunding its rent to whoever paid it
+pub fn close_feed_page(
+    authority: Pubkey,
+    game_id: u64,
+    page_index: u64,
+    payer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::CloseFeedPage {
+            authority,
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, page_index),
+            payer,
+        },
+        instruction::CloseFeedPage {
+            game_id,
+            page_index,
+        },
+    )
+}
+
+/// Create the state account of `player`
+pub fn initialize_player(player: Pubkey) -> Instruction {
+    build(
+        accounts::InitializePlayer {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            system_program: system_program::ID,
+        },
+        instruction::InitializePlayer {},
+    )
+}
+
+/// Close the state account of `player` and return its rent, archiving the lifetime statistics
+/// if `archive` is set. Unspent ciphers are only given up with `forfeit_ciphers`.
+pub fn close_player(player: Pubkey, forfeit_ciphers: bool, archive: bool) -> Instruction {
+    build(
+        accounts::ClosePlayer {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            player_archive: archive.then(|| pda::player_archive(&player)),
+            system_program: system_program::ID,
+        },
+        instruction::ClosePlayer { forfeit_ciphers },
+    )
+}
+
+/// Join the current instance of game `game_id`
+pub fn join_game(player: Pubkey, game_id: u64, feed_page: u64) -> Instruction {
+    build(
+        accounts::JoinGame {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, feed_page),
+            game_config: pda::game_config(game_id),
+            reserve_vault: pda::reserve_vault(game_id),
+            system_program: system_program::ID,
+        },
+        instruction::JoinGame { game_id },
+    )
+}
+
+/// Buy `amount` ciphers, `fee_recipient` must be the fee recipient of the game
+pub fn purchase_ciphers(
+    player: Pubkey,
+    fee_recipient: Pubkey,
+    game_id: u64,
+    amount: u64,
+    feed_page: u64,
+) -> Instruction {
+    build(
+        accounts::PurchaseCiphers {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, feed_page),
+            game_config: pda::game_config(game_id),
+            prize_vault: pda::prize_vault(game_id),
+            admin_wallet: fee_recipient,
+            system_program: system_program::ID,
+        },
+        instruction::PurchaseCiphers { game_id, amount },
+    )
+}
+
+/// Sell `amount` unspent ciphers back to the reserve vault of game `game_id`
+pub fn redeem_ciphers(player: Pubkey, game_id: u64, amount: u64) -> Instruction {
+    build(
+        accounts::RedeemCiphers {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            game_state: pda::game_state(game_id),
+            game_config: pda::game_config(game_id),
+            reserve_vault: pda::reserve_vault(game_id),
+            system_program: system_program::ID,
+        },
+        instruction::RedeemCiphers { game_id, amount },
+    )
+}
+
+/// Commit to the direction and cards of the next move
+pub fn move_commit(
+    player: Pubkey,
+    game_id: u64,
+    randomness_account: Pubkey,
+    direction: PathDirection,
+    card_usage: CardUsage,
+) -> Instruction {
+    build(
+        accounts::MoveCommit {
+            player,
+            player_state: pda::player_state(&player),
+            game_state: pda::game_state(game_id),
+            game_config: pda::game_config(game_id),
+            randomness_account,
+        },
+        instruction::MoveCommit {
+            game_id,
+            direction,
+            card_usage,
+        },
+    )
+}
+
+/// Reveal the committed move
+pub fn move_reveal(
+    player: Pubkey,
+    game_id: u64,
+    randomness_account: Pubkey,
+    feed_page: u64,
+) -> Instruction {
+    build(
+        accounts::MoveReveal {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            game_state: pda::game_state(game_id),
+            feed_page: pda::feed_page(game_id, feed_page),
+            game_config: pda::game_config(game_id),
+            prize_vault: pda::prize_vault(game_id),
+            randomness_account,
+            system_program: system_program::ID,
+        },
+        instruction::MoveReveal { game_id },
+    )
+}
+
+/// Cancel an expired move commitment
+pub fn cancel_move(player: Pubkey, game_id: u64, randomness_account: Pubkey) -> Instruction {
+    build(
+        accounts::CancelMove {
+            player,
+            player_state: pda::player_state(&player),
+            player_feed: pda::player_feed(&player),
+            game_state: pda::game_state(game_id),
+            game_config: pda::game_config(game_id),
+            randomness_account,
+        },
+        instruction::CancelMove { game_id },
+    )
+}
+
+/// Commit to the next secret of the local oracle
+pub fn oracle_commit(authority: Pubkey, game_id: u64, commitment: [u8; 32]) -> Instruction {
+    build(
+        accounts::OracleCommit {
+            authority,
+            game_state: pda::game_state(game_id),
+        },
+        instruction::OracleCommit {
+            game_id,
+            commitment,
+        },
+    )
+}
+
+/// Reveal the committed secret of the local oracle, optionally committing to the next one
+pub fn oracle_reveal(
+    authority: Pubkey,
+    game_id: u64,
+    secret: [u8; 32],
+    next_commitment: Option<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts::OracleRevealSecret {
+            authority,
+            game_state: pda::game_state(game_id),
+        },
+        instruction::OracleReveal {
+            game_id,
+            secret,
+            next_commitment,
+        },
+    )
+}
+
+/// Get the base price of the next step of `player`, returned as the transaction's return data
+pub fn get_next_step_price(player: Pubkey, game_id: u64) -> Instruction {
+    build(
+        accounts::GetNextStepPrice {
+            player,
+            player_state: pda::player_state(&player),
+            game_config: pda::game_config(game_id),
+        },
+        instruction::GetNextStepPrice { game_id },
+    )
+}
+
+/// Give `player` a card, only available in test builds
+#[cfg(feature = "test")]
+pub fn debug_give_card(player: Pubkey, card: blockrunners::state::Card) -> Instruction {
+    build(
+        accounts::DebugGiveCard {
+            player,
+            player_state: pda::player_state(&player),
+        },
+        instruction::DebugGiveCard { card },
+    )
+}
+
+/// Queue the randomness of the next moves of `player`, only available in test builds
+#[cfg(feature = "test")]
+pub fn debug_script_randomness(player: Pubkey, values: Vec<[u8; 32]>) -> Instruction {
+    build(
+        accounts::DebugScriptRandomness {
+            player,
+            scripted_randomness: pda::scripted_randomness(&player),
+            system_program: system_program::ID,
+        },
+        instruction::DebugScriptRandomness { values },
+    )
+}
+
+/// Pass the scripted randomness of `player` to a `move_reveal` instruction, only used by test builds
+pub fn with_scripted_randomness(mut move_reveal: Instruction, player: &Pubkey) -> Instruction {
+    move_reveal
+        .accounts
+        .push(AccountMeta::new(pda::scripted_randomness(player), false));
+    move_reveal
+}
@@ -0,0 +1,18 @@
+//! Rust client for the Blockrunners program.
+//!
+//! PDA helpers, typed instruction builders for every instruction of the program, account
+//! decoders, a decoder for the events the program logs and the rendering of the social feed,
+//! all built on the program's own types, so they can't drift apart from the on-chain layout.
+
+pub mod accounts;
+pub mod events;
+pub mod feed;
+pub mod instructions;
+pub mod pda;
+
+pub use blockrunners::{
+    constants,
+    errors::BlockrunnersError,
+    state::{self, CardUsage, GameConfigParams, PathDirection, PauseFlags},
+    ID,
+};
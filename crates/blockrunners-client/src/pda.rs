@@ -0,0 +1,61 @@
+use anchor_lang::prelude::Pubkey;
+use blockrunners::{
+    constants::{
+        FEED_PAGE_SEED, GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_ARCHIVE_SEED, PLAYER_FEED_SEED,
+        PLAYER_STATE_SEED, PRIZE_VAULT_SEED, RESERVE_VAULT_SEED, SCRIPTED_RANDOMNESS_SEED,
+    },
+    ID,
+};
+
+/// Address of the state account of game `game_id`
+pub fn game_state(game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[GAME_STATE_SEED, &game_id.to_le_bytes()], &ID).0
+}
+
+/// Address of the config account of game `game_id`
+pub fn game_config(game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[GAME_CONFIG_SEED, &game_id.to_le_bytes()], &ID).0
+}
+
+/// Address of the vault holding the prize pool of game `game_id`
+pub fn prize_vault(game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[PRIZE_VAULT_SEED, &game_id.to_le_bytes()], &ID).0
+}
+
+/// Address of the vault paying refunds of unspent ciphers in game `game_id`
+pub fn reserve_vault(game_id: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RESERVE_VAULT_SEED, &game_id.to_le_bytes()], &ID).0
+}
+
+/// Address of page `page_index` of the feed history of game `game_id`
+pub fn feed_page(game_id: u64, page_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            FEED_PAGE_SEED,
+            &game_id.to_le_bytes(),
+            &page_index.to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+/// Address of the state account of `player`
+pub fn player_state(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PLAYER_STATE_SEED, player.as_ref()], &ID).0
+}
+
+/// Address of the feed of the events that only concern `player`
+pub fn player_feed(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PLAYER_FEED_SEED, player.as_ref()], &ID).0
+}
+
+/// Address of the lifetime statistics of the closed player accounts of `player`
+pub fn player_archive(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PLAYER_ARCHIVE_SEED, player.as_ref()], &ID).0
+}
+
+/// Address of the queue of scripted randomness of `player`, only used by test builds
+pub fn scripted_randomness(player: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SCRIPTED_RANDOMNESS_SEED, player.as_ref()], &ID).0
+}
@@ -0,0 +1,247 @@
+//! Decoding of Blockrunners transactions into the calls and feed events the store records.
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    AnchorDeserialize, Discriminator,
+};
+use blockrunners::{
+    instruction,
+    state::{CardUsage, PathDirection},
+    ID,
+};
+use blockrunners_client::{events::decode_social_feed_events, feed::VersionedFeedEvent};
+use solana_sdk::{signature::Signature, transaction::VersionedTransaction};
+use solana_transaction_status_client_types::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+};
+
+/// A confirmed transaction that called the program
+#[derive(Clone, Debug)]
+pub struct ProgramTransaction {
+    pub signature: Signature,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub succeeded: bool,
+    /// All top-level instructions, including the ones of other programs
+    pub instructions: Vec<Instruction>,
+    pub logs: Vec<String>,
+}
+
+impl ProgramTransaction {
+    /// Read a transaction as returned by the `getTransaction` RPC method, in a binary encoding
+    pub fn from_encoded(encoded: EncodedConfirmedTransactionWithStatusMeta) -> Option<Self> {
+        let transaction = encoded.transaction.transaction.decode()?;
+        let meta = encoded.transaction.meta?;
+
+        let mut account_keys = transaction.message.static_account_keys().to_vec();
+        if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+            for key in loaded.writable.iter().chain(&loaded.readonly) {
+                account_keys.push(key.parse().ok()?);
+            }
+        }
+        let logs = match meta.log_messages {
+            OptionSerializer::Some(logs) => logs,
+            _ => Vec::new(),
+        };
+
+        Some(Self {
+            signature: *transaction.signatures.first()?,
+            slot: encoded.slot,
+            block_time: encoded.block_time,
+            succeeded: meta.err.is_none(),
+            instructions: instructions(&transaction, &account_keys)?,
+            logs,
+        })
+    }
+}
+
+/// Resolve the account indexes of the instructions of `transaction`
+fn instructions(
+    transaction: &VersionedTransaction,
+    account_keys: &[Pubkey],
+) -> Option<Vec<Instruction>> {
+    transaction
+        .message
+        .instructions()
+        .iter()
+        .map(|instruction| {
+            let accounts = instruction
+                .accounts
+                .iter()
+                .map(|index| account_keys.get(usize::from(*index)).copied())
+                .collect::<Option<Vec<_>>>()?;
+            Some(Instruction {
+                program_id: *account_keys.get(usize::from(instruction.program_id_index))?,
+                accounts: accounts
+                    .into_iter()
+                    .map(|key| AccountMeta::new_readonly(key, false))
+                    .collect(),
+                data: instruction.data.clone(),
+            })
+        })
+        .collect()
+}
+
+// Position of the randomness account in the accounts of the move instructions
+const MOVE_COMMIT_RANDOMNESS: usize = 4;
+const MOVE_REVEAL_RANDOMNESS: usize = 7;
+const CANCEL_MOVE_RANDOMNESS: usize = 5;
+
+/// A program instruction the store records
+#[derive(Clone, Debug, PartialEq)]
+pub enum Call {
+    InitializeGame {
+        game_id: u64,
+        authority: Pubkey,
+    },
+    InitializePlayer {
+        player: Pubkey,
+    },
+    JoinGame {
+        game_id: u64,
+        player: Pubkey,
+    },
+    PurchaseCiphers {
+        game_id: u64,
+        player: Pubkey,
+        amount: u64,
+    },
+    MoveCommit {
+        game_id: u64,
+        player: Pubkey,
+        randomness_account: Pubkey,
+        direction: PathDirection,
+        card_usage: CardUsage,
+    },
+    MoveReveal {
+        game_id: u64,
+        player: Pubkey,
+        randomness_account: Pubkey,
+    },
+    CancelMove {
+        game_id: u64,
+        player: Pubkey,
+        randomness_account: Pubkey,
+    },
+}
+
+impl Call {
+    /// Decode an instruction of the program, other instructions aren't recorded
+    pub fn decode(instruction: &Instruction) -> Option<Self> {
+        if instruction.program_id != ID {
+            return None;
+        }
+        let data = instruction.data.as_slice();
+        // All recorded instructions are signed by the game admin or the player as first account
+        let signer = instruction.accounts.first()?.pubkey;
+
+        if let Some(args) = args::<instruction::InitializeGame>(data) {
+            return Some(Self::InitializeGame {
+                game_id: args.game_id,
+                authority: signer,
+            });
+        }
+        if args::<instruction::InitializePlayer>(data).is_some() {
+            return Some(Self::InitializePlayer { player: signer });
+        }
+        if let Some(args) = args::<instruction::JoinGame>(data) {
+            return Some(Self::JoinGame {
+                game_id: args.game_id,
+                player: signer,
+            });
+        }
+        if let Some(args) = args::<instruction::PurchaseCiphers>(data) {
+            return Some(Self::PurchaseCiphers {
+                game_id: args.game_id,
+                player: signer,
+                amount: args.amount,
+            });
+        }
+        if let Some(args) = args::<instruction::MoveCommit>(data) {
+            return Some(Self::MoveCommit {
+                game_id: args.game_id,
+                player: signer,
+                randomness_account: account(instruction, MOVE_COMMIT_RANDOMNESS)?,
+                direction: args.direction,
+                card_usage: args.card_usage,
+            });
+        }
+        if let Some(args) = args::<instruction::MoveReveal>(data) {
+            return Some(Self::MoveReveal {
+                game_id: args.game_id,
+                player: signer,
+                randomness_account: account(instruction, MOVE_REVEAL_RANDOMNESS)?,
+            });
+        }
+        if let Some(args) = args::<instruction::CancelMove>(data) {
+            return Some(Self::CancelMove {
+                game_id: args.game_id,
+                player: signer,
+                randomness_account: account(instruction, CANCEL_MOVE_RANDOMNESS)?,
+            });
+        }
+        None
+    }
+}
+
+/// The key of the account at `index` of an instruction
+fn account(instruction: &Instruction, index: usize) -> Option<Pubkey> {
+    instruction.accounts.get(index).map(|meta| meta.pubkey)
+}
+
+/// Decode the arguments of an instruction of type `T`
+fn args<T: AnchorDeserialize + Discriminator>(data: &[u8]) -> Option<T> {
+    T::try_from_slice(data.strip_prefix(T::DISCRIMINATOR)?).ok()
+}
+
+/// A top-level instruction of the program with the feed events it logged
+#[derive(Clone, Debug)]
+pub struct DecodedInstruction {
+    /// Position of the instruction in the transaction
+    pub index: usize,
+    pub call: Option<Call>,
+    pub events: Vec<VersionedFeedEvent>,
+}
+
+/// Decode the program's top-level instructions of a transaction with their feed events
+pub fn decode_transaction(transaction: &ProgramTransaction) -> Vec<DecodedInstruction> {
+    let logs = split_logs(&transaction.logs);
+    // Truncated logs can't be matched to the instructions, the calls are still recorded
+    let logs_complete = logs.len() == transaction.instructions.len();
+
+    transaction
+        .instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| instruction.program_id == ID)
+        .map(|(index, instruction)| DecodedInstruction {
+            index,
+            call: Call::decode(instruction),
+            events: if logs_complete {
+                decode_social_feed_events(logs[index])
+            } else {
+                Vec::new()
+            },
+        })
+        .collect()
+}
+
+/// Split the log messages of a transaction by top-level instruction
+pub fn split_logs(logs: &[String]) -> Vec<&[String]> {
+    let starts: Vec<usize> = logs
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| log.starts_with("Program ") && log.ends_with(" invoke [1]"))
+        .map(|(index, _)| index)
+        .collect();
+
+    starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let end = starts.get(i + 1).copied().unwrap_or(logs.len());
+            &logs[start..end]
+        })
+        .collect()
+}
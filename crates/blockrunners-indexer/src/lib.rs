@@ -0,0 +1,10 @@
+//! Indexer of the Blockrunners program.
+//!
+//! Reads the program's transactions from an RPC node or a dump, decodes the instructions and
+//! the `SocialFeedEvent`s they logged, and stores games, players, moves, purchases, wins and the
+//! feed history in SQLite. The store keeps a slot checkpoint, so the indexer resumes where it
+//! stopped.
+
+pub mod decode;
+pub mod source;
+pub mod store;
@@ -0,0 +1,135 @@
+//! Indexer of the Blockrunners program.
+//!
+//! Follows the program on an RPC node, or replays a dump of transactions, into a SQLite
+//! database. Restarting resumes after the last indexed transaction.
+//!
+//! ```text
+//! blockrunners-indexer [--db FILE] [--url URL] [--poll-interval SECONDS] [--once]
+//! blockrunners-indexer replay DUMP [--db FILE]
+//! ```
+
+use std::{process, thread, time::Duration};
+
+use blockrunners_indexer::{
+    decode::ProgramTransaction,
+    source::{read_dump, RpcSource, TRANSACTIONS_PER_PAGE},
+    store::Store,
+};
+
+enum Mode {
+    Follow {
+        url: String,
+        poll_interval: Duration,
+        once: bool,
+    },
+    Replay {
+        dump: String,
+    },
+}
+
+struct Options {
+    db: String,
+    mode: Mode,
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(2);
+    });
+
+    let mut store = Store::open(&options.db).unwrap_or_else(|error| {
+        eprintln!("error: can't open {}: {error}", options.db);
+        process::exit(1);
+    });
+
+    match options.mode {
+        Mode::Follow {
+            url,
+            poll_interval,
+            once,
+        } => {
+            let source = RpcSource::new(url);
+            loop {
+                // The node may be restarting, try again on the next poll
+                if let Err(error) = poll(&mut store, &source) {
+                    eprintln!("warning: can't read transactions: {error}");
+                }
+                if once {
+                    break;
+                }
+                thread::sleep(poll_interval);
+            }
+        }
+        Mode::Replay { dump } => {
+            let transactions = read_dump(&dump).unwrap_or_else(|error| fail(error));
+            for page in transactions.chunks(TRANSACTIONS_PER_PAGE) {
+                index(&mut store, page);
+            }
+        }
+    }
+}
+
+/// Index the transactions after the checkpoint a page at a time, so a page that can't be read
+/// leaves the pages before it stored and the next poll resumes from there
+fn poll(store: &mut Store, source: &RpcSource) -> Result<(), String> {
+    let checkpoint = store.checkpoint().unwrap_or_else(|error| fail(error));
+    let signatures = source.signatures_since(checkpoint.as_ref())?;
+    for page in signatures.chunks(TRANSACTIONS_PER_PAGE) {
+        index(store, &source.transactions(page)?);
+    }
+    Ok(())
+}
+
+fn index(store: &mut Store, transactions: &[ProgramTransaction]) {
+    let indexed = store
+        .index_page(transactions)
+        .unwrap_or_else(|error| fail(error));
+    for transaction in indexed {
+        println!("{} {}", transaction.slot, transaction.signature);
+    }
+}
+
+fn fail(error: impl std::fmt::Display) -> ! {
+    eprintln!("error: {error}");
+    process::exit(1);
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut db = "blockrunners.sqlite".to_string();
+    let mut url = "http://127.0.0.1:8899".to_string();
+    let mut poll_interval = 5;
+    let mut once = false;
+    let mut dump = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {flag}"))
+        };
+
+        match flag.as_str() {
+            "replay" if dump.is_none() => dump = Some(value()?),
+            "--db" => db = value()?,
+            "--url" => url = value()?,
+            "--poll-interval" => {
+                let seconds = value()?;
+                poll_interval = seconds
+                    .parse()
+                    .map_err(|_| format!("invalid number {seconds}"))?;
+            }
+            "--once" => once = true,
+            other => return Err(format!("unknown argument {other}")),
+        }
+    }
+
+    let mode = match dump {
+        Some(dump) => Mode::Replay { dump },
+        None => Mode::Follow {
+            url,
+            poll_interval: Duration::from_secs(poll_interval),
+            once,
+        },
+    };
+    Ok(Options { db, mode })
+}
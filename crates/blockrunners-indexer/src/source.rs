@@ -0,0 +1,129 @@
+//! Where the indexer reads transactions from: an RPC node, or a dump of transactions.
+
+use std::{fs, path::Path, str::FromStr};
+
+use blockrunners::ID;
+use solana_rpc_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_rpc_client_api::config::RpcTransactionConfig;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding,
+};
+
+use crate::{decode::ProgramTransaction, store::Checkpoint};
+
+/// Most signatures the RPC returns per request
+const SIGNATURES_PER_PAGE: usize = 1_000;
+
+/// Most transactions read and stored at once, the checkpoint moves after each page
+pub const TRANSACTIONS_PER_PAGE: usize = 100;
+
+/// Reads the program's transactions from an RPC node
+pub struct RpcSource {
+    client: RpcClient,
+}
+
+impl RpcSource {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: RpcClient::new_with_commitment(url, CommitmentConfig::confirmed()),
+        }
+    }
+
+    /// Get the signatures of the program's transactions after `checkpoint`, oldest first.
+    /// Only the signatures are held, the transactions are read a page at a time with
+    /// [`RpcSource::transactions`].
+    pub fn signatures_since(
+        &self,
+        checkpoint: Option<&Checkpoint>,
+    ) -> Result<Vec<Signature>, String> {
+        let until = checkpoint
+            .map(|checkpoint| parse_signature(&checkpoint.signature))
+            .transpose()?;
+
+        // The RPC returns the newest signatures first, page back until the checkpoint
+        let mut signatures = Vec::new();
+        let mut before = None;
+        loop {
+            let page = self
+                .client
+                .get_signatures_for_address_with_config(
+                    &ID,
+                    GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until,
+                        limit: Some(SIGNATURES_PER_PAGE),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                    },
+                )
+                .map_err(|error| format!("can't read signatures: {error}"))?;
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(parse_signature(&last.signature)?);
+            let full = page.len() == SIGNATURES_PER_PAGE;
+            signatures.extend(page);
+            if !full {
+                break;
+            }
+        }
+
+        signatures
+            .iter()
+            .rev()
+            .map(|status| parse_signature(&status.signature))
+            .collect()
+    }
+
+    /// Get the transactions of `signatures`, in the same order
+    pub fn transactions(
+        &self,
+        signatures: &[Signature],
+    ) -> Result<Vec<ProgramTransaction>, String> {
+        signatures
+            .iter()
+            .map(|signature| {
+                let encoded = self
+                    .client
+                    .get_transaction_with_config(
+                        signature,
+                        RpcTransactionConfig {
+                            encoding: Some(UiTransactionEncoding::Base64),
+                            commitment: Some(CommitmentConfig::confirmed()),
+                            max_supported_transaction_version: Some(0),
+                        },
+                    )
+                    .map_err(|error| format!("can't read transaction {signature}: {error}"))?;
+                ProgramTransaction::from_encoded(encoded)
+                    .ok_or_else(|| format!("can't decode transaction {signature}"))
+            })
+            .collect()
+    }
+}
+
+/// Read a dump of transactions, one `getTransaction` result in a binary encoding per line
+pub fn read_dump(path: impl AsRef<Path>) -> Result<Vec<ProgramTransaction>, String> {
+    let path = path.as_ref();
+    let dump = fs::read_to_string(path)
+        .map_err(|error| format!("can't read {}: {error}", path.display()))?;
+
+    let mut transactions = dump
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| {
+            serde_json::from_str::<EncodedConfirmedTransactionWithStatusMeta>(line)
+                .ok()
+                .and_then(ProgramTransaction::from_encoded)
+                .ok_or_else(|| format!("line {}: not a binary encoded transaction", number + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Keep the order of the ledger however the dump was written
+    transactions.sort_by_key(|transaction| transaction.slot);
+    Ok(transactions)
+}
+
+fn parse_signature(signature: &str) -> Result<Signature, String> {
+    Signature::from_str(signature).map_err(|_| format!("invalid signature {signature}"))
+}
@@ -0,0 +1,649 @@
+//! SQLite store of the indexed games, players, moves, purchases, wins and feed events.
+//!
+//! Every transaction, or page of transactions, is stored in one database transaction together
+//! with the checkpoint, so an interrupted indexer resumes after the last transaction it fully
+//! stored. Storing a transaction again is a no-op.
+
+use std::{path::Path, time::Duration};
+
+use anchor_lang::{prelude::Pubkey, AnchorSerialize};
+use blockrunners::state::FeedPayload;
+use blockrunners_client::feed::VersionedFeedEvent;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension, Transaction};
+
+use crate::decode::{decode_transaction, Call, DecodedInstruction, ProgramTransaction};
+
+/// Version of the schema below, kept in the database's `user_version`
+pub const SCHEMA_VERSION: i64 = 1;
+
+const SCHEMA: &str = "
+CREATE TABLE checkpoint (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    slot INTEGER NOT NULL,
+    signature TEXT NOT NULL
+);
+
+CREATE TABLE transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    succeeded INTEGER NOT NULL
+);
+
+CREATE TABLE games (
+    game_id INTEGER PRIMARY KEY,
+    authority TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    block_time INTEGER
+);
+
+CREATE TABLE players (
+    player TEXT PRIMARY KEY,
+    first_slot INTEGER NOT NULL,
+    last_slot INTEGER NOT NULL
+);
+
+CREATE TABLE joins (
+    signature TEXT NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    game_id INTEGER NOT NULL,
+    player TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    PRIMARY KEY (signature, instruction_index)
+);
+
+CREATE TABLE purchases (
+    signature TEXT NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    game_id INTEGER NOT NULL,
+    player TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    cost INTEGER,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    PRIMARY KEY (signature, instruction_index)
+);
+
+-- One row per committed move, completed by the reveal or cancellation with the same randomness
+-- account
+CREATE TABLE moves (
+    id INTEGER PRIMARY KEY,
+    game_id INTEGER NOT NULL,
+    player TEXT NOT NULL,
+    randomness_account TEXT NOT NULL,
+    direction TEXT NOT NULL,
+    shield INTEGER NOT NULL,
+    doubler INTEGER NOT NULL,
+    swift INTEGER NOT NULL,
+    commit_signature TEXT NOT NULL,
+    commit_slot INTEGER NOT NULL,
+    commit_block_time INTEGER,
+    -- advanced, shielded, reset, revealed (logs unavailable), cancelled or replaced (the
+    -- settlement wasn't indexed before the player's next commit)
+    outcome TEXT,
+    position INTEGER,
+    settle_signature TEXT,
+    settle_slot INTEGER,
+    settle_block_time INTEGER
+);
+CREATE INDEX moves_player ON moves (player, game_id);
+
+CREATE TABLE wins (
+    signature TEXT PRIMARY KEY,
+    game_id INTEGER NOT NULL,
+    player TEXT NOT NULL,
+    steps INTEGER NOT NULL,
+    prize INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    block_time INTEGER
+);
+
+-- The payload is the Borsh encoding of the program's FeedPayload, NULL for legacy events
+CREATE TABLE feed_events (
+    signature TEXT NOT NULL,
+    instruction_index INTEGER NOT NULL,
+    event_index INTEGER NOT NULL,
+    game_id INTEGER,
+    player TEXT,
+    event_type TEXT NOT NULL,
+    version INTEGER NOT NULL,
+    payload BLOB,
+    message TEXT NOT NULL,
+    timestamp INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    PRIMARY KEY (signature, instruction_index, event_index)
+);
+CREATE INDEX feed_events_game ON feed_events (game_id, slot);
+CREATE INDEX feed_events_player ON feed_events (player, slot);
+
+-- Lifetime statistics of every player, kept up to date as the moves, purchases, wins and feed
+-- events are stored so that profiles and leaderboards don't scan the history
+CREATE TABLE player_stats (
+    player TEXT PRIMARY KEY,
+    games_won INTEGER NOT NULL DEFAULT 0,
+    total_ciphers_bought INTEGER NOT NULL DEFAULT 0,
+    best_position INTEGER NOT NULL DEFAULT 0,
+    total_steps INTEGER NOT NULL DEFAULT 0,
+    total_resets INTEGER NOT NULL DEFAULT 0,
+    shields_used INTEGER NOT NULL DEFAULT 0,
+    -- Only the feed events tell these, like the matching PlayerState fields
+    cards_collected INTEGER NOT NULL DEFAULT 0,
+    system_breaches INTEGER NOT NULL DEFAULT 0,
+    best_win_streak INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX player_stats_games_won ON player_stats (games_won DESC, player);
+CREATE INDEX player_stats_ciphers_bought ON player_stats (total_ciphers_bought DESC, player);
+CREATE INDEX player_stats_best_position ON player_stats (best_position DESC, player);
+CREATE INDEX player_stats_total_steps ON player_stats (total_steps DESC, player);
+
+CREATE TABLE achievements (
+    player TEXT NOT NULL,
+    achievement TEXT NOT NULL,
+    signature TEXT NOT NULL,
+    slot INTEGER NOT NULL,
+    PRIMARY KEY (player, achievement)
+);
+";
+
+/// The last transaction the store holds
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub slot: u64,
+    pub signature: String,
+}
+
+pub struct Store {
+    connection: Connection,
+}
+
+impl Store {
+    /// Open the database at `path`, creating the schema if it's new
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        // Readers, like the HTTP API, don't block the indexer and wait for its writes
+        connection.pragma_update(None, "journal_mode", "WAL")?;
+        connection.busy_timeout(Duration::from_secs(5))?;
+        Self::with_connection(connection)
+    }
+
+    /// Open the database of an indexer at `path` for reading, without changing it
+    ///
+    /// Fails if the indexer didn't create the schema yet or created another version of it.
+    pub fn open_read_only(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let connection = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        connection.busy_timeout(Duration::from_secs(5))?;
+        let version: i64 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        match version {
+            SCHEMA_VERSION => Ok(Self { connection }),
+            0 => Err(rusqlite::Error::InvalidParameterName(
+                "database has no schema, run the indexer on it first".to_string(),
+            )),
+            _ => Err(rusqlite::Error::InvalidParameterName(format!(
+                "database schema version {version} isn't supported, expected {SCHEMA_VERSION}"
+            ))),
+        }
+    }
+
+    /// Open a database that only lives in memory
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        let version: i64 = connection.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        match version {
+            0 => connection.execute_batch(&format!(
+                "BEGIN; {SCHEMA} PRAGMA user_version = {SCHEMA_VERSION}; COMMIT;"
+            ))?,
+            SCHEMA_VERSION => {}
+            _ => {
+                return Err(rusqlite::Error::InvalidParameterName(format!(
+                    "database schema version {version} isn't supported, expected {SCHEMA_VERSION}"
+                )))
+            }
+        }
+
+        Ok(Self { connection })
+    }
+
+    /// The connection, for reading the indexed data
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    pub fn checkpoint(&self) -> rusqlite::Result<Option<Checkpoint>> {
+        self.connection
+            .query_row(
+                "SELECT slot, signature FROM checkpoint WHERE id = 1",
+                [],
+                |row| {
+                    Ok(Checkpoint {
+                        slot: row.get(0)?,
+                        signature: row.get(1)?,
+                    })
+                },
+            )
+            .optional()
+    }
+
+    /// Store a transaction and move the checkpoint to it. Returns false if it was stored before.
+    pub fn index(&mut self, transaction: &ProgramTransaction) -> rusqlite::Result<bool> {
+        let db = self.connection.transaction()?;
+        let indexed = index_transaction(&db, transaction)?;
+        db.commit()?;
+        Ok(indexed)
+    }
+
+    /// Store a page of transactions, oldest first, and move the checkpoint to the last one.
+    /// Returns the transactions that weren't stored before.
+    pub fn index_page<'a>(
+        &mut self,
+        transactions: &'a [ProgramTransaction],
+    ) -> rusqlite::Result<Vec<&'a ProgramTransaction>> {
+        let db = self.connection.transaction()?;
+        let mut indexed = Vec::new();
+        for transaction in transactions {
+            if index_transaction(&db, transaction)? {
+                indexed.push(transaction);
+            }
+        }
+        db.commit()?;
+        Ok(indexed)
+    }
+}
+
+/// Store a transaction and move the checkpoint to it. Returns false if it was stored before.
+fn index_transaction(db: &Transaction, transaction: &ProgramTransaction) -> rusqlite::Result<bool> {
+    let signature = transaction.signature.to_string();
+
+    let inserted = db.execute(
+        "INSERT OR IGNORE INTO transactions (signature, slot, block_time, succeeded)
+             VALUES (?1, ?2, ?3, ?4)",
+        params![
+            signature,
+            transaction.slot,
+            transaction.block_time,
+            transaction.succeeded
+        ],
+    )?;
+    if inserted == 0 {
+        return Ok(false);
+    }
+
+    // Failed transactions changed nothing on-chain
+    if transaction.succeeded {
+        for instruction in decode_transaction(transaction) {
+            index_instruction(db, transaction, &signature, &instruction)?;
+        }
+    }
+
+    // Transactions are indexed oldest first, the checkpoint never moves back
+    db.execute(
+        "INSERT INTO checkpoint (id, slot, signature) VALUES (1, ?1, ?2)
+         ON CONFLICT (id) DO UPDATE SET slot = excluded.slot, signature = excluded.signature
+         WHERE excluded.slot >= checkpoint.slot",
+        params![transaction.slot, signature],
+    )?;
+
+    Ok(true)
+}
+
+fn index_instruction(
+    db: &Transaction,
+    transaction: &ProgramTransaction,
+    signature: &str,
+    instruction: &DecodedInstruction,
+) -> rusqlite::Result<()> {
+    let slot = transaction.slot;
+    let block_time = transaction.block_time;
+    let index = instruction.index;
+
+    let (game_id, player) = match &instruction.call {
+        Some(Call::InitializeGame { game_id, authority }) => {
+            db.execute(
+                "INSERT OR IGNORE INTO games (game_id, authority, signature, slot, block_time)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![game_id, authority.to_string(), signature, slot, block_time],
+            )?;
+            (Some(*game_id), None)
+        }
+        Some(Call::InitializePlayer { player }) => (None, Some(*player)),
+        Some(Call::JoinGame { game_id, player }) => {
+            db.execute(
+                "INSERT INTO joins (signature, instruction_index, game_id, player, slot, block_time)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    signature,
+                    index,
+                    game_id,
+                    player.to_string(),
+                    slot,
+                    block_time
+                ],
+            )?;
+            (Some(*game_id), Some(*player))
+        }
+        Some(Call::PurchaseCiphers {
+            game_id,
+            player,
+            amount,
+        }) => {
+            let cost = payloads(instruction).find_map(|payload| match payload {
+                FeedPayload::CiphersPurchased { cost, .. } => Some(*cost),
+                _ => None,
+            });
+            db.execute(
+                "INSERT INTO purchases
+                 (signature, instruction_index, game_id, player, amount, cost, slot, block_time)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    signature,
+                    index,
+                    game_id,
+                    player.to_string(),
+                    amount,
+                    cost,
+                    slot,
+                    block_time
+                ],
+            )?;
+            add_player_stats(
+                db,
+                &player.to_string(),
+                &StatsChange {
+                    total_ciphers_bought: *amount,
+                    ..StatsChange::default()
+                },
+            )?;
+            (Some(*game_id), Some(*player))
+        }
+        Some(Call::MoveCommit {
+            game_id,
+            player,
+            randomness_account,
+            direction,
+            card_usage,
+        }) => {
+            // The program only accepts a commit once the previous move is settled
+            settle_move(db, transaction, *game_id, player, None, "replaced", None)?;
+            db.execute(
+                "INSERT INTO moves
+                 (game_id, player, randomness_account, direction, shield, doubler, swift,
+                  commit_signature, commit_slot, commit_block_time)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    game_id,
+                    player.to_string(),
+                    randomness_account.to_string(),
+                    format!("{direction:?}"),
+                    card_usage.shield,
+                    card_usage.doubler,
+                    card_usage.swift,
+                    signature,
+                    slot,
+                    block_time
+                ],
+            )?;
+            (Some(*game_id), Some(*player))
+        }
+        Some(Call::MoveReveal {
+            game_id,
+            player,
+            randomness_account,
+        }) => {
+            let (outcome, position) = payloads(instruction)
+                .find_map(|payload| match payload {
+                    FeedPayload::Advanced { position, .. } => Some(("advanced", Some(*position))),
+                    FeedPayload::ShieldSaved { position, .. } => {
+                        Some(("shielded", Some(*position)))
+                    }
+                    FeedPayload::Reset { .. } => Some(("reset", Some(0))),
+                    _ => None,
+                })
+                .unwrap_or(("revealed", None));
+            settle_move(
+                db,
+                transaction,
+                *game_id,
+                player,
+                Some(randomness_account),
+                outcome,
+                position,
+            )?;
+
+            for payload in payloads(instruction) {
+                if let FeedPayload::GameWon {
+                    player,
+                    steps,
+                    prize,
+                } = payload
+                {
+                    db.execute(
+                        "INSERT INTO wins (signature, game_id, player, steps, prize, slot, block_time)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                        params![
+                            signature,
+                            game_id,
+                            player.to_string(),
+                            steps,
+                            prize,
+                            slot,
+                            block_time
+                        ],
+                    )?;
+                    add_player_stats(
+                        db,
+                        &player.to_string(),
+                        &StatsChange {
+                            games_won: 1,
+                            ..StatsChange::default()
+                        },
+                    )?;
+                }
+            }
+            (Some(*game_id), Some(*player))
+        }
+        Some(Call::CancelMove {
+            game_id,
+            player,
+            randomness_account,
+        }) => {
+            settle_move(
+                db,
+                transaction,
+                *game_id,
+                player,
+                Some(randomness_account),
+                "cancelled",
+                None,
+            )?;
+            (Some(*game_id), Some(*player))
+        }
+        None => (None, None),
+    };
+
+    if let Some(player) = player {
+        db.execute(
+            "INSERT INTO players (player, first_slot, last_slot) VALUES (?1, ?2, ?2)
+             ON CONFLICT (player) DO UPDATE SET last_slot = max(last_slot, excluded.last_slot)",
+            params![player.to_string(), slot],
+        )?;
+        // Every player has statistics, even before their first move
+        add_player_stats(db, &player.to_string(), &StatsChange::default())?;
+    }
+
+    for (event_index, event) in instruction.events.iter().enumerate() {
+        let (version, payload) = match event {
+            VersionedFeedEvent::Legacy(_) => (0, None),
+            VersionedFeedEvent::Current(event) => (
+                event.version,
+                Some(event.payload.try_to_vec().expect("payload serializes")),
+            ),
+        };
+        db.execute(
+            "INSERT INTO feed_events
+             (signature, instruction_index, event_index, game_id, player, event_type, version,
+              payload, message, timestamp, slot)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                signature,
+                index,
+                event_index,
+                game_id,
+                player.map(|player| player.to_string()),
+                format!("{:?}", event.event_type()),
+                version,
+                payload,
+                event.message(),
+                event.timestamp(),
+                slot
+            ],
+        )?;
+
+        if let (Some(player), VersionedFeedEvent::Current(event)) = (player, event) {
+            add_event_stats_of(db, &player.to_string(), &event.payload, signature, slot)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// What an instruction adds to the lifetime statistics of a player
+#[derive(Default)]
+struct StatsChange {
+    games_won: u64,
+    total_ciphers_bought: u64,
+    /// A position reached, the best one is kept
+    best_position: u8,
+    total_steps: u64,
+    total_resets: u64,
+    shields_used: u64,
+    cards_collected: u64,
+    system_breaches: u64,
+    /// A win streak reached, the best one is kept
+    best_win_streak: u64,
+}
+
+fn add_player_stats(db: &Transaction, player: &str, change: &StatsChange) -> rusqlite::Result<()> {
+    db.execute(
+        "INSERT INTO player_stats
+         (player, games_won, total_ciphers_bought, best_position, total_steps, total_resets,
+          shields_used, cards_collected, system_breaches, best_win_streak)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+         ON CONFLICT (player) DO UPDATE SET
+             games_won = games_won + excluded.games_won,
+             total_ciphers_bought = total_ciphers_bought + excluded.total_ciphers_bought,
+             best_position = max(best_position, excluded.best_position),
+             total_steps = total_steps + excluded.total_steps,
+             total_resets = total_resets + excluded.total_resets,
+             shields_used = shields_used + excluded.shields_used,
+             cards_collected = cards_collected + excluded.cards_collected,
+             system_breaches = system_breaches + excluded.system_breaches,
+             best_win_streak = max(best_win_streak, excluded.best_win_streak)",
+        params![
+            player,
+            change.games_won,
+            change.total_ciphers_bought,
+            change.best_position,
+            change.total_steps,
+            change.total_resets,
+            change.shields_used,
+            change.cards_collected,
+            change.system_breaches,
+            change.best_win_streak
+        ],
+    )?;
+    Ok(())
+}
+
+/// Add what a feed event of `player` tells about their statistics, counted the way the rules
+/// update the player account
+fn add_event_stats_of(
+    db: &Transaction,
+    player: &str,
+    payload: &FeedPayload,
+    signature: &str,
+    slot: u64,
+) -> rusqlite::Result<()> {
+    let change = match payload {
+        FeedPayload::Advanced {
+            position,
+            card_usage,
+        } => StatsChange {
+            best_position: *position,
+            total_steps: 1,
+            shields_used: u64::from(card_usage.shield),
+            // A doubler collects two cards, some may be rejected by a full hand
+            cards_collected: if card_usage.doubler { 2 } else { 1 },
+            ..StatsChange::default()
+        },
+        FeedPayload::ShieldSaved { .. } => StatsChange {
+            shields_used: 1,
+            ..StatsChange::default()
+        },
+        FeedPayload::Reset { .. } => StatsChange {
+            total_resets: 1,
+            ..StatsChange::default()
+        },
+        FeedPayload::SystemIntrusion { .. } => StatsChange {
+            system_breaches: 1,
+            ..StatsChange::default()
+        },
+        FeedPayload::WinStreakRecord { streak } => StatsChange {
+            best_win_streak: *streak,
+            ..StatsChange::default()
+        },
+        FeedPayload::AchievementUnlocked { achievement } => {
+            db.execute(
+                "INSERT OR IGNORE INTO achievements (player, achievement, signature, slot)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![player, format!("{achievement:?}"), signature, slot],
+            )?;
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+    add_player_stats(db, player, &change)
+}
+
+/// Complete the player's unsettled moves committed with `randomness_account`, or all of them
+fn settle_move(
+    db: &Transaction,
+    transaction: &ProgramTransaction,
+    game_id: u64,
+    player: &Pubkey,
+    randomness_account: Option<&Pubkey>,
+    outcome: &str,
+    position: Option<u8>,
+) -> rusqlite::Result<()> {
+    db.execute(
+        "UPDATE moves SET outcome = ?1, position = ?2, settle_signature = ?3, settle_slot = ?4,
+                          settle_block_time = ?5
+         WHERE game_id = ?6 AND player = ?7 AND outcome IS NULL
+           AND (?8 IS NULL OR randomness_account = ?8)",
+        params![
+            outcome,
+            position,
+            transaction.signature.to_string(),
+            transaction.slot,
+            transaction.block_time,
+            game_id,
+            player.to_string(),
+            randomness_account.map(Pubkey::to_string)
+        ],
+    )?;
+    Ok(())
+}
+
+/// The payloads of the current feed events of an instruction
+fn payloads(instruction: &DecodedInstruction) -> impl Iterator<Item = &FeedPayload> {
+    instruction.events.iter().filter_map(|event| match event {
+        VersionedFeedEvent::Current(event) => Some(&event.payload),
+        VersionedFeedEvent::Legacy(_) => None,
+    })
+}
@@ -0,0 +1,483 @@
+use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction, Event};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blockrunners::{
+    state::{Achievement, CardUsage, FeedPayload, PathDirection, SocialFeedEvent},
+    ID,
+};
+use blockrunners_client::instructions;
+use blockrunners_indexer::{
+    decode::ProgramTransaction,
+    source::read_dump,
+    store::{Checkpoint, Store},
+};
+use solana_sdk::{
+    message::Message,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, TransactionBinaryEncoding, TransactionStatusMeta,
+};
+
+const GAME_ID: u64 = 1;
+/// Randomness account of the move of [`won_game`]
+const RANDOMNESS: Pubkey = Pubkey::new_from_array([9; 32]);
+
+/// A successful transaction whose instructions logged `events`, one list per instruction
+fn transaction(
+    slot: u64,
+    instructions: Vec<Instruction>,
+    events: Vec<Vec<FeedPayload>>,
+) -> ProgramTransaction {
+    let mut logs = Vec::new();
+    for payloads in &events {
+        logs.push(format!("Program {ID} invoke [1]"));
+        for payload in payloads {
+            let event = SocialFeedEvent::new(payload.clone(), slot as i64);
+            logs.push(format!("Program data: {}", STANDARD.encode(event.data())));
+        }
+        logs.push(format!("Program {ID} success"));
+    }
+
+    ProgramTransaction {
+        signature: Signature::new_unique(),
+        slot,
+        block_time: Some(slot as i64),
+        succeeded: true,
+        instructions,
+        logs,
+    }
+}
+
+fn count(store: &Store, table: &str) -> i64 {
+    store
+        .connection()
+        .query_row(&format!("SELECT count(*) FROM {table}"), [], |row| {
+            row.get(0)
+        })
+        .unwrap()
+}
+
+/// Transactions of a game won by `player` in one step
+fn won_game(player: Pubkey) -> Vec<ProgramTransaction> {
+    let admin = Pubkey::new_unique();
+
+    vec![
+        transaction(
+            1,
+            vec![instructions::initialize_game(admin, GAME_ID)],
+            vec![vec![]],
+        ),
+        transaction(
+            2,
+            vec![
+                instructions::initialize_player(player),
+                instructions::join_game(player, GAME_ID, 0),
+            ],
+            vec![vec![], vec![FeedPayload::PlayerJoined { player }]],
+        ),
+        transaction(
+            3,
+            vec![instructions::purchase_ciphers(
+                player, admin, GAME_ID, 10, 0,
+            )],
+            vec![vec![FeedPayload::CiphersPurchased {
+                amount: 10,
+                cost: 1_000,
+                ciphers: 10,
+            }]],
+        ),
+        transaction(
+            4,
+            vec![instructions::move_commit(
+                player,
+                GAME_ID,
+                RANDOMNESS,
+                PathDirection::Left,
+                CardUsage::default(),
+            )],
+            vec![vec![]],
+        ),
+        transaction(
+            5,
+            vec![instructions::move_reveal(player, GAME_ID, RANDOMNESS, 0)],
+            vec![vec![
+                FeedPayload::Advanced {
+                    position: 1,
+                    card_usage: CardUsage::default(),
+                },
+                FeedPayload::GameWon {
+                    player,
+                    steps: 1,
+                    prize: 900,
+                },
+            ]],
+        ),
+    ]
+}
+
+#[test]
+fn indexes_a_game_from_join_to_win() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    for transaction in won_game(player) {
+        assert!(store.index(&transaction).unwrap());
+    }
+
+    let db = store.connection();
+    let cost: u64 = db
+        .query_row("SELECT cost FROM purchases", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(cost, 1_000);
+    let (direction, outcome, position): (String, String, u8) = db
+        .query_row(
+            "SELECT direction, outcome, position FROM moves",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .unwrap();
+    assert_eq!(
+        (direction.as_str(), outcome.as_str(), position),
+        ("Left", "advanced", 1)
+    );
+    let (winner, prize): (String, u64) = db
+        .query_row("SELECT player, prize FROM wins", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!((winner, prize), (player.to_string(), 900));
+
+    assert_eq!(count(&store, "games"), 1);
+    assert_eq!(count(&store, "players"), 1);
+    assert_eq!(count(&store, "joins"), 1);
+    assert_eq!(count(&store, "feed_events"), 4);
+}
+
+#[test]
+fn events_belong_to_the_instruction_that_logged_them() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    store.index(&won_game(player)[1]).unwrap();
+
+    let (instruction_index, message): (u32, String) = store
+        .connection()
+        .query_row(
+            "SELECT instruction_index, message FROM feed_events WHERE game_id = ?1",
+            [GAME_ID],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert_eq!(instruction_index, 1);
+    assert!(message.starts_with("Player "));
+}
+
+#[test]
+fn cancelled_move_is_settled_without_a_position() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    let transactions = won_game(player);
+    for transaction in &transactions[..4] {
+        store.index(transaction).unwrap();
+    }
+    store
+        .index(&transaction(
+            5,
+            vec![instructions::cancel_move(player, GAME_ID, RANDOMNESS)],
+            vec![vec![]],
+        ))
+        .unwrap();
+
+    let (outcome, position): (String, Option<u8>) = store
+        .connection()
+        .query_row("SELECT outcome, position FROM moves", [], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })
+        .unwrap();
+    assert_eq!((outcome.as_str(), position), ("cancelled", None));
+}
+
+#[test]
+fn moves_are_settled_by_their_randomness_account() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    let transactions = won_game(player);
+    for transaction in &transactions[..4] {
+        store.index(transaction).unwrap();
+    }
+
+    // A cancellation with another randomness account settles nothing
+    let other = Pubkey::new_unique();
+    store
+        .index(&transaction(
+            5,
+            vec![instructions::cancel_move(player, GAME_ID, other)],
+            vec![vec![]],
+        ))
+        .unwrap();
+    // The settlement of the first move was missed, the next commit replaces it
+    store
+        .index(&transaction(
+            6,
+            vec![instructions::move_commit(
+                player,
+                GAME_ID,
+                other,
+                PathDirection::Right,
+                CardUsage::default(),
+            )],
+            vec![vec![]],
+        ))
+        .unwrap();
+    store
+        .index(&transaction(
+            7,
+            vec![instructions::move_reveal(player, GAME_ID, other, 0)],
+            vec![vec![FeedPayload::Advanced {
+                position: 1,
+                card_usage: CardUsage::default(),
+            }]],
+        ))
+        .unwrap();
+
+    let mut statement = store
+        .connection()
+        .prepare("SELECT outcome, settle_slot FROM moves ORDER BY id")
+        .unwrap();
+    let moves: Vec<(String, u64)> = statement
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        moves,
+        [("replaced".to_string(), 6), ("advanced".to_string(), 7)]
+    );
+}
+
+#[test]
+fn failed_transactions_change_nothing() {
+    let mut store = Store::open_in_memory().unwrap();
+    let mut failed = won_game(Pubkey::new_unique()).remove(1);
+    failed.succeeded = false;
+
+    assert!(store.index(&failed).unwrap());
+    assert_eq!(count(&store, "joins"), 0);
+    assert_eq!(count(&store, "feed_events"), 0);
+    assert_eq!(store.checkpoint().unwrap().map(|c| c.slot), Some(2));
+}
+
+#[test]
+fn reopened_store_resumes_from_its_checkpoint() {
+    let path = std::env::temp_dir().join(format!(
+        "blockrunners-indexer-{}.sqlite",
+        std::process::id()
+    ));
+    let transactions = won_game(Pubkey::new_unique());
+
+    {
+        let mut store = Store::open(&path).unwrap();
+        assert_eq!(store.checkpoint().unwrap(), None);
+        for transaction in &transactions[..3] {
+            store.index(transaction).unwrap();
+        }
+    }
+
+    let mut store = Store::open(&path).unwrap();
+    assert_eq!(
+        store.checkpoint().unwrap(),
+        Some(Checkpoint {
+            slot: 3,
+            signature: transactions[2].signature.to_string(),
+        })
+    );
+
+    // Replaying from the start only stores what's new
+    let indexed = transactions
+        .iter()
+        .filter(|transaction| store.index(transaction).unwrap())
+        .count();
+    assert_eq!(indexed, 2);
+    assert_eq!(count(&store, "purchases"), 1);
+    assert_eq!(store.checkpoint().unwrap().map(|c| c.slot), Some(5));
+
+    drop(store);
+    std::fs::remove_file(path).unwrap();
+}
+
+/// `games_won, total_ciphers_bought, best_position, total_steps` of `player`
+fn player_stats(store: &Store, player: &Pubkey) -> (u64, u64, u8, u64) {
+    store
+        .connection()
+        .query_row(
+            "SELECT games_won, total_ciphers_bought, best_position, total_steps
+             FROM player_stats WHERE player = ?1",
+            [player.to_string()],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap()
+}
+
+#[test]
+fn player_stats_follow_the_history() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    let transactions = won_game(player);
+
+    store.index(&transactions[1]).unwrap();
+    assert_eq!(player_stats(&store, &player), (0, 0, 0, 0));
+    for transaction in &transactions {
+        store.index(transaction).unwrap();
+    }
+    assert_eq!(player_stats(&store, &player), (1, 10, 1, 1));
+}
+
+#[test]
+fn event_stats_are_counted_like_the_rules() {
+    let mut store = Store::open_in_memory().unwrap();
+    let player = Pubkey::new_unique();
+    let transactions = won_game(player);
+    for transaction in &transactions[..4] {
+        store.index(transaction).unwrap();
+    }
+    let card_usage = CardUsage {
+        shield: true,
+        doubler: true,
+        swift: false,
+    };
+    store
+        .index(&transaction(
+            5,
+            vec![instructions::move_reveal(player, GAME_ID, RANDOMNESS, 0)],
+            vec![vec![
+                FeedPayload::Advanced {
+                    position: 1,
+                    card_usage,
+                },
+                FeedPayload::SystemIntrusion { percentage: 50 },
+                FeedPayload::WinStreakRecord { streak: 2 },
+                FeedPayload::AchievementUnlocked {
+                    achievement: Achievement::CipherLord,
+                },
+            ]],
+        ))
+        .unwrap();
+
+    let stats: (u64, u64, u64, u64) = store
+        .connection()
+        .query_row(
+            "SELECT shields_used, cards_collected, system_breaches, best_win_streak
+             FROM player_stats",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .unwrap();
+    assert_eq!(stats, (1, 2, 1, 2));
+    let achievement: String = store
+        .connection()
+        .query_row("SELECT achievement FROM achievements", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(achievement, "CipherLord");
+}
+
+#[test]
+fn read_only_store_needs_the_indexer_schema() {
+    let path = std::env::temp_dir().join(format!(
+        "blockrunners-indexer-read-only-{}.sqlite",
+        std::process::id()
+    ));
+    rusqlite::Connection::open(&path).unwrap();
+    assert!(Store::open_read_only(&path).is_err());
+
+    let mut store = Store::open(&path).unwrap();
+    let transactions = won_game(Pubkey::new_unique());
+    store.index(&transactions[0]).unwrap();
+
+    let reader = Store::open_read_only(&path).unwrap();
+    assert_eq!(count(&reader, "games"), 1);
+    assert!(reader
+        .connection()
+        .execute("DELETE FROM games", [])
+        .is_err());
+
+    drop((store, reader));
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{suffix}", path.display()));
+    }
+}
+
+#[test]
+fn dump_of_rpc_transactions_is_replayed() {
+    let player = Pubkey::new_unique();
+    let logged = won_game(player).remove(1);
+    let transaction = VersionedTransaction::from(Transaction::new_unsigned(Message::new(
+        &logged.instructions,
+        Some(&player),
+    )));
+    let encoded = EncodedConfirmedTransactionWithStatusMeta {
+        slot: 7,
+        transaction: EncodedTransactionWithStatusMeta {
+            transaction: EncodedTransaction::Binary(
+                STANDARD.encode(bincode::serialize(&transaction).unwrap()),
+                TransactionBinaryEncoding::Base64,
+            ),
+            meta: Some(
+                TransactionStatusMeta {
+                    log_messages: Some(logged.logs),
+                    ..TransactionStatusMeta::default()
+                }
+                .into(),
+            ),
+            version: None,
+        },
+        block_time: Some(7),
+    };
+    let path = std::env::temp_dir().join(format!(
+        "blockrunners-indexer-dump-{}.jsonl",
+        std::process::id()
+    ));
+    std::fs::write(&path, serde_json::to_string(&encoded).unwrap()).unwrap();
+
+    let transactions = read_dump(&path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].slot, 7);
+
+    let mut store = Store::open_in_memory().unwrap();
+    store.index(&transactions[0]).unwrap();
+    let joined: String = store
+        .connection()
+        .query_row("SELECT player FROM joins", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(joined, player.to_string());
+    assert_eq!(count(&store, "feed_events"), 1);
+}
+
+#[test]
+fn page_is_stored_with_the_checkpoint_of_its_last_transaction() {
+    let mut store = Store::open_in_memory().unwrap();
+    let transactions = won_game(Pubkey::new_unique());
+    store.index(&transactions[0]).unwrap();
+
+    // Transactions stored before are skipped
+    let indexed = store.index_page(&transactions[..3]).unwrap();
+    let indexed: Vec<_> = indexed
+        .iter()
+        .map(|transaction| transaction.signature)
+        .collect();
+    assert_eq!(
+        indexed,
+        vec![transactions[1].signature, transactions[2].signature]
+    );
+    assert_eq!(
+        store.checkpoint().unwrap(),
+        Some(Checkpoint {
+            slot: 3,
+            signature: transactions[2].signature.to_string(),
+        })
+    );
+
+    store.index_page(&transactions[3..]).unwrap();
+    assert_eq!(count(&store, "wins"), 1);
+    assert_eq!(store.checkpoint().unwrap().map(|c| c.slot), Some(5));
+}
@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+use crate::{RuleEvent, Runner};
+
+/// Cards to collect in total for Data Hoarder
+pub const DATA_HOARDER_CARDS: u64 = 100;
+
+/// Ciphers to purchase in total for Cipher Lord
+pub const CIPHER_LORD_CIPHERS: u64 = 1000;
+
+/// Progress percentages announced to the game feed
+pub const MILESTONES: [u8; 4] = [25, 50, 75, 90];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum Achievement {
+    /// Won without using any cards
+    GhostProtocol,
+
+    /// Collected 100+ cards total
+    DataHoarder,
+
+    /// Reached 90%+ of path length
+    ConsensusBreaker,
+
+    /// Purchased 1000+ ciphers total
+    CipherLord,
+}
+
+/// Get the runner's progress on the path as a percentage
+pub fn progress_percentage(position: u8, path_length: u8) -> u8 {
+    (position as f32 / path_length as f32 * 100.0) as u8
+}
+
+/// Award the achievements the runner qualifies for and count a system breach if the runner
+/// got past half of the path
+pub fn check_and_award_achievements(
+    runner: &mut Runner,
+    path_length: u8,
+    events: &mut Vec<RuleEvent>,
+) {
+    let mut achievements = Vec::new();
+
+    // Ghost Protocol: Win without using any cards
+    // This would need to be tracked per game, simplified for now
+    if !runner.ghost_protocol && runner.games_won > 0 && runner.shields_used == 0 {
+        runner.ghost_protocol = true;
+        achievements.push(Achievement::GhostProtocol);
+    }
+
+    // Data Hoarder: Collected 100+ cards total
+    if !runner.data_hoarder && runner.cards_collected >= DATA_HOARDER_CARDS {
+        runner.data_hoarder = true;
+        achievements.push(Achievement::DataHoarder);
+    }
+
+    // Consensus Breaker: Reached 90%+ of path length
+    let breach_threshold = (path_length as f32 * 0.9) as u8;
+    if !runner.consensus_breaker && runner.best_position >= breach_threshold {
+        runner.consensus_breaker = true;
+        achievements.push(Achievement::ConsensusBreaker);
+    }
+
+    // Cipher Lord: Purchased 1000+ ciphers total
+    if !runner.cipher_lord && runner.total_ciphers_bought >= CIPHER_LORD_CIPHERS {
+        runner.cipher_lord = true;
+        achievements.push(Achievement::CipherLord);
+    }
+
+    // System Breach milestone (50%+ of path)
+    let system_breach_threshold = (path_length as f32 * 0.5) as u8;
+    if runner.position >= system_breach_threshold {
+        runner.system_breaches += 1;
+        events.push(RuleEvent::SystemIntrusion {
+            percentage: progress_percentage(runner.position, path_length),
+        });
+    }
+
+    events.extend(
+        achievements
+            .into_iter()
+            .map(|achievement| RuleEvent::AchievementUnlocked { achievement }),
+    );
+}
+
+/// Get the milestone the runner just reached, if any
+pub fn check_milestones(position: u8, path_length: u8) -> Option<u8> {
+    let progress = progress_percentage(position, path_length);
+
+    MILESTONES
+        .into_iter()
+        .find(|&milestone| progress >= milestone && progress < milestone + 5)
+}
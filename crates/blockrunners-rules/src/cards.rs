@@ -0,0 +1,136 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// Ability cards a runner can hold and use on a move
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum Card {
+    Shield,
+    Doubler,
+    Swift,
+}
+
+impl Card {
+    pub const COUNT: u8 = 3;
+
+    /// Pick a card from a random byte, each card type being equally likely
+    pub fn from_random(random_value: u8) -> Self {
+        match random_value % Self::COUNT {
+            0 => Card::Shield,
+            1 => Card::Doubler,
+            _ => Card::Swift,
+        }
+    }
+}
+
+/// Cards used on a move
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct CardUsage {
+    pub shield: bool,
+    pub doubler: bool,
+    pub swift: bool,
+}
+
+impl CardUsage {
+    /// Iterate over the used cards
+    pub fn cards(&self) -> impl Iterator<Item = Card> {
+        [
+            (Card::Shield, self.shield),
+            (Card::Doubler, self.doubler),
+            (Card::Swift, self.swift),
+        ]
+        .into_iter()
+        .filter_map(|(card, is_used)| is_used.then_some(card))
+    }
+
+    /// Get the number of used cards
+    pub fn count(&self) -> u64 {
+        self.cards().count() as u64
+    }
+}
+
+/// Number of cards of each type held by a runner
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct CardCounts {
+    pub shield: u8,
+    pub doubler: u8,
+    pub swift: u8,
+}
+
+impl Default for CardCounts {
+    fn default() -> Self {
+        Self {
+            shield: 1,
+            doubler: 1,
+            swift: 1,
+        }
+    }
+}
+
+impl CardCounts {
+    /// Get the count for a specific card type
+    pub fn get_count(&self, card: Card) -> u8 {
+        match card {
+            Card::Shield => self.shield,
+            Card::Doubler => self.doubler,
+            Card::Swift => self.swift,
+        }
+    }
+
+    fn count_mut(&mut self, card: Card) -> &mut u8 {
+        match card {
+            Card::Shield => &mut self.shield,
+            Card::Doubler => &mut self.doubler,
+            Card::Swift => &mut self.swift,
+        }
+    }
+
+    /// Add a card to the collection, returns true if successful (not at max)
+    pub fn add_card(&mut self, card: Card) -> bool {
+        let count = self.count_mut(card);
+        let old_count = *count;
+        *count = count.saturating_add(1);
+        old_count < u8::MAX
+    }
+
+    /// Remove a card from the collection, returns true if successful
+    pub fn remove_card(&mut self, card: Card) -> bool {
+        let count = self.count_mut(card);
+        let old_count = *count;
+        *count = count.saturating_sub(1);
+        old_count > 0
+    }
+
+    /// Get the total number of cards
+    pub fn total_cards(&self) -> u16 {
+        self.shield as u16 + self.doubler as u16 + self.swift as u16
+    }
+
+    /// Check if the runner has a specific card
+    pub fn has_card(&self, card: Card) -> bool {
+        self.get_count(card) > 0
+    }
+}
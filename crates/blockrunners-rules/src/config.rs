@@ -0,0 +1,185 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+use crate::CardCounts;
+
+/// Maximum number of tiers in a tiered step price curve
+pub const MAX_STEP_PRICE_TIERS: usize = 8;
+
+/// Number of prize pool tiers deciding the starting hand
+pub const STARTING_HAND_TIERS: usize = 4;
+
+/// Fixed-point scale of the exponential step price, whose steps are rounded up to whole ciphers
+const EXPONENTIAL_PRICE_SCALE: u128 = 1_000_000;
+
+/// How the base price of a step grows with the runner's position on the path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum StepPriceCurve {
+    /// Every step costs the base move cost
+    Flat,
+
+    /// Each step costs `increment` ciphers more than the previous one
+    Linear { increment: u64 },
+
+    /// Each step costs `growth_percentage` percent more than the previous one, rounded up to
+    /// whole ciphers
+    Exponential { growth_percentage: u16 },
+
+    /// Every `tier_length` steps the price moves to the next entry of `prices`,
+    /// staying at the last entry once the table runs out. Prices can't be zero.
+    Tiered {
+        tier_length: u8,
+        prices: [u64; MAX_STEP_PRICE_TIERS],
+    },
+}
+
+impl StepPriceCurve {
+    /// Get the base price in ciphers of the step taken from `position`
+    pub fn step_price(&self, base_move_cost: u64, position: u8) -> u64 {
+        match *self {
+            StepPriceCurve::Flat => base_move_cost,
+            StepPriceCurve::Linear { increment } => {
+                base_move_cost.saturating_add(increment.saturating_mul(u64::from(position)))
+            }
+            StepPriceCurve::Exponential { growth_percentage } => {
+                // Grow a scaled price so cheap steps don't truncate back to the same price
+                let mut scaled = u128::from(base_move_cost) * EXPONENTIAL_PRICE_SCALE;
+                for _ in 0..position {
+                    scaled = scaled.saturating_mul(100 + u128::from(growth_percentage)) / 100;
+                }
+                u64::try_from(scaled.div_ceil(EXPONENTIAL_PRICE_SCALE)).unwrap_or(u64::MAX)
+            }
+            StepPriceCurve::Tiered {
+                tier_length,
+                prices,
+            } => {
+                let tier = usize::from(position / tier_length.max(1));
+                prices[tier.min(prices.len() - 1)]
+            }
+        }
+    }
+}
+
+/// How the path length of a new game is derived from the game that was just won
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum PathLengthRule {
+    /// Every game uses the configured path length
+    Fixed,
+
+    /// One extra step on top of the configured path length for every `lamports_per_step`
+    /// lamports paid out in the previous game
+    PrizePool { lamports_per_step: u64 },
+
+    /// One step longer than the previous game if it was won in less than `target_duration`
+    /// seconds, one step shorter otherwise
+    WinSpeed { target_duration: i64 },
+}
+
+/// Path length of new games
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PathRules {
+    /// The configured path length
+    pub path_length: u8,
+
+    /// How the path length changes between games, bounded by the min and max path length
+    pub rule: PathLengthRule,
+    pub min_path_length: u8,
+    pub max_path_length: u8,
+}
+
+impl PathRules {
+    /// Get the path length of the next game from the previous path length, the prize paid out
+    /// and how many seconds the previous game lasted
+    pub fn next_path_length(&self, previous_length: u8, prize_amount: u64, duration: i64) -> u8 {
+        let next_length = match self.rule {
+            PathLengthRule::Fixed => u64::from(self.path_length),
+            PathLengthRule::PrizePool { lamports_per_step } => {
+                u64::from(self.path_length).saturating_add(prize_amount / lamports_per_step.max(1))
+            }
+            PathLengthRule::WinSpeed { target_duration } => {
+                if duration < target_duration {
+                    u64::from(previous_length).saturating_add(1)
+                } else {
+                    u64::from(previous_length).saturating_sub(1)
+                }
+            }
+        };
+
+        next_length.clamp(
+            u64::from(self.min_path_length),
+            u64::from(self.max_path_length),
+        ) as u8
+    }
+}
+
+/// Starting hand dealt when the prize pool holds at least `min_prize_pool` lamports
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub struct StartingHandTier {
+    pub min_prize_pool: u64,
+
+    pub cards: CardCounts,
+}
+
+/// Parameters deciding how moves play out
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rules {
+    /// Base cost of a move in ciphers
+    pub base_move_cost: u64,
+
+    /// Cost of using one card in ciphers
+    pub card_cost: u64,
+
+    /// Ciphers saved on a move by using the Swift card
+    pub swift_discount: u64,
+
+    /// How the base move cost grows with the position on the path
+    pub step_price_curve: StepPriceCurve,
+
+    /// Move success probability as a percentage (out of 100)
+    pub move_success_probability: u8,
+
+    /// Starting hands by prize pool size, in ascending order of `min_prize_pool`
+    pub starting_hand_tiers: [StartingHandTier; STARTING_HAND_TIERS],
+}
+
+impl Rules {
+    /// Get the base price in ciphers of the step taken from `position`
+    pub fn step_price(&self, position: u8) -> u64 {
+        self.step_price_curve
+            .step_price(self.base_move_cost, position)
+    }
+
+    /// Get the starting hand for the given prize pool size
+    pub fn starting_hand(&self, prize_pool: u64) -> CardCounts {
+        self.starting_hand_tiers
+            .iter()
+            .rev()
+            .find(|tier| prize_pool >= tier.min_prize_pool)
+            .map(|tier| tier.cards)
+            .unwrap_or_default()
+    }
+}
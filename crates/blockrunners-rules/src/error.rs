@@ -0,0 +1,24 @@
+use core::fmt;
+
+/// Reasons a move can't be played by the rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RulesError {
+    /// The runner can't pay for the move
+    InsufficientBalance,
+
+    /// The runner doesn't hold a card it wants to use
+    InsufficientCards,
+
+    /// The move needs more random bytes than were revealed
+    RandomnessFinished,
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RulesError::InsufficientBalance => f.write_str("insufficient balance"),
+            RulesError::InsufficientCards => f.write_str("insufficient cards"),
+            RulesError::RandomnessFinished => f.write_str("randomness finished"),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+
+use crate::{Achievement, Card, CardCounts, CardUsage};
+
+/// Something that happened while applying the rules, to be announced in a feed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleEvent {
+    /// The runner reached a position further than ever before
+    PersonalBest { position: u8 },
+
+    /// The runner passed a progress milestone, announced to the whole game
+    MilestoneReached { percentage: u8 },
+
+    /// The next step costs a different price than the last one
+    StepPriceChanged { previous: u64, next: u64 },
+
+    /// Cards collected after a correct step
+    CardsCollected { cards: Vec<Card> },
+
+    /// Cards that couldn't be collected because the runner holds the maximum of their type
+    CardsRejected { count: u8 },
+
+    /// The runner advanced to `position`
+    Advanced { position: u8, card_usage: CardUsage },
+
+    /// A shield kept the runner at `position` after a failed step
+    ShieldSaved { position: u8, card_usage: CardUsage },
+
+    /// The runner was sent back to the start from `from_position`
+    Reset {
+        from_position: u8,
+        card_usage: CardUsage,
+    },
+
+    /// A starting hand was dealt for the prize pool size
+    StartingHandDealt { cards: CardCounts, prize_pool: u64 },
+
+    /// The runner set a new personal best win streak
+    WinStreakRecord { streak: u64 },
+
+    /// The runner got past half of the path when winning
+    SystemIntrusion { percentage: u8 },
+
+    /// The runner unlocked an achievement
+    AchievementUnlocked { achievement: Achievement },
+}
+
+impl RuleEvent {
+    /// Check if the event belongs to the game feed rather than the player's feed
+    pub fn is_global(&self) -> bool {
+        matches!(self, RuleEvent::MilestoneReached { .. })
+    }
+}
@@ -0,0 +1,32 @@
+//! Pure game rules of Blockrunners.
+//!
+//! The on-chain program, the simulator and other off-chain tools all resolve moves with the
+//! same code. Everything works on plain structs and a stream of random bytes, without accounts
+//! or sysvars, and only allocates for the returned events, so the crate builds for `no_std`.
+//!
+//! The types the program stores in its accounts derive Anchor's serialization with the
+//! `anchor` feature, so the program uses them directly instead of keeping copies in sync.
+
+#![cfg_attr(not(feature = "anchor"), no_std)]
+
+extern crate alloc;
+
+mod achievements;
+mod cards;
+mod config;
+mod error;
+mod events;
+mod moves;
+mod path;
+mod randomness;
+mod runner;
+
+pub use achievements::*;
+pub use cards::*;
+pub use config::*;
+pub use error::*;
+pub use events::*;
+pub use moves::*;
+pub use path::*;
+pub use randomness::*;
+pub use runner::*;
@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+
+use crate::{
+    check_and_award_achievements, check_milestones, path_step, Card, CardUsage, PathDirection,
+    RandomnessStream, RuleEvent, Rules, RulesError, Runner,
+};
+
+/// The state of the game a move is played in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameView {
+    /// Seed of the hidden left/right path
+    pub path_seed: [u8; 32],
+
+    /// The length of the path
+    pub path_length: u8,
+
+    /// The current prize pool amount in lamports
+    pub prize_pool: u64,
+}
+
+/// How a move ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveOutcome {
+    /// The runner took the step
+    Advanced,
+
+    /// The runner took the last step of the path
+    Won,
+
+    /// The step failed but a shield kept the runner in place
+    ShieldSaved,
+
+    /// The step failed and the runner is back at the start
+    Reset,
+}
+
+/// The result of resolving a move
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveResult {
+    pub outcome: MoveOutcome,
+
+    /// The hidden direction of the step
+    pub path_step: PathDirection,
+
+    /// The consensus roll, the step only succeeds if it's below the success probability
+    pub roll: u8,
+
+    /// Events to announce, in order
+    pub events: Vec<RuleEvent>,
+}
+
+impl Rules {
+    /// Get the cost in ciphers of a move from the runner's position with the given cards
+    pub fn move_cost(&self, runner: &Runner, card_usage: &CardUsage) -> Result<u64, RulesError> {
+        let mut total_cost = self.step_price(runner.position); // Base cost for move
+
+        // Early exit if no cards used
+        if card_usage.count() == 0 {
+            return Ok(total_cost);
+        }
+
+        total_cost = total_cost.saturating_add(self.card_cost.saturating_mul(card_usage.count()));
+
+        // Ensure runner has all required cards
+        if card_usage.cards().any(|card| !runner.cards.has_card(card)) {
+            return Err(RulesError::InsufficientCards);
+        }
+
+        // Apply swift card effect
+        if card_usage.swift {
+            total_cost = total_cost.saturating_sub(self.swift_discount);
+        }
+
+        Ok(total_cost)
+    }
+
+    /// Charge the ciphers for a move and remove the used cards from the runner's inventory
+    pub fn pay_for_move(
+        &self,
+        runner: &mut Runner,
+        card_usage: &CardUsage,
+    ) -> Result<(), RulesError> {
+        let total_cost = self.move_cost(runner, card_usage)?;
+        if runner.ciphers < total_cost {
+            return Err(RulesError::InsufficientBalance);
+        }
+        runner.ciphers -= total_cost;
+
+        for card in card_usage.cards() {
+            if !runner.cards.remove_card(card) {
+                return Err(RulesError::InsufficientCards);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve a paid move in `direction` from the revealed randomness
+    pub fn resolve_move(
+        &self,
+        runner: &mut Runner,
+        game: &GameView,
+        direction: PathDirection,
+        card_usage: CardUsage,
+        randomness: &mut RandomnessStream,
+    ) -> Result<MoveResult, RulesError> {
+        // Determine whether the consensus traces the runner and the hidden step of the path
+        let roll = randomness.next_byte()?;
+        let path_step = path_step(&game.path_seed, runner.position, randomness.next_byte()?);
+        let is_move_successful =
+            direction == path_step && (roll % 100) < self.move_success_probability;
+
+        let mut events = Vec::new();
+        let outcome = if is_move_successful {
+            self.advance(runner, game, card_usage, randomness, &mut events)?;
+
+            if runner.position == game.path_length {
+                self.win(runner, game.path_length, &mut events);
+                MoveOutcome::Won
+            } else {
+                MoveOutcome::Advanced
+            }
+        } else {
+            self.fail_move(runner, game, card_usage, &mut events)
+        };
+
+        Ok(MoveResult {
+            outcome,
+            path_step,
+            roll,
+            events,
+        })
+    }
+
+    /// Apply a failed step: a shield keeps the runner in place, otherwise the runner restarts
+    /// with a fresh starting hand
+    pub fn fail_move(
+        &self,
+        runner: &mut Runner,
+        game: &GameView,
+        card_usage: CardUsage,
+        events: &mut Vec<RuleEvent>,
+    ) -> MoveOutcome {
+        if card_usage.shield {
+            runner.shields_used += 1;
+            events.push(RuleEvent::ShieldSaved {
+                position: runner.position,
+                card_usage,
+            });
+
+            return MoveOutcome::ShieldSaved;
+        }
+
+        // No shield = reset to start
+        let from_position = runner.position;
+        runner.position = 0;
+        runner.total_resets += 1;
+
+        // Reset consecutive wins
+        runner.consecutive_wins = 0;
+
+        events.push(RuleEvent::Reset {
+            from_position,
+            card_usage,
+        });
+
+        // Restart with a fresh starting hand for the current prize pool
+        self.deal_starting_hand(runner, game.prize_pool, events);
+
+        MoveOutcome::Reset
+    }
+
+    /// Replace the runner's cards with the starting hand for the prize pool size
+    pub fn deal_starting_hand(
+        &self,
+        runner: &mut Runner,
+        prize_pool: u64,
+        events: &mut Vec<RuleEvent>,
+    ) {
+        runner.cards = self.starting_hand(prize_pool);
+        events.push(RuleEvent::StartingHandDealt {
+            cards: runner.cards,
+            prize_pool,
+        });
+    }
+
+    /// Record a win in the runner's statistics and award achievements
+    pub fn win(&self, runner: &mut Runner, path_length: u8, events: &mut Vec<RuleEvent>) {
+        runner.games_won = runner.games_won.saturating_add(1);
+
+        // Update win streak
+        runner.consecutive_wins += 1;
+        if runner.consecutive_wins > runner.best_win_streak {
+            runner.best_win_streak = runner.consecutive_wins;
+            events.push(RuleEvent::WinStreakRecord {
+                streak: runner.consecutive_wins,
+            });
+        }
+
+        check_and_award_achievements(runner, path_length, events);
+    }
+
+    fn advance(
+        &self,
+        runner: &mut Runner,
+        game: &GameView,
+        card_usage: CardUsage,
+        randomness: &mut RandomnessStream,
+        events: &mut Vec<RuleEvent>,
+    ) -> Result<(), RulesError> {
+        // Correct move: advance one step
+        runner.position += 1;
+        let new_position = runner.position;
+
+        // Update statistics
+        runner.total_steps += 1;
+
+        // Check for personal best position
+        if new_position > runner.best_position {
+            runner.best_position = new_position;
+            events.push(RuleEvent::PersonalBest {
+                position: new_position,
+            });
+        }
+
+        // Check for milestone notifications
+        if let Some(percentage) = check_milestones(new_position, game.path_length) {
+            events.push(RuleEvent::MilestoneReached { percentage });
+        }
+
+        // Announce when the runner crosses into a new step price tier
+        let previous = self.step_price(new_position - 1);
+        let next = self.step_price(new_position);
+        if new_position < game.path_length && next != previous {
+            events.push(RuleEvent::StepPriceChanged { previous, next });
+        }
+
+        // Collect cards based on success and doubler
+        let collect_cards_count = if card_usage.doubler { 2 } else { 1 };
+        self.give_random_cards(runner, collect_cards_count, randomness, events)?;
+        runner.cards_collected += u64::from(collect_cards_count);
+
+        if card_usage.shield {
+            runner.shields_used += 1;
+        }
+
+        events.push(RuleEvent::Advanced {
+            position: new_position,
+            card_usage,
+        });
+
+        Ok(())
+    }
+
+    /// Give the runner `card_count` random cards, each type being equally likely
+    pub fn give_random_cards(
+        &self,
+        runner: &mut Runner,
+        card_count: u8,
+        randomness: &mut RandomnessStream,
+        events: &mut Vec<RuleEvent>,
+    ) -> Result<(), RulesError> {
+        let mut cards = Vec::with_capacity(usize::from(card_count));
+        let mut rejected = 0u8;
+
+        for _ in 0..card_count {
+            let card = Card::from_random(randomness.next_byte()?);
+
+            // Add card to runner's collection and track success
+            if runner.cards.add_card(card) {
+                cards.push(card);
+            } else {
+                rejected += 1;
+            }
+        }
+
+        if !cards.is_empty() {
+            events.push(RuleEvent::CardsCollected { cards });
+        }
+        if rejected > 0 {
+            events.push(RuleEvent::CardsRejected { count: rejected });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::borsh;
+
+/// Direction of a step on the path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "anchor",
+    derive(
+        anchor_lang::AnchorSerialize,
+        anchor_lang::AnchorDeserialize,
+        anchor_lang::InitSpace
+    )
+)]
+pub enum PathDirection {
+    Left,
+    Right,
+}
+
+/// Returns the hidden direction of the step at `position`.
+///
+/// The direction mixes the game's path seed with a byte of the player's revealed randomness,
+/// which was committed before its value could be known. Anyone can recompute it after the game
+/// from the published seed and the revealed randomness.
+pub fn path_step(path_seed: &[u8; 32], position: u8, random_value: u8) -> PathDirection {
+    let seed_byte = path_seed[usize::from(position) % path_seed.len()];
+
+    if (seed_byte ^ random_value) & 1 == 1 {
+        PathDirection::Right
+    } else {
+        PathDirection::Left
+    }
+}
@@ -0,0 +1,41 @@
+use crate::RulesError;
+
+/// The random bytes of one revealed 32-byte value.
+///
+/// Bytes are consumed like `Vec::swap_remove(0)`: the first byte, then the remaining ones from
+/// the back. This is the order the program has always used, so scripted test values and
+/// recorded reveals replay exactly.
+#[derive(Clone, Debug)]
+pub struct RandomnessStream {
+    values: [u8; 32],
+    len: usize,
+}
+
+impl RandomnessStream {
+    pub fn new(values: [u8; 32]) -> Self {
+        Self { values, len: 32 }
+    }
+
+    /// Take the next random byte
+    pub fn next_byte(&mut self) -> Result<u8, RulesError> {
+        if self.len == 0 {
+            return Err(RulesError::RandomnessFinished);
+        }
+
+        let value = self.values[0];
+        self.len -= 1;
+        self.values[0] = self.values[self.len];
+
+        Ok(value)
+    }
+
+    /// Get the number of bytes left
+    pub fn remaining(&self) -> usize {
+        self.len
+    }
+
+    /// Get the bytes left, in storage order
+    pub fn as_slice(&self) -> &[u8] {
+        &self.values[..self.len]
+    }
+}
@@ -0,0 +1,34 @@
+use crate::CardCounts;
+
+/// The part of a player's state the rules read and change
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Runner {
+    /// Number of ciphers owned
+    pub ciphers: u64,
+
+    /// Cards owned
+    pub cards: CardCounts,
+
+    /// Current position
+    pub position: u8,
+
+    /// Player statistics
+    pub games_won: u64,
+    pub total_ciphers_bought: u64,
+
+    /// Personal bests and achievements
+    pub best_position: u8,
+    pub total_resets: u64,
+    pub total_steps: u64,
+    pub cards_collected: u64,
+    pub shields_used: u64,
+    pub system_breaches: u64,
+    pub consecutive_wins: u64,
+    pub best_win_streak: u64,
+
+    /// Achievement flags
+    pub ghost_protocol: bool,
+    pub data_hoarder: bool,
+    pub consensus_breaker: bool,
+    pub cipher_lord: bool,
+}
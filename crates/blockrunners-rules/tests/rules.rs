@@ -0,0 +1,365 @@
+use blockrunners_rules::{
+    CardCounts, CardUsage, GameView, MoveOutcome, PathDirection, PathLengthRule, PathRules,
+    RandomnessStream, RuleEvent, Rules, RulesError, Runner, StartingHandTier, StepPriceCurve,
+    STARTING_HAND_TIERS,
+};
+
+const BASE_MOVE_COST: u64 = 10;
+const CARD_COST: u64 = 3;
+const SWIFT_DISCOUNT: u64 = 2;
+
+fn hand(count: u8) -> CardCounts {
+    CardCounts {
+        shield: count,
+        doubler: count,
+        swift: count,
+    }
+}
+
+fn rules() -> Rules {
+    let mut starting_hand_tiers = [StartingHandTier {
+        min_prize_pool: 0,
+        cards: hand(1),
+    }; STARTING_HAND_TIERS];
+    for (index, tier) in starting_hand_tiers.iter_mut().enumerate() {
+        tier.min_prize_pool = 1000 * index as u64;
+        tier.cards = hand(index as u8 + 1);
+    }
+
+    Rules {
+        base_move_cost: BASE_MOVE_COST,
+        card_cost: CARD_COST,
+        swift_discount: SWIFT_DISCOUNT,
+        step_price_curve: StepPriceCurve::Flat,
+        move_success_probability: 75,
+        starting_hand_tiers,
+    }
+}
+
+fn game(path_length: u8) -> GameView {
+    GameView {
+        path_seed: [0; 32],
+        path_length,
+        prize_pool: 0,
+    }
+}
+
+fn runner(position: u8) -> Runner {
+    Runner {
+        ciphers: 100,
+        position,
+        ..Runner::default()
+    }
+}
+
+/// Randomness whose first byte is the consensus roll and whose second one, taken from the
+/// back, decides the path step: an even byte makes the step go left on a zero path seed
+fn randomness(roll: u8, path_byte: u8) -> RandomnessStream {
+    let mut values = [0; 32];
+    values[0] = roll;
+    values[31] = path_byte;
+    RandomnessStream::new(values)
+}
+
+fn resolve(
+    runner: &mut Runner,
+    game: &GameView,
+    direction: PathDirection,
+    card_usage: CardUsage,
+    roll: u8,
+) -> (MoveOutcome, Vec<RuleEvent>) {
+    let result = rules()
+        .resolve_move(
+            runner,
+            game,
+            direction,
+            card_usage,
+            &mut randomness(roll, 0),
+        )
+        .expect("move resolves");
+    (result.outcome, result.events)
+}
+
+#[test]
+fn move_costs_the_step_price_without_cards() {
+    let cost = rules().move_cost(&runner(0), &CardUsage::default());
+    assert_eq!(cost, Ok(BASE_MOVE_COST));
+}
+
+#[test]
+fn cards_add_to_the_move_cost_and_swift_discounts_it() {
+    let card_usage = CardUsage {
+        shield: true,
+        doubler: false,
+        swift: true,
+    };
+    let cost = rules().move_cost(&runner(0), &card_usage);
+    assert_eq!(cost, Ok(BASE_MOVE_COST + 2 * CARD_COST - SWIFT_DISCOUNT));
+}
+
+#[test]
+fn move_cost_requires_the_used_cards() {
+    let mut runner = runner(0);
+    runner.cards.doubler = 0;
+    let card_usage = CardUsage {
+        doubler: true,
+        ..CardUsage::default()
+    };
+    assert_eq!(
+        rules().move_cost(&runner, &card_usage),
+        Err(RulesError::InsufficientCards)
+    );
+}
+
+#[test]
+fn paying_for_a_move_charges_ciphers_and_cards() {
+    let mut runner = runner(0);
+    let card_usage = CardUsage {
+        shield: true,
+        ..CardUsage::default()
+    };
+    rules()
+        .pay_for_move(&mut runner, &card_usage)
+        .expect("move is paid");
+
+    assert_eq!(runner.ciphers, 100 - BASE_MOVE_COST - CARD_COST);
+    assert_eq!(runner.cards.shield, 0);
+}
+
+#[test]
+fn paying_for_a_move_requires_enough_ciphers() {
+    let mut runner = runner(0);
+    runner.ciphers = BASE_MOVE_COST - 1;
+    assert_eq!(
+        rules().pay_for_move(&mut runner, &CardUsage::default()),
+        Err(RulesError::InsufficientBalance)
+    );
+}
+
+#[test]
+fn correct_step_advances_and_collects_a_card() {
+    let mut runner = runner(0);
+    let (outcome, events) = resolve(
+        &mut runner,
+        &game(10),
+        PathDirection::Left,
+        CardUsage::default(),
+        0,
+    );
+
+    assert_eq!(outcome, MoveOutcome::Advanced);
+    assert_eq!(runner.position, 1);
+    assert_eq!(runner.best_position, 1);
+    assert_eq!(runner.total_steps, 1);
+    assert_eq!(runner.cards_collected, 1);
+    assert_eq!(runner.cards.total_cards(), 4);
+    assert!(events.contains(&RuleEvent::PersonalBest { position: 1 }));
+    assert_eq!(
+        events.last(),
+        Some(&RuleEvent::Advanced {
+            position: 1,
+            card_usage: CardUsage::default(),
+        })
+    );
+}
+
+#[test]
+fn doubler_collects_two_cards() {
+    let mut runner = runner(0);
+    let card_usage = CardUsage {
+        doubler: true,
+        ..CardUsage::default()
+    };
+    resolve(&mut runner, &game(10), PathDirection::Left, card_usage, 0);
+
+    assert_eq!(runner.cards_collected, 2);
+}
+
+#[test]
+fn last_step_wins_the_game() {
+    let mut runner = runner(4);
+    let (outcome, events) = resolve(
+        &mut runner,
+        &game(5),
+        PathDirection::Left,
+        CardUsage::default(),
+        0,
+    );
+
+    assert_eq!(outcome, MoveOutcome::Won);
+    assert_eq!(runner.games_won, 1);
+    assert_eq!(runner.consecutive_wins, 1);
+    assert_eq!(runner.best_win_streak, 1);
+    assert!(events.contains(&RuleEvent::WinStreakRecord { streak: 1 }));
+}
+
+#[test]
+fn wrong_direction_resets_the_runner_with_a_fresh_hand() {
+    let mut runner = runner(3);
+    runner.consecutive_wins = 2;
+    let (outcome, events) = resolve(
+        &mut runner,
+        &game(10),
+        PathDirection::Right,
+        CardUsage::default(),
+        0,
+    );
+
+    assert_eq!(outcome, MoveOutcome::Reset);
+    assert_eq!(runner.position, 0);
+    assert_eq!(runner.total_resets, 1);
+    assert_eq!(runner.consecutive_wins, 0);
+    assert_eq!(runner.cards, hand(1));
+    assert_eq!(
+        events,
+        vec![
+            RuleEvent::Reset {
+                from_position: 3,
+                card_usage: CardUsage::default(),
+            },
+            RuleEvent::StartingHandDealt {
+                cards: hand(1),
+                prize_pool: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn roll_above_the_success_probability_fails_the_step() {
+    let mut runner = runner(3);
+    let (outcome, _) = resolve(
+        &mut runner,
+        &game(10),
+        PathDirection::Left,
+        CardUsage::default(),
+        75,
+    );
+
+    assert_eq!(outcome, MoveOutcome::Reset);
+    assert_eq!(runner.position, 0);
+}
+
+#[test]
+fn shield_keeps_the_runner_in_place_on_a_failed_step() {
+    let mut runner = runner(3);
+    let card_usage = CardUsage {
+        shield: true,
+        ..CardUsage::default()
+    };
+    let (outcome, events) = resolve(&mut runner, &game(10), PathDirection::Right, card_usage, 0);
+
+    assert_eq!(outcome, MoveOutcome::ShieldSaved);
+    assert_eq!(runner.position, 3);
+    assert_eq!(runner.shields_used, 1);
+    assert_eq!(runner.total_resets, 0);
+    assert_eq!(
+        events,
+        vec![RuleEvent::ShieldSaved {
+            position: 3,
+            card_usage,
+        }]
+    );
+}
+
+#[test]
+fn move_needs_enough_randomness() {
+    let mut randomness = RandomnessStream::new([0; 32]);
+    while randomness.remaining() > 1 {
+        randomness.next_byte().expect("byte is left");
+    }
+
+    let result = rules().resolve_move(
+        &mut runner(0),
+        &game(10),
+        PathDirection::Left,
+        CardUsage::default(),
+        &mut randomness,
+    );
+    assert_eq!(result, Err(RulesError::RandomnessFinished));
+}
+
+fn path_rules(rule: PathLengthRule) -> PathRules {
+    PathRules {
+        path_length: 20,
+        rule,
+        min_path_length: 10,
+        max_path_length: 30,
+    }
+}
+
+#[test]
+fn fixed_path_length_ignores_the_last_game() {
+    let rules = path_rules(PathLengthRule::Fixed);
+    assert_eq!(rules.next_path_length(25, 1_000_000, 1), 20);
+}
+
+#[test]
+fn prize_pool_path_length_grows_with_the_prize() {
+    let rules = path_rules(PathLengthRule::PrizePool {
+        lamports_per_step: 1000,
+    });
+    assert_eq!(rules.next_path_length(20, 0, 0), 20);
+    assert_eq!(rules.next_path_length(20, 5999, 0), 25);
+    assert_eq!(rules.next_path_length(20, 1_000_000, 0), 30);
+}
+
+#[test]
+fn win_speed_path_length_follows_how_fast_the_game_was_won() {
+    let rules = path_rules(PathLengthRule::WinSpeed {
+        target_duration: 3600,
+    });
+    assert_eq!(rules.next_path_length(20, 0, 60), 21);
+    assert_eq!(rules.next_path_length(20, 0, 3600), 19);
+    assert_eq!(rules.next_path_length(30, 0, 60), 30);
+    assert_eq!(rules.next_path_length(10, 0, 7200), 10);
+}
+
+#[test]
+fn starting_hand_follows_the_prize_pool_tiers() {
+    let rules = rules();
+    assert_eq!(rules.starting_hand(0), hand(1));
+    assert_eq!(rules.starting_hand(999), hand(1));
+    assert_eq!(rules.starting_hand(1000), hand(2));
+    assert_eq!(rules.starting_hand(2500), hand(3));
+    assert_eq!(rules.starting_hand(u64::MAX), hand(4));
+}
+
+#[test]
+fn starting_hand_is_dealt_on_reset_for_the_current_prize_pool() {
+    let mut runner = runner(3);
+    let game = GameView {
+        prize_pool: 1500,
+        ..game(10)
+    };
+    resolve(
+        &mut runner,
+        &game,
+        PathDirection::Right,
+        CardUsage::default(),
+        0,
+    );
+
+    assert_eq!(runner.cards, hand(2));
+}
+
+#[test]
+fn step_is_decided_by_each_runners_own_randomness() {
+    // Two runners at the same step of the same path, only their revealed randomness differs
+    let outcomes: Vec<MoveOutcome> = [0, 1]
+        .into_iter()
+        .map(|path_byte| {
+            rules()
+                .resolve_move(
+                    &mut runner(3),
+                    &game(10),
+                    PathDirection::Left,
+                    CardUsage::default(),
+                    &mut randomness(0, path_byte),
+                )
+                .expect("move resolves")
+                .outcome
+        })
+        .collect();
+    assert_eq!(outcomes, [MoveOutcome::Advanced, MoveOutcome::Reset]);
+}
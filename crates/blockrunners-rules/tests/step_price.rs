@@ -0,0 +1,95 @@
+use blockrunners_rules::{StepPriceCurve, MAX_STEP_PRICE_TIERS};
+
+#[test]
+fn flat_curve_keeps_the_base_cost() {
+    let curve = StepPriceCurve::Flat;
+    assert_eq!(curve.step_price(5, 0), 5);
+    assert_eq!(curve.step_price(5, 200), 5);
+}
+
+#[test]
+fn linear_curve_adds_the_increment_per_step() {
+    let curve = StepPriceCurve::Linear { increment: 3 };
+    assert_eq!(curve.step_price(5, 0), 5);
+    assert_eq!(curve.step_price(5, 1), 8);
+    assert_eq!(curve.step_price(5, 10), 35);
+}
+
+#[test]
+fn linear_curve_saturates() {
+    let curve = StepPriceCurve::Linear {
+        increment: u64::MAX,
+    };
+    assert_eq!(curve.step_price(5, 2), u64::MAX);
+}
+
+#[test]
+fn exponential_curve_compounds_the_growth() {
+    let curve = StepPriceCurve::Exponential {
+        growth_percentage: 10,
+    };
+    assert_eq!(curve.step_price(100, 0), 100);
+    assert_eq!(curve.step_price(100, 1), 110);
+    assert_eq!(curve.step_price(100, 2), 121);
+    // 133.1 is rounded up
+    assert_eq!(curve.step_price(100, 3), 134);
+}
+
+#[test]
+fn exponential_curve_grows_cheap_steps() {
+    let curve = StepPriceCurve::Exponential {
+        growth_percentage: 10,
+    };
+    assert_eq!(curve.step_price(1, 0), 1);
+    assert_eq!(curve.step_price(1, 1), 2);
+    // 1.1^8 = 2.14
+    assert_eq!(curve.step_price(1, 8), 3);
+    // 1.1^30 = 17.45
+    assert_eq!(curve.step_price(1, 30), 18);
+}
+
+#[test]
+fn exponential_curve_never_shrinks() {
+    let curve = StepPriceCurve::Exponential {
+        growth_percentage: 1,
+    };
+    for position in 1..=u8::MAX {
+        assert!(curve.step_price(3, position) >= curve.step_price(3, position - 1));
+    }
+    assert!(curve.step_price(3, u8::MAX) > 3);
+}
+
+#[test]
+fn exponential_curve_saturates() {
+    let curve = StepPriceCurve::Exponential {
+        growth_percentage: u16::MAX,
+    };
+    assert_eq!(curve.step_price(u64::MAX, u8::MAX), u64::MAX);
+}
+
+#[test]
+fn tiered_curve_moves_through_the_table() {
+    let mut prices = [0; MAX_STEP_PRICE_TIERS];
+    for (index, price) in prices.iter_mut().enumerate() {
+        *price = 10 * (index as u64 + 1);
+    }
+    let curve = StepPriceCurve::Tiered {
+        tier_length: 5,
+        prices,
+    };
+    assert_eq!(curve.step_price(1, 0), 10);
+    assert_eq!(curve.step_price(1, 4), 10);
+    assert_eq!(curve.step_price(1, 5), 20);
+    assert_eq!(curve.step_price(1, 39), 80);
+    // The last price holds once the table runs out
+    assert_eq!(curve.step_price(1, 200), 80);
+}
+
+#[test]
+fn tiered_curve_ignores_the_base_cost() {
+    let curve = StepPriceCurve::Tiered {
+        tier_length: 1,
+        prices: [7; MAX_STEP_PRICE_TIERS],
+    };
+    assert_eq!(curve.step_price(100, 3), 7);
+}
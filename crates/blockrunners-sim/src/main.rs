@@ -0,0 +1,154 @@
+//! Monte Carlo balance simulator.
+//!
+//! Simulates runners playing with different card strategies against the same rules the program
+//! resolves moves with, and reports what a win costs, how long games take, what the cards are
+//! worth and how purchases split between the prize pool and the house.
+//!
+//! ```text
+//! blockrunners-sim [--runners N] [--strategy NAME[,NAME...]] [--seed N] [--max-moves N]
+//!                  [--path-length N] [--probability N] [--base-move-cost N] [--card-cost N]
+//!                  [--swift-discount N] [--cipher-cost N] [--prize-pool-percentage N]
+//!                  [--initial-prize-pool N] [--format json|csv] [--output FILE]
+//! ```
+
+mod report;
+mod simulation;
+mod strategy;
+
+use std::{fs, process};
+
+use blockrunners::state::GameConfigParams;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    report::{to_csv, to_json},
+    simulation::{simulate, SimulationConfig},
+    strategy::{all_strategies, strategy_by_name, Strategy},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    Csv,
+}
+
+struct Options {
+    config: SimulationConfig,
+    strategies: Vec<Box<dyn Strategy>>,
+    seed: u64,
+    format: Format,
+    output: Option<String>,
+}
+
+fn main() {
+    let options = parse_args(std::env::args().skip(1)).unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(2);
+    });
+
+    // Every strategy plays against the same sequence of randomness
+    let reports: Vec<_> = options
+        .strategies
+        .iter()
+        .map(|strategy| {
+            let mut rng = ChaCha8Rng::seed_from_u64(options.seed);
+            simulate(&options.config, strategy.as_ref(), &mut rng)
+        })
+        .collect();
+
+    let output = match options.format {
+        Format::Json => to_json(&options.config, options.seed, &reports),
+        Format::Csv => to_csv(&reports),
+    };
+
+    match options.output {
+        Some(path) => fs::write(&path, output).unwrap_or_else(|error| {
+            eprintln!("error: can't write {path}: {error}");
+            process::exit(1);
+        }),
+        None => print!("{output}"),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut params = GameConfigParams::default();
+    let mut runners = 1_000;
+    let mut max_moves = 10_000;
+    let mut initial_prize_pool = 0;
+    let mut strategies = None;
+    let mut seed = 0;
+    let mut format = Format::Json;
+    let mut output = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("missing value for {flag}"))
+        };
+
+        match flag.as_str() {
+            "--runners" => runners = parse(&value()?)?,
+            "--max-moves" => max_moves = parse(&value()?)?,
+            "--seed" => seed = parse(&value()?)?,
+            "--path-length" => params.path_length = parse(&value()?)?,
+            "--probability" => params.move_success_probability = parse(&value()?)?,
+            "--base-move-cost" => params.base_move_cost = parse(&value()?)?,
+            "--card-cost" => params.card_cost = parse(&value()?)?,
+            "--swift-discount" => params.swift_discount = parse(&value()?)?,
+            "--cipher-cost" => params.cipher_cost = parse(&value()?)?,
+            "--prize-pool-percentage" => {
+                params.prize_pool_percentage = parse(&value()?)?;
+                params.admin_percentage = 100u8.saturating_sub(params.prize_pool_percentage);
+            }
+            "--initial-prize-pool" => initial_prize_pool = parse(&value()?)?,
+            "--strategy" => {
+                let names = value()?;
+                strategies = Some(
+                    names
+                        .split(',')
+                        .map(|name| {
+                            strategy_by_name(name).ok_or_else(|| format!("unknown strategy {name}"))
+                        })
+                        .collect::<Result<Vec<_>, _>>()?,
+                );
+            }
+            "--format" => {
+                format = match value()?.as_str() {
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    other => return Err(format!("unknown format {other}")),
+                }
+            }
+            "--output" => output = Some(value()?),
+            other => return Err(format!("unknown argument {other}")),
+        }
+    }
+
+    // Keep the path length bounds out of the way of the simulated length
+    params.min_path_length = params.min_path_length.min(params.path_length);
+    params.max_path_length = params.max_path_length.max(params.path_length);
+    params
+        .validate()
+        .map_err(|error| format!("invalid game parameters: {error}"))?;
+
+    Ok(Options {
+        config: SimulationConfig {
+            runners,
+            max_moves,
+            path_length: params.path_length,
+            cipher_cost: params.cipher_cost,
+            prize_pool_percentage: params.prize_pool_percentage,
+            initial_prize_pool,
+            rules: params.rules(),
+        },
+        strategies: strategies.unwrap_or_else(all_strategies),
+        seed,
+        format,
+        output,
+    })
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid number {value}"))
+}
@@ -0,0 +1,76 @@
+use serde::Serialize;
+
+use crate::simulation::{SimulationConfig, StrategyReport};
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    seed: u64,
+    config: &'a SimulationConfig,
+    move_success_probability: u8,
+    base_move_cost: u64,
+    card_cost: u64,
+    swift_discount: u64,
+    strategies: &'a [StrategyReport],
+}
+
+/// Render the reports with the simulated parameters as pretty JSON
+pub fn to_json(config: &SimulationConfig, seed: u64, reports: &[StrategyReport]) -> String {
+    let report = JsonReport {
+        seed,
+        config,
+        move_success_probability: config.rules.move_success_probability,
+        base_move_cost: config.rules.base_move_cost,
+        card_cost: config.rules.card_cost,
+        swift_discount: config.rules.swift_discount,
+        strategies: reports,
+    };
+
+    let mut json = serde_json::to_string_pretty(&report).expect("reports serialize to JSON");
+    json.push('\n');
+    json
+}
+
+const CSV_HEADER: &str = "strategy,runners,wins,win_rate,ciphers_spent,ciphers_spent_per_win,\
+moves,average_moves_per_win,resets,average_resets_per_win,\
+shield_used,shield_ciphers_spent,shield_resets_prevented,shield_steps_saved,\
+doubler_used,doubler_ciphers_spent,doubler_extra_cards,\
+swift_used,swift_ciphers_spent,swift_ciphers_saved,\
+revenue_lamports,prize_pool_lamports,house_lamports,prizes_paid_lamports";
+
+/// Render the reports as CSV, one row per strategy
+pub fn to_csv(reports: &[StrategyReport]) -> String {
+    let mut csv = format!("{CSV_HEADER}\n");
+
+    for report in reports {
+        let row = [
+            report.strategy.clone(),
+            report.runners.to_string(),
+            report.wins.to_string(),
+            report.win_rate.to_string(),
+            report.ciphers_spent.to_string(),
+            report.ciphers_spent_per_win.to_string(),
+            report.moves.to_string(),
+            report.average_moves_per_win.to_string(),
+            report.resets.to_string(),
+            report.average_resets_per_win.to_string(),
+            report.shield.used.to_string(),
+            report.shield.ciphers_spent.to_string(),
+            report.shield.benefit.to_string(),
+            report.shield.steps_saved.to_string(),
+            report.doubler.used.to_string(),
+            report.doubler.ciphers_spent.to_string(),
+            report.doubler.benefit.to_string(),
+            report.swift.used.to_string(),
+            report.swift.ciphers_spent.to_string(),
+            report.swift.benefit.to_string(),
+            report.revenue_lamports.to_string(),
+            report.prize_pool_lamports.to_string(),
+            report.house_lamports.to_string(),
+            report.prizes_paid_lamports.to_string(),
+        ];
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
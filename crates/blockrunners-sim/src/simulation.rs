@@ -0,0 +1,206 @@
+use blockrunners_rules::{GameView, MoveOutcome, PathDirection, RandomnessStream, Rules, Runner};
+use rand::{Rng, RngCore};
+use serde::Serialize;
+
+use crate::strategy::Strategy;
+
+/// Game parameters of a simulation
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct SimulationConfig {
+    /// Number of runners simulated per strategy
+    pub runners: u64,
+
+    /// Moves after which a runner gives up
+    pub max_moves: u64,
+
+    /// Length of the path
+    pub path_length: u8,
+
+    /// Price of one cipher in lamports
+    pub cipher_cost: u64,
+
+    /// Share of every purchase going to the prize pool, the rest goes to the house
+    pub prize_pool_percentage: u8,
+
+    /// Prize pool in lamports when a runner joins
+    pub initial_prize_pool: u64,
+
+    #[serde(skip)]
+    pub rules: Rules,
+}
+
+/// How often a card was used and what it was worth
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+pub struct CardReport {
+    /// Number of moves the card was used on
+    pub used: u64,
+
+    /// Ciphers paid for using the card
+    pub ciphers_spent: u64,
+
+    /// Shield: resets prevented, doubler: extra cards collected, swift: ciphers saved
+    pub benefit: u64,
+
+    /// Shield: steps kept by prevented resets
+    pub steps_saved: u64,
+}
+
+/// Results of one strategy
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StrategyReport {
+    pub strategy: String,
+    pub runners: u64,
+    pub wins: u64,
+    pub win_rate: f64,
+
+    /// Ciphers spent on moves and cards, by all runners
+    pub ciphers_spent: u64,
+    pub ciphers_spent_per_win: f64,
+
+    /// Moves made by all runners
+    pub moves: u64,
+    pub average_moves_per_win: f64,
+    pub resets: u64,
+    pub average_resets_per_win: f64,
+
+    pub shield: CardReport,
+    pub doubler: CardReport,
+    pub swift: CardReport,
+
+    /// Lamports paid for ciphers and their split between the prize pool and the house
+    pub revenue_lamports: u64,
+    pub prize_pool_lamports: u64,
+    pub house_lamports: u64,
+
+    /// Lamports paid out to the winners
+    pub prizes_paid_lamports: u64,
+}
+
+/// Simulate `config.runners` runners playing with `strategy`, each in its own game
+pub fn simulate(
+    config: &SimulationConfig,
+    strategy: &dyn Strategy,
+    rng: &mut impl RngCore,
+) -> StrategyReport {
+    let mut report = StrategyReport {
+        strategy: strategy.name().to_string(),
+        runners: config.runners,
+        ..StrategyReport::default()
+    };
+
+    for _ in 0..config.runners {
+        simulate_runner(config, strategy, rng, &mut report);
+    }
+
+    let wins = report.wins.max(1) as f64;
+    report.win_rate = report.wins as f64 / config.runners.max(1) as f64;
+    report.ciphers_spent_per_win = report.ciphers_spent as f64 / wins;
+    report.average_moves_per_win = report.moves as f64 / wins;
+    report.average_resets_per_win = report.resets as f64 / wins;
+
+    report
+}
+
+fn simulate_runner(
+    config: &SimulationConfig,
+    strategy: &dyn Strategy,
+    rng: &mut impl RngCore,
+    report: &mut StrategyReport,
+) {
+    let rules = &config.rules;
+    let mut game = GameView {
+        path_seed: rng.gen(),
+        path_length: config.path_length,
+        prize_pool: config.initial_prize_pool,
+    };
+
+    // Join the game with the starting hand for the prize pool
+    let mut runner = Runner::default();
+    rules.deal_starting_hand(&mut runner, game.prize_pool, &mut Vec::new());
+
+    for _ in 0..config.max_moves {
+        let card_usage = strategy.choose_cards(&runner, game.path_length);
+        let direction = if rng.gen() {
+            PathDirection::Right
+        } else {
+            PathDirection::Left
+        };
+
+        // Buy just enough ciphers for the move
+        let cost = rules
+            .move_cost(&runner, &card_usage)
+            .expect("strategies only use held cards");
+        if runner.ciphers < cost {
+            let amount = cost - runner.ciphers;
+            let lamports = amount.saturating_mul(config.cipher_cost);
+            let prize_pool_amount =
+                lamports.saturating_mul(u64::from(config.prize_pool_percentage)) / 100;
+
+            runner.ciphers += amount;
+            runner.total_ciphers_bought += amount;
+            game.prize_pool += prize_pool_amount;
+            report.revenue_lamports += lamports;
+            report.prize_pool_lamports += prize_pool_amount;
+            report.house_lamports += lamports - prize_pool_amount;
+        }
+
+        let position = runner.position;
+        rules
+            .pay_for_move(&mut runner, &card_usage)
+            .expect("the runner holds enough ciphers and cards");
+        report.ciphers_spent += cost;
+        report.moves += 1;
+        track_card_costs(rules, &runner, card_usage, report);
+
+        let mut randomness = RandomnessStream::new(rng.gen());
+        let result = rules
+            .resolve_move(&mut runner, &game, direction, card_usage, &mut randomness)
+            .expect("a move never uses more than 32 random bytes");
+
+        match result.outcome {
+            MoveOutcome::Advanced | MoveOutcome::Won => {
+                // The doubler collects one card more than a plain correct step
+                if card_usage.doubler {
+                    report.doubler.benefit += 1;
+                }
+            }
+            MoveOutcome::ShieldSaved => {
+                report.shield.benefit += 1;
+                report.shield.steps_saved += u64::from(position);
+            }
+            MoveOutcome::Reset => report.resets += 1,
+        }
+
+        if result.outcome == MoveOutcome::Won {
+            report.wins += 1;
+            report.prizes_paid_lamports += game.prize_pool;
+            return;
+        }
+    }
+}
+
+fn track_card_costs(
+    rules: &Rules,
+    runner: &Runner,
+    card_usage: blockrunners_rules::CardUsage,
+    report: &mut StrategyReport,
+) {
+    for (used, card_report) in [
+        (card_usage.shield, &mut report.shield),
+        (card_usage.doubler, &mut report.doubler),
+        (card_usage.swift, &mut report.swift),
+    ] {
+        if used {
+            card_report.used += 1;
+            card_report.ciphers_spent += rules.card_cost;
+        }
+    }
+
+    // Swift saves at most the price of the move it's used on
+    if card_usage.swift {
+        let price = rules
+            .step_price(runner.position)
+            .saturating_add(rules.card_cost.saturating_mul(card_usage.count()));
+        report.swift.benefit += price.min(rules.swift_discount);
+    }
+}
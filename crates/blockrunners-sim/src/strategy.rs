@@ -0,0 +1,113 @@
+use blockrunners_rules::{Card, CardUsage, Runner};
+
+/// Decides which cards a runner uses on its next move
+pub trait Strategy {
+    /// Name used on the command line and in the reports
+    fn name(&self) -> &'static str;
+
+    /// Pick the cards for the next move, only cards the runner holds are used
+    fn choose_cards(&self, runner: &Runner, path_length: u8) -> CardUsage;
+}
+
+/// Never uses any cards
+pub struct Plain;
+
+impl Strategy for Plain {
+    fn name(&self) -> &'static str {
+        "plain"
+    }
+
+    fn choose_cards(&self, _runner: &Runner, _path_length: u8) -> CardUsage {
+        CardUsage::default()
+    }
+}
+
+/// Saves shields for the second half of the path, where a reset costs the most
+pub struct ShieldDeep;
+
+impl Strategy for ShieldDeep {
+    fn name(&self) -> &'static str {
+        "shield-deep"
+    }
+
+    fn choose_cards(&self, runner: &Runner, path_length: u8) -> CardUsage {
+        CardUsage {
+            shield: is_past_half(runner, path_length) && runner.cards.has_card(Card::Shield),
+            ..CardUsage::default()
+        }
+    }
+}
+
+/// Uses a swift card whenever it holds one
+pub struct SwiftOnly;
+
+impl Strategy for SwiftOnly {
+    fn name(&self) -> &'static str {
+        "swift-only"
+    }
+
+    fn choose_cards(&self, runner: &Runner, _path_length: u8) -> CardUsage {
+        CardUsage {
+            swift: runner.cards.has_card(Card::Swift),
+            ..CardUsage::default()
+        }
+    }
+}
+
+/// Doublers in the first half to build up cards, shields in the second half, swift always
+pub struct Balanced;
+
+impl Strategy for Balanced {
+    fn name(&self) -> &'static str {
+        "balanced"
+    }
+
+    fn choose_cards(&self, runner: &Runner, path_length: u8) -> CardUsage {
+        let past_half = is_past_half(runner, path_length);
+
+        CardUsage {
+            shield: past_half && runner.cards.has_card(Card::Shield),
+            doubler: !past_half && runner.cards.has_card(Card::Doubler),
+            swift: runner.cards.has_card(Card::Swift),
+        }
+    }
+}
+
+/// Uses every card it holds on every move
+pub struct AllIn;
+
+impl Strategy for AllIn {
+    fn name(&self) -> &'static str {
+        "all-in"
+    }
+
+    fn choose_cards(&self, runner: &Runner, _path_length: u8) -> CardUsage {
+        CardUsage {
+            shield: runner.cards.has_card(Card::Shield),
+            doubler: runner.cards.has_card(Card::Doubler),
+            swift: runner.cards.has_card(Card::Swift),
+        }
+    }
+}
+
+fn is_past_half(runner: &Runner, path_length: u8) -> bool {
+    u16::from(runner.position) * 2 >= u16::from(path_length)
+}
+
+/// Get all available strategies
+pub fn all_strategies() -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(Plain),
+        Box::new(ShieldDeep),
+        Box::new(SwiftOnly),
+        Box::new(Balanced),
+        Box::new(AllIn),
+    ]
+}
+
+/// Find a strategy by its name
+pub fn strategy_by_name(name: &str) -> Option<Box<dyn Strategy>> {
+    all_strategies()
+        .into_iter()
+        .find(|strategy| strategy.name() == name)
+}
@@ -0,0 +1,423 @@
+//! In-process SVM harness for the integration tests.
+//!
+//! The tests run the compiled program inside `solana-program-test`, without a validator. Build it
+//! with `anchor build` first; it's loaded from `SBF_OUT_DIR` when set, or from the workspace's
+//! `target/deploy`. Switchboard is replaced by randomness accounts whose data the tests write
+//! directly.
+
+use std::path::{Path, PathBuf};
+
+use anchor_lang::{
+    prelude::{Pubkey, Rent},
+    solana_program::instruction::Instruction,
+    AccountDeserialize,
+};
+use blockrunners::{
+    errors::BlockrunnersError,
+    state::{
+        CardUsage, FeedEntry, FeedPage, GameConfigParams, GameState, PathDirection, PauseFlags,
+        PlayerFeed, PlayerState,
+    },
+};
+use blockrunners_client::{instructions, pda};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader,
+    clock::Clock,
+    instruction::InstructionError,
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use switchboard_on_demand::{Discriminator, RandomnessAccountData, ON_DEMAND_MAINNET_PID};
+
+pub const GAME_ID: u64 = 1;
+
+/// Number of feed pages opened with the game, the tests open more when they need them
+pub const OPEN_FEED_PAGES: u64 = 4;
+
+/// Path of the compiled program
+pub fn program_path() -> PathBuf {
+    std::env::var_os("SBF_OUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target/deploy"))
+        .join("blockrunners.so")
+}
+
+/// A running SVM with an initialized game
+pub struct TestGame {
+    pub context: ProgramTestContext,
+    pub admin: Keypair,
+    /// Compute units consumed by the last processed transaction
+    pub compute_units: u64,
+}
+
+impl TestGame {
+    /// Start the SVM and initialize game `GAME_ID`
+    pub async fn start() -> Self {
+        let path = program_path();
+        let elf = std::fs::read(&path).unwrap_or_else(|err| {
+            panic!(
+                "can't read the compiled program at {}, build it with `anchor build`: {err}",
+                path.display()
+            )
+        });
+        let mut program_test = ProgramTest::default();
+        program_test.add_account(
+            blockrunners::ID,
+            Account {
+                lamports: Rent::default().minimum_balance(elf.len()),
+                data: elf,
+                owner: bpf_loader::ID,
+                executable: true,
+                rent_epoch: 0,
+            },
+        );
+        let context = program_test.start_with_context().await;
+
+        let mut game = Self {
+            context,
+            admin: Keypair::new(),
+            compute_units: 0,
+        };
+        game.airdrop(&game.admin.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .await;
+
+        let admin = game.admin.insecure_clone();
+        let mut setup = vec![instructions::initialize_game(admin.pubkey(), GAME_ID)];
+        setup.extend(
+            (1..OPEN_FEED_PAGES).map(|page_index| {
+                instructions::open_feed_page(admin.pubkey(), GAME_ID, page_index)
+            }),
+        );
+        game.process(&setup, &[&admin])
+            .await
+            .expect("game initializes");
+
+        game
+    }
+
+    /// Send `instructions` in one transaction, paid for by the test payer
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Vec<String>, BanksClientError> {
+        let blockhash = self.context.get_new_latest_blockhash().await?;
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        let result = self
+            .context
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        let (logs, compute_units) = result
+            .metadata
+            .map(|metadata| (metadata.log_messages, metadata.compute_units_consumed))
+            .unwrap_or_default();
+        result.result?;
+        self.compute_units = compute_units;
+
+        Ok(logs)
+    }
+
+    pub async fn airdrop(&mut self, to: &Pubkey, lamports: u64) {
+        let payer = self.context.payer.insecure_clone();
+        self.process(
+            &[solana_system_interface::instruction::transfer(
+                &payer.pubkey(),
+                to,
+                lamports,
+            )],
+            &[],
+        )
+        .await
+        .expect("airdrop succeeds");
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(*address)
+            .await
+            .expect("balance is readable")
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self
+            .context
+            .banks_client
+            .get_account(address)
+            .await
+            .expect("account is readable")
+            .expect("account exists");
+        T::try_deserialize(&mut account.data.as_slice()).expect("account decodes")
+    }
+
+    pub async fn game_state(&mut self) -> GameState {
+        self.account(pda::game_state(GAME_ID)).await
+    }
+
+    pub async fn player_state(&mut self, player: &Pubkey) -> PlayerState {
+        self.account(pda::player_state(player)).await
+    }
+
+    pub async fn player_feed(&mut self, player: &Pubkey) -> PlayerFeed {
+        self.account(pda::player_feed(player)).await
+    }
+
+    /// Index of the feed page the game currently writes to
+    pub async fn feed_page(&mut self) -> u64 {
+        self.game_state().await.feed_page
+    }
+
+    /// Get the whole game feed, oldest first, from the pages that are still open
+    pub async fn game_feed(&mut self) -> Vec<FeedEntry> {
+        let mut entries = Vec::new();
+        for page_index in 0..=self.feed_page().await {
+            let address = pda::feed_page(GAME_ID, page_index);
+            let exists = self
+                .context
+                .banks_client
+                .get_account(address)
+                .await
+                .expect("account is readable")
+                .is_some();
+            if exists {
+                let page: FeedPage = self.account(address).await;
+                entries.extend(page.entries);
+            }
+        }
+        entries
+    }
+
+    pub async fn slot(&mut self) -> u64 {
+        self.context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .expect("clock is readable")
+            .slot
+    }
+
+    /// Advance the SVM by `slots` slots, and its clock by a second per slot
+    pub async fn warp(&mut self, slots: u64) {
+        // Longer warps recompute the accounts hash, which needs thousands of open files
+        let slot = self.slot().await;
+        for slot in slot + 1..=slot + slots {
+            self.context.warp_to_slot(slot).expect("warp succeeds");
+        }
+
+        // The bank's clock doesn't move on its own, but game instances are told apart by the
+        // second they started in
+        let mut clock = self
+            .context
+            .banks_client
+            .get_sysvar::<Clock>()
+            .await
+            .expect("clock is readable");
+        clock.unix_timestamp += slots as i64;
+        self.context.set_sysvar(&clock);
+    }
+
+    pub async fn update_config(&mut self, params: GameConfigParams) {
+        let admin = self.admin.insecure_clone();
+        self.process(
+            &[instructions::update_config(admin.pubkey(), GAME_ID, params)],
+            &[&admin],
+        )
+        .await
+        .expect("config updates");
+    }
+
+    pub async fn set_pause(&mut self, paused: PauseFlags) {
+        let admin = self.admin.insecure_clone();
+        let feed_page = self.feed_page().await;
+        self.process(
+            &[instructions::set_pause(
+                admin.pubkey(),
+                GAME_ID,
+                paused,
+                feed_page,
+            )],
+            &[&admin],
+        )
+        .await
+        .expect("pause is set");
+    }
+
+    /// Create a funded player who joined the game
+    pub async fn join_player(&mut self) -> Keypair {
+        let player = Keypair::new();
+        self.airdrop(&player.pubkey(), 2 * LAMPORTS_PER_SOL).await;
+        let feed_page = self.feed_page().await;
+        self.process(
+            &[
+                instructions::initialize_player(player.pubkey()),
+                instructions::join_game(player.pubkey(), GAME_ID, feed_page),
+            ],
+            &[&player],
+        )
+        .await
+        .expect("player joins");
+
+        player
+    }
+
+    pub async fn purchase_ciphers(&mut self, player: &Keypair, amount: u64) {
+        let game_state = self.game_state().await;
+        self.process(
+            &[instructions::purchase_ciphers(
+                player.pubkey(),
+                game_state.fee_recipient,
+                GAME_ID,
+                amount,
+                game_state.feed_page,
+            )],
+            &[player],
+        )
+        .await
+        .expect("ciphers are purchased");
+    }
+
+    /// Write a Switchboard randomness account owned by the on-demand program
+    pub fn set_randomness(
+        &mut self,
+        address: &Pubkey,
+        seed_slot: u64,
+        reveal_slot: u64,
+        value: [u8; 32],
+    ) {
+        let mut data = vec![0u8; RandomnessAccountData::size()];
+        data[..8].copy_from_slice(RandomnessAccountData::DISCRIMINATOR);
+
+        // Field offsets after the discriminator: authority, queue, seed slot hash, seed slot,
+        // oracle, reveal slot and value
+        data[8 + 96..8 + 104].copy_from_slice(&seed_slot.to_le_bytes());
+        data[8 + 136..8 + 144].copy_from_slice(&reveal_slot.to_le_bytes());
+        data[8 + 144..8 + 176].copy_from_slice(&value);
+
+        self.context.set_account(
+            address,
+            &Account {
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                owner: Pubkey::new_from_array(ON_DEMAND_MAINNET_PID.to_bytes()),
+                executable: false,
+                rent_epoch: 0,
+            }
+            .into(),
+        );
+    }
+
+    /// Commit a move against a fresh Switchboard randomness account
+    pub async fn commit(
+        &mut self,
+        player: &Keypair,
+        randomness: &Pubkey,
+        direction: PathDirection,
+        card_usage: CardUsage,
+    ) -> Result<Vec<String>, BanksClientError> {
+        let seed_slot = self.slot().await - 1;
+        self.set_randomness(randomness, seed_slot, 0, [0; 32]);
+        self.process(
+            &[instructions::move_commit(
+                player.pubkey(),
+                GAME_ID,
+                *randomness,
+                direction,
+                card_usage,
+            )],
+            &[player],
+        )
+        .await
+    }
+
+    /// Publish `value` as the revealed randomness and reveal the committed move
+    pub async fn reveal(
+        &mut self,
+        player: &Keypair,
+        randomness: &Pubkey,
+        value: [u8; 32],
+    ) -> Result<Vec<String>, BanksClientError> {
+        self.warp(1).await;
+        let player_state = self.player_state(&player.pubkey()).await;
+        let seed_slot = player_state.randomness_slot.expect("move is committed");
+        let reveal_slot = self.slot().await;
+        self.set_randomness(randomness, seed_slot, reveal_slot, value);
+        let feed_page = self.feed_page().await;
+        self.process(
+            &[instructions::move_reveal(
+                player.pubkey(),
+                GAME_ID,
+                *randomness,
+                feed_page,
+            )],
+            &[player],
+        )
+        .await
+    }
+
+    /// Walk the whole path with `player`, who needs enough ciphers for every step
+    pub async fn win_game(&mut self, player: &Keypair) {
+        let randomness = Keypair::new().pubkey();
+        let path_length = self.game_state().await.path_length;
+        for _ in 0..path_length {
+            let (direction, value) = self.winning_move(&player.pubkey()).await;
+            self.commit(player, &randomness, direction, CardUsage::default())
+                .await
+                .expect("move is committed");
+            self.reveal(player, &randomness, value)
+                .await
+                .expect("move is revealed");
+        }
+    }
+
+    /// Get the direction and randomness that make the player's next step succeed
+    pub async fn winning_move(&mut self, player: &Pubkey) -> (PathDirection, [u8; 32]) {
+        let game_state = self.game_state().await;
+        let position = self.player_state(player).await.position;
+
+        // The first byte is the consensus roll, the second one decides the path step
+        let value = [0u8; 32];
+        let direction = blockrunners_rules::path_step(&game_state.path_seed, position, value[31]);
+
+        (direction, value)
+    }
+}
+
+/// The opposite direction
+pub fn opposite(direction: PathDirection) -> PathDirection {
+    match direction {
+        PathDirection::Left => PathDirection::Right,
+        PathDirection::Right => PathDirection::Left,
+    }
+}
+
+/// Get the program error a transaction failed with
+pub fn program_error(result: Result<Vec<String>, BanksClientError>) -> Option<u32> {
+    match result.err()? {
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => Some(code),
+        _ => None,
+    }
+}
+
+/// Check that a transaction failed with `error`
+pub fn assert_error(result: Result<Vec<String>, BanksClientError>, error: BlockrunnersError) {
+    assert_eq!(
+        program_error(result),
+        Some(u32::from(error)),
+        "expected {error:?}"
+    );
+}
@@ -0,0 +1,265 @@
+use anchor_lang::error::ErrorCode;
+use blockrunners::{errors::BlockrunnersError, state::GameConfigParams};
+use blockrunners_client::instructions;
+use blockrunners_tests::{assert_error, program_error, TestGame, GAME_ID};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn authority_changes_only_once_the_proposed_key_accepts() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let new_admin = Keypair::new();
+
+    game.process(
+        &[instructions::propose_authority(
+            admin.pubkey(),
+            GAME_ID,
+            Some(new_admin.pubkey()),
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("authority is proposed");
+
+    // Until the proposal is accepted, the old authority stays in charge
+    let game_state = game.game_state().await;
+    assert_eq!(game_state.authority, admin.pubkey());
+    assert_eq!(game_state.pending_authority, Some(new_admin.pubkey()));
+
+    game.process(
+        &[instructions::accept_authority(new_admin.pubkey(), GAME_ID)],
+        &[&new_admin],
+    )
+    .await
+    .expect("authority is accepted");
+
+    let game_state = game.game_state().await;
+    assert_eq!(game_state.authority, new_admin.pubkey());
+    assert_eq!(game_state.pending_authority, None);
+    // The fee recipient doesn't move with the authority
+    assert_eq!(game_state.fee_recipient, admin.pubkey());
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                admin.pubkey(),
+                GAME_ID,
+                GameConfigParams::default(),
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+
+    game.process(
+        &[instructions::update_config(
+            new_admin.pubkey(),
+            GAME_ID,
+            GameConfigParams::default(),
+        )],
+        &[&new_admin],
+    )
+    .await
+    .expect("new authority updates the config");
+}
+
+#[tokio::test]
+async fn stranger_cannot_propose_authority() {
+    let mut game = TestGame::start().await;
+    let stranger = Keypair::new();
+
+    let result = game
+        .process(
+            &[instructions::propose_authority(
+                stranger.pubkey(),
+                GAME_ID,
+                Some(stranger.pubkey()),
+            )],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
+
+#[tokio::test]
+async fn only_the_proposed_key_can_accept() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let new_admin = Keypair::new();
+    let stranger = Keypair::new();
+
+    // Nothing is proposed yet
+    let result = game
+        .process(
+            &[instructions::accept_authority(new_admin.pubkey(), GAME_ID)],
+            &[&new_admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::NotPendingAuthority);
+
+    game.process(
+        &[instructions::propose_authority(
+            admin.pubkey(),
+            GAME_ID,
+            Some(new_admin.pubkey()),
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("authority is proposed");
+
+    let result = game
+        .process(
+            &[instructions::accept_authority(stranger.pubkey(), GAME_ID)],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::NotPendingAuthority);
+    assert_eq!(game.game_state().await.authority, admin.pubkey());
+}
+
+#[tokio::test]
+async fn withdrawn_proposal_cannot_be_accepted() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let new_admin = Keypair::new();
+
+    game.process(
+        &[
+            instructions::propose_authority(admin.pubkey(), GAME_ID, Some(new_admin.pubkey())),
+            instructions::propose_authority(admin.pubkey(), GAME_ID, None),
+        ],
+        &[&admin],
+    )
+    .await
+    .expect("proposal is withdrawn");
+
+    let result = game
+        .process(
+            &[instructions::accept_authority(new_admin.pubkey(), GAME_ID)],
+            &[&new_admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::NotPendingAuthority);
+}
+
+#[tokio::test]
+async fn admin_share_goes_to_the_fee_recipient() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let treasury = Keypair::new().pubkey();
+    game.airdrop(&treasury, LAMPORTS_PER_SOL).await;
+
+    game.process(
+        &[instructions::set_fee_recipient(
+            admin.pubkey(),
+            GAME_ID,
+            treasury,
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("fee recipient is set");
+    assert_eq!(game.game_state().await.fee_recipient, treasury);
+
+    let player = game.join_player().await;
+    let admin_before = game.lamports(&admin.pubkey()).await;
+    let treasury_before = game.lamports(&treasury).await;
+
+    let amount = 100;
+    game.purchase_ciphers(&player, amount).await;
+
+    let params = GameConfigParams::default();
+    let cost = amount * params.cipher_cost;
+    let admin_share = cost - cost * params.prize_pool_percentage as u64 / 100;
+    assert_eq!(
+        game.lamports(&treasury).await - treasury_before,
+        admin_share
+    );
+    assert_eq!(game.lamports(&admin.pubkey()).await, admin_before);
+
+    // The authority no longer receives the admin share
+    let feed_page = game.feed_page().await;
+    let result = game
+        .process(
+            &[instructions::purchase_ciphers(
+                player.pubkey(),
+                admin.pubkey(),
+                GAME_ID,
+                amount,
+                feed_page,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_eq!(
+        program_error(result),
+        Some(u32::from(ErrorCode::ConstraintAddress))
+    );
+}
+
+#[tokio::test]
+async fn fresh_fee_recipient_must_be_rent_exempt_before_it_receives_purchases() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let treasury = Keypair::new().pubkey();
+
+    // The admin share of a single cipher is below the rent-exempt minimum
+    let result = game
+        .process(
+            &[instructions::set_fee_recipient(
+                admin.pubkey(),
+                GAME_ID,
+                treasury,
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::FeeRecipientNotRentExempt);
+
+    let rent_exempt_minimum = Rent::default().minimum_balance(0);
+    game.airdrop(&treasury, rent_exempt_minimum).await;
+    game.process(
+        &[instructions::set_fee_recipient(
+            admin.pubkey(),
+            GAME_ID,
+            treasury,
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("fee recipient is set");
+
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 1).await;
+
+    let params = GameConfigParams::default();
+    let admin_share =
+        params.cipher_cost - params.cipher_cost * params.prize_pool_percentage as u64 / 100;
+    assert_eq!(
+        game.lamports(&treasury).await,
+        rent_exempt_minimum + admin_share
+    );
+}
+
+#[tokio::test]
+async fn stranger_cannot_set_fee_recipient() {
+    let mut game = TestGame::start().await;
+    let stranger = Keypair::new();
+
+    let result = game
+        .process(
+            &[instructions::set_fee_recipient(
+                stranger.pubkey(),
+                GAME_ID,
+                stranger.pubkey(),
+            )],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
@@ -0,0 +1,261 @@
+use blockrunners::{
+    errors::BlockrunnersError,
+    state::{CipherCarryOver, GameConfigParams, SocialFeedEventType},
+};
+use blockrunners_client::{feed::feed_message, instructions, pda};
+use blockrunners_tests::{assert_error, TestGame, GAME_ID};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Start a game with `policy`, where `runner` holds 10 unspent ciphers when somebody else wins
+async fn game_won_by_someone_else(policy: CipherCarryOver) -> (TestGame, Keypair) {
+    let mut game = TestGame::start().await;
+    game.update_config(GameConfigParams {
+        cipher_carry_over: policy,
+        ..GameConfigParams::default()
+    })
+    .await;
+
+    let runner = game.join_player().await;
+    game.purchase_ciphers(&runner, 10).await;
+    let winner = game.join_player().await;
+    game.purchase_ciphers(&winner, 100).await;
+    game.win_game(&winner).await;
+
+    (game, runner)
+}
+
+async fn rejoin(game: &mut TestGame, runner: &Keypair) {
+    let feed_page = game.feed_page().await;
+    game.process(
+        &[instructions::join_game(runner.pubkey(), GAME_ID, feed_page)],
+        &[runner],
+    )
+    .await
+    .expect("runner joins the next game");
+}
+
+/// Get the message recording how the unspent ciphers of `runner` were settled
+async fn settlement(game: &mut TestGame, runner: &Keypair) -> String {
+    feed_message(
+        &game
+            .player_feed(&runner.pubkey())
+            .await
+            .iter()
+            .rfind(|event| event.event_type == SocialFeedEventType::CiphersSettled)
+            .expect("settlement is recorded"),
+    )
+}
+
+#[tokio::test]
+async fn unspent_ciphers_carry_into_the_next_game() {
+    let (mut game, runner) = game_won_by_someone_else(CipherCarryOver::CarryOver).await;
+
+    rejoin(&mut game, &runner).await;
+
+    let player_state = game.player_state(&runner.pubkey()).await;
+    assert_eq!(player_state.ciphers, 10);
+    assert_eq!(
+        settlement(&mut game, &runner).await,
+        "CIPHERS SECURED: 10 unspent ciphers carried into the new run."
+    );
+}
+
+#[tokio::test]
+async fn unspent_ciphers_can_be_forfeited() {
+    let (mut game, runner) = game_won_by_someone_else(CipherCarryOver::Forfeit).await;
+
+    rejoin(&mut game, &runner).await;
+
+    let player_state = game.player_state(&runner.pubkey()).await;
+    assert_eq!(player_state.ciphers, 0);
+    assert_eq!(
+        settlement(&mut game, &runner).await,
+        "CIPHERS PURGED: 10 unspent ciphers were lost with the last run."
+    );
+}
+
+#[tokio::test]
+async fn unspent_ciphers_are_refunded_from_the_reserve() {
+    let policy = CipherCarryOver::Refund {
+        refund_percentage: 10,
+    };
+    let (mut game, runner) = game_won_by_someone_else(policy).await;
+    let admin = game.admin.insecure_clone();
+    game.process(
+        &[instructions::fund_reserve(
+            admin.pubkey(),
+            GAME_ID,
+            1_000_000,
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("reserve is funded");
+
+    let reserve = pda::reserve_vault(GAME_ID);
+    let prize_pool_before = game.game_state().await.prize_pool;
+    let runner_before = game.lamports(&runner.pubkey()).await;
+    let reserve_before = game.lamports(&reserve).await;
+
+    rejoin(&mut game, &runner).await;
+
+    // 10 ciphers at 10% of the cipher cost, paid by the reserve and not the prize pool
+    let refund = 10 * GameConfigParams::default().cipher_cost / 10;
+    assert_eq!(
+        game.lamports(&runner.pubkey()).await - runner_before,
+        refund
+    );
+    assert_eq!(reserve_before - game.lamports(&reserve).await, refund);
+    assert_eq!(game.game_state().await.prize_pool, prize_pool_before);
+
+    let player_state = game.player_state(&runner.pubkey()).await;
+    assert_eq!(player_state.ciphers, 0);
+    assert_eq!(
+        settlement(&mut game, &runner).await,
+        "CIPHERS CASHED OUT: 10 unspent ciphers refunded for 1000 lamports."
+    );
+}
+
+#[tokio::test]
+async fn refund_is_paid_at_the_price_the_ciphers_were_bought_at() {
+    let policy = CipherCarryOver::Refund {
+        refund_percentage: 10,
+    };
+    let (mut game, runner) = game_won_by_someone_else(policy).await;
+    let admin = game.admin.insecure_clone();
+    game.process(
+        &[instructions::fund_reserve(
+            admin.pubkey(),
+            GAME_ID,
+            1_000_000,
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("reserve is funded");
+    game.update_config(GameConfigParams {
+        cipher_carry_over: policy,
+        cipher_cost: 100 * GameConfigParams::default().cipher_cost,
+        ..GameConfigParams::default()
+    })
+    .await;
+
+    rejoin(&mut game, &runner).await;
+
+    assert_eq!(
+        settlement(&mut game, &runner).await,
+        "CIPHERS CASHED OUT: 10 unspent ciphers refunded for 1000 lamports."
+    );
+}
+
+#[tokio::test]
+async fn refund_is_limited_by_the_reserve() {
+    let policy = CipherCarryOver::Refund {
+        refund_percentage: 10,
+    };
+    let (mut game, runner) = game_won_by_someone_else(policy).await;
+    let admin = game.admin.insecure_clone();
+    game.process(
+        &[instructions::fund_reserve(admin.pubkey(), GAME_ID, 400)],
+        &[&admin],
+    )
+    .await
+    .expect("reserve is funded");
+    let runner_before = game.lamports(&runner.pubkey()).await;
+
+    rejoin(&mut game, &runner).await;
+
+    assert_eq!(game.lamports(&runner.pubkey()).await - runner_before, 400);
+    let player_state = game.player_state(&runner.pubkey()).await;
+    assert_eq!(player_state.ciphers, 0);
+    assert_eq!(
+        settlement(&mut game, &runner).await,
+        "CIPHERS CASHED OUT: 10 ciphers refunded for 400 of 1000 lamports, reserve dry."
+    );
+}
+
+#[tokio::test]
+async fn refund_above_the_admin_share_is_rejected() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                admin.pubkey(),
+                GAME_ID,
+                GameConfigParams {
+                    cipher_carry_over: CipherCarryOver::Refund {
+                        refund_percentage: 13,
+                    },
+                    ..GameConfigParams::default()
+                },
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InvalidCipherCarryOver);
+}
+
+#[tokio::test]
+async fn unspent_ciphers_cannot_move_to_another_game() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let other_game = GAME_ID + 1;
+    game.process(
+        &[instructions::initialize_game(admin.pubkey(), other_game)],
+        &[&admin],
+    )
+    .await
+    .expect("other game initializes");
+
+    let runner = game.join_player().await;
+    game.purchase_ciphers(&runner, 10).await;
+
+    let result = game
+        .process(
+            &[instructions::join_game(runner.pubkey(), other_game, 0)],
+            &[&runner],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::UnspentCiphers);
+}
+
+#[tokio::test]
+async fn unspent_ciphers_are_not_forfeited_by_joining_another_game() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let other_game = GAME_ID + 1;
+    game.process(
+        &[
+            instructions::initialize_game(admin.pubkey(), other_game),
+            instructions::update_config(
+                admin.pubkey(),
+                other_game,
+                GameConfigParams {
+                    cipher_carry_over: CipherCarryOver::Forfeit,
+                    ..GameConfigParams::default()
+                },
+            ),
+        ],
+        &[&admin],
+    )
+    .await
+    .expect("other game initializes");
+
+    // Bought in a game that carries ciphers over
+    let runner = game.join_player().await;
+    game.purchase_ciphers(&runner, 10).await;
+
+    let result = game
+        .process(
+            &[instructions::join_game(runner.pubkey(), other_game, 0)],
+            &[&runner],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::UnspentCiphers);
+
+    let player_state = game.player_state(&runner.pubkey()).await;
+    assert_eq!(player_state.game_id, Some(GAME_ID));
+    assert_eq!(player_state.ciphers, 10);
+}
@@ -0,0 +1,148 @@
+use blockrunners::{
+    errors::BlockrunnersError,
+    state::{CardUsage, PlayerArchive},
+};
+use blockrunners_client::{instructions, pda};
+use blockrunners_tests::{assert_error, TestGame, GAME_ID};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn exists(game: &mut TestGame, address: &Pubkey) -> bool {
+    game.context
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("account is readable")
+        .is_some()
+}
+
+#[tokio::test]
+async fn closing_returns_the_rent_to_the_player() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let player_state = pda::player_state(&player.pubkey());
+    let player_feed = pda::player_feed(&player.pubkey());
+    let rent = game.lamports(&player_state).await + game.lamports(&player_feed).await;
+    let player_before = game.lamports(&player.pubkey()).await;
+
+    game.process(
+        &[instructions::close_player(player.pubkey(), false, false)],
+        &[&player],
+    )
+    .await
+    .expect("player closes");
+
+    assert!(!exists(&mut game, &player_state).await);
+    assert!(!exists(&mut game, &player_feed).await);
+    assert!(!exists(&mut game, &pda::player_archive(&player.pubkey())).await);
+    assert_eq!(game.lamports(&player.pubkey()).await - player_before, rent);
+}
+
+#[tokio::test]
+async fn unspent_ciphers_are_only_given_up_explicitly() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+
+    let result = game
+        .process(
+            &[instructions::close_player(player.pubkey(), false, false)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::UnspentCiphersRemaining);
+
+    game.process(
+        &[instructions::close_player(player.pubkey(), true, false)],
+        &[&player],
+    )
+    .await
+    .expect("player closes and forfeits the ciphers");
+    assert!(!exists(&mut game, &pda::player_state(&player.pubkey())).await);
+}
+
+#[tokio::test]
+async fn cannot_close_with_a_committed_move() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+    let (direction, _) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+
+    let result = game
+        .process(
+            &[instructions::close_player(player.pubkey(), true, true)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::MovePending);
+}
+
+#[tokio::test]
+async fn archive_keeps_the_statistics_of_every_closed_account() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+    let first = game.player_state(&player.pubkey()).await;
+
+    game.process(
+        &[instructions::close_player(player.pubkey(), true, true)],
+        &[&player],
+    )
+    .await
+    .expect("player closes");
+
+    let archive: PlayerArchive = game.account(pda::player_archive(&player.pubkey())).await;
+    assert_eq!(archive.player, player.pubkey());
+    assert_eq!(archive.accounts_closed, 1);
+    assert_eq!(archive.first_login, first.first_login);
+    assert_eq!(archive.total_ciphers_bought, 10);
+    assert_eq!(archive.total_steps, first.total_steps);
+    assert_eq!(archive.best_position, 1);
+
+    // A new account starts from scratch, closing it adds to the same archive
+    game.warp(1).await;
+    let feed_page = game.feed_page().await;
+    game.process(
+        &[
+            instructions::initialize_player(player.pubkey()),
+            instructions::join_game(player.pubkey(), GAME_ID, feed_page),
+        ],
+        &[&player],
+    )
+    .await
+    .expect("player joins again");
+    game.purchase_ciphers(&player, 5).await;
+    assert_eq!(
+        game.player_state(&player.pubkey())
+            .await
+            .total_ciphers_bought,
+        5
+    );
+    game.process(
+        &[instructions::close_player(player.pubkey(), true, true)],
+        &[&player],
+    )
+    .await
+    .expect("player closes again");
+
+    let archive: PlayerArchive = game.account(pda::player_archive(&player.pubkey())).await;
+    assert_eq!(archive.accounts_closed, 2);
+    assert_eq!(archive.first_login, first.first_login);
+    assert!(archive.last_login > first.last_login);
+    assert_eq!(archive.total_ciphers_bought, 15);
+    assert_eq!(archive.best_position, 1);
+}
@@ -0,0 +1,441 @@
+use blockrunners::{
+    constants::MAX_STEP_PRICE_TIERS,
+    errors::BlockrunnersError,
+    state::{CardUsage, GameConfigParams, PathDirection, StepPriceCurve},
+};
+use blockrunners_client::instructions;
+use blockrunners_tests::{assert_error, TestGame, GAME_ID};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn stranger_cannot_update_config() {
+    let mut game = TestGame::start().await;
+    let stranger = Keypair::new();
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                stranger.pubkey(),
+                GAME_ID,
+                GameConfigParams::default(),
+            )],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
+
+#[tokio::test]
+async fn invalid_config_is_rejected() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                admin.pubkey(),
+                GAME_ID,
+                GameConfigParams {
+                    prize_pool_percentage: 90,
+                    admin_percentage: 20,
+                    ..GameConfigParams::default()
+                },
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InvalidRevenueSplit);
+}
+
+#[tokio::test]
+async fn tiered_step_price_of_zero_is_rejected() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let mut prices = [10; MAX_STEP_PRICE_TIERS];
+    prices[3] = 0;
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                admin.pubkey(),
+                GAME_ID,
+                GameConfigParams {
+                    step_price_curve: StepPriceCurve::Tiered {
+                        tier_length: 2,
+                        prices,
+                    },
+                    ..GameConfigParams::default()
+                },
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InvalidStepPriceCurve);
+}
+
+#[tokio::test]
+async fn player_cannot_join_twice() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let feed_page = game.feed_page().await;
+
+    let result = game
+        .process(
+            &[instructions::join_game(player.pubkey(), GAME_ID, feed_page)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::PlayerAlreadyJoinedGame);
+}
+
+#[tokio::test]
+async fn purchase_of_zero_ciphers_fails() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let game_state = game.game_state().await;
+
+    let result = game
+        .process(
+            &[instructions::purchase_ciphers(
+                player.pubkey(),
+                game_state.fee_recipient,
+                GAME_ID,
+                0,
+                game_state.feed_page,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::NegativeCiphersAmount);
+}
+
+#[tokio::test]
+async fn ciphers_cannot_be_bought_in_another_game() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let other_game = GAME_ID + 1;
+    game.process(
+        &[instructions::initialize_game(admin.pubkey(), other_game)],
+        &[&admin],
+    )
+    .await
+    .expect("other game initializes");
+    let player = game.join_player().await;
+
+    let result = game
+        .process(
+            &[instructions::purchase_ciphers(
+                player.pubkey(),
+                admin.pubkey(),
+                other_game,
+                10,
+                0,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::PlayingInDifferentGame);
+}
+
+#[tokio::test]
+async fn commit_without_ciphers_fails() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let randomness = Keypair::new().pubkey();
+
+    let result = game
+        .commit(
+            &player,
+            &randomness,
+            PathDirection::Left,
+            CardUsage::default(),
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InsufficientBalance);
+}
+
+#[tokio::test]
+async fn committed_move_cannot_be_replaced() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    game.commit(
+        &player,
+        &Keypair::new().pubkey(),
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+
+    let result = game
+        .commit(
+            &player,
+            &Keypair::new().pubkey(),
+            PathDirection::Right,
+            CardUsage::default(),
+        )
+        .await;
+    assert_error(result, BlockrunnersError::MovePending);
+}
+
+#[tokio::test]
+async fn reveal_without_commit_fails() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let randomness = Keypair::new().pubkey();
+    game.set_randomness(&randomness, 0, 0, [0; 32]);
+    let feed_page = game.feed_page().await;
+
+    let result = game
+        .process(
+            &[instructions::move_reveal(
+                player.pubkey(),
+                GAME_ID,
+                randomness,
+                feed_page,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::MoveNotCommitted);
+}
+
+#[tokio::test]
+async fn randomness_account_of_another_program_is_rejected() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+
+    let forged = Keypair::new().pubkey();
+    let slot = game.slot().await;
+    game.set_randomness(&forged, slot - 1, 0, [0; 32]);
+    let mut account: Account = game
+        .context
+        .banks_client
+        .get_account(forged)
+        .await
+        .unwrap()
+        .unwrap();
+    account.owner = player.pubkey();
+    game.context.set_account(&forged, &account.into());
+
+    let result = game
+        .process(
+            &[instructions::move_commit(
+                player.pubkey(),
+                GAME_ID,
+                forged,
+                PathDirection::Left,
+                CardUsage::default(),
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InvalidRandomnessAccountOwner);
+}
+
+#[tokio::test]
+async fn stale_randomness_is_rejected() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    game.warp(5).await;
+
+    let randomness = Keypair::new().pubkey();
+    let slot = game.slot().await;
+    game.set_randomness(&randomness, slot - 3, 0, [0; 32]);
+
+    let result = game
+        .process(
+            &[instructions::move_commit(
+                player.pubkey(),
+                GAME_ID,
+                randomness,
+                PathDirection::Left,
+                CardUsage::default(),
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::RandomnessStale);
+}
+
+#[tokio::test]
+async fn revealed_randomness_is_rejected_on_commit() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+
+    let randomness = Keypair::new().pubkey();
+    let slot = game.slot().await;
+    game.set_randomness(&randomness, slot - 1, slot, [0; 32]);
+
+    let result = game
+        .process(
+            &[instructions::move_commit(
+                player.pubkey(),
+                GAME_ID,
+                randomness,
+                PathDirection::Left,
+                CardUsage::default(),
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::RandomnessAlreadyRevealed);
+}
+
+#[tokio::test]
+async fn unrevealed_randomness_cannot_be_used() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    game.commit(
+        &player,
+        &randomness,
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+    game.warp(1).await;
+    let feed_page = game.feed_page().await;
+
+    let result = game
+        .process(
+            &[instructions::move_reveal(
+                player.pubkey(),
+                GAME_ID,
+                randomness,
+                feed_page,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::RandomnessNotResolved);
+}
+
+#[tokio::test]
+async fn move_cannot_be_cancelled_before_it_expires() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    game.commit(
+        &player,
+        &randomness,
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+
+    let result = game
+        .process(
+            &[instructions::cancel_move(
+                player.pubkey(),
+                GAME_ID,
+                randomness,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::MoveCommitNotExpired);
+}
+
+#[tokio::test]
+async fn expired_move_cannot_be_revealed_but_can_be_cancelled() {
+    let mut game = TestGame::start().await;
+    let params = GameConfigParams {
+        move_timeout_slots: 5,
+        ..GameConfigParams::default()
+    };
+    game.update_config(params).await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    game.commit(
+        &player,
+        &randomness,
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+    game.warp(params.move_timeout_slots + 1).await;
+
+    let result = game.reveal(&player, &randomness, [0; 32]).await;
+    assert_error(result, BlockrunnersError::MoveCommitExpired);
+
+    // The randomness is public by now, so cancelling forfeits the move
+    game.process(
+        &[instructions::cancel_move(
+            player.pubkey(),
+            GAME_ID,
+            randomness,
+        )],
+        &[&player],
+    )
+    .await
+    .expect("move is cancelled");
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.move_direction, None);
+    assert_eq!(player_state.total_resets, 1);
+    assert_eq!(
+        player_state.ciphers,
+        10 - GameConfigParams::default().base_move_cost
+    );
+}
+
+#[tokio::test]
+async fn move_expired_without_a_reveal_still_costs_its_price() {
+    let mut game = TestGame::start().await;
+    let params = GameConfigParams {
+        move_timeout_slots: 5,
+        ..GameConfigParams::default()
+    };
+    game.update_config(params).await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    game.commit(
+        &player,
+        &randomness,
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+    assert_eq!(
+        game.player_state(&player.pubkey()).await.ciphers,
+        10 - params.base_move_cost
+    );
+
+    // The player may know a losing outcome from the oracle and never post it
+    game.warp(params.move_timeout_slots + 1).await;
+    game.process(
+        &[instructions::cancel_move(
+            player.pubkey(),
+            GAME_ID,
+            randomness,
+        )],
+        &[&player],
+    )
+    .await
+    .expect("move is cancelled");
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.move_direction, None);
+    assert_eq!(player_state.total_resets, 0);
+    assert_eq!(player_state.ciphers, 10 - params.base_move_cost);
+}
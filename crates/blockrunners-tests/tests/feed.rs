@@ -0,0 +1,173 @@
+use anchor_lang::{prelude::Pubkey, AnchorSerialize, Discriminator};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use blockrunners::{
+    constants::FEED_EVENT_VERSION,
+    state::{
+        Card, CardUsage, FeedPayload, PauseFlags, PlayerFeed, SocialFeedEvent, SocialFeedEventType,
+    },
+};
+use blockrunners_client::{
+    events::decode_social_feed_events,
+    feed::{payload_message, VersionedFeedEvent},
+};
+
+/// Log `data` the way `emit!` logs a feed event
+fn feed_log(data: impl AnchorSerialize) -> String {
+    let mut bytes = SocialFeedEvent::DISCRIMINATOR.to_vec();
+    data.serialize(&mut bytes).unwrap();
+    format!("Program data: {}", STANDARD.encode(bytes))
+}
+
+#[test]
+fn logged_events_decode_with_their_payload() {
+    let payload = FeedPayload::CiphersPurchased {
+        amount: 5,
+        cost: 5_000,
+        ciphers: 7,
+    };
+    let logs = vec![feed_log(SocialFeedEvent::new(payload.clone(), 42))];
+
+    let events = decode_social_feed_events(&logs);
+    let [VersionedFeedEvent::Current(event)] = events.as_slice() else {
+        panic!("expected one current event, got {events:?}");
+    };
+    assert_eq!(event.payload, payload);
+    assert_eq!(event.event_type, SocialFeedEventType::CiphersPurchased);
+    assert_eq!(event.timestamp, 42);
+    assert_eq!(
+        events[0].message(),
+        "RESOURCES ACQUIRED: 5 computational ciphers purchased for 5000 lamports. Your current reserves: 7"
+    );
+}
+
+#[test]
+fn legacy_events_still_decode() {
+    // Before payloads, events were logged with their message formatted on-chain, starting with
+    // the event type where current events have their version
+    let message = "RESOURCES ACQUIRED: 5 computational ciphers purchased for 5000 lamports. Your current reserves: 7";
+    let logs = vec![feed_log((
+        SocialFeedEventType::CiphersPurchased,
+        message.to_string(),
+        42i64,
+    ))];
+
+    let events = decode_social_feed_events(&logs);
+    let [VersionedFeedEvent::Legacy(event)] = events.as_slice() else {
+        panic!("expected one legacy event, got {events:?}");
+    };
+    assert_eq!(event.event_type, SocialFeedEventType::CiphersPurchased);
+    assert_eq!(events[0].message(), message);
+    assert_eq!(events[0].timestamp(), 42);
+}
+
+#[test]
+fn no_legacy_event_type_is_mistaken_for_a_version() {
+    let last_event_type = SocialFeedEventType::CiphersRedeemed as u8;
+    assert!(FEED_EVENT_VERSION > last_event_type);
+
+    for event_type in 0..=last_event_type {
+        let logs = vec![feed_log((event_type, "legacy".to_string(), 42i64))];
+        let events = decode_social_feed_events(&logs);
+        assert!(
+            matches!(events.as_slice(), [VersionedFeedEvent::Legacy(_)]),
+            "event type {event_type} decoded as {events:?}"
+        );
+    }
+}
+
+#[test]
+fn payloads_render_as_english_messages() {
+    let player = Pubkey::new_from_array([7; 32]);
+    let key = player.to_string();
+    assert_eq!(
+        payload_message(&FeedPayload::GameWon {
+            player,
+            steps: 10,
+            prize: 500,
+        }),
+        format!(
+            "CONSENSUS BREACH: Runner {}...{} extracted protocol fragment after 10 steps. Reward: 500 lamports",
+            &key[..4],
+            &key[key.len() - 4..]
+        )
+    );
+    assert_eq!(
+        payload_message(&FeedPayload::CardsCollected {
+            cards: vec![Card::Shield, Card::Swift],
+        }),
+        "You have collected 2 new cards: Shield, Swift"
+    );
+    assert_eq!(
+        payload_message(&FeedPayload::Reset {
+            from_position: 3,
+            card_usage: CardUsage {
+                swift: true,
+                ..CardUsage::default()
+            },
+        }),
+        "CONSENSUS DETECTED: Connection severed at depth 3. Returning to entry point. Cards used: Swift (cipher refund)"
+    );
+    assert_eq!(
+        payload_message(&FeedPayload::PauseChanged {
+            paused: PauseFlags {
+                joins: true,
+                reveals: true,
+                ..PauseFlags::default()
+            },
+        }),
+        "SYSTEM LOCKDOWN: The Consensus froze joins, reveals. Stand by, runners."
+    );
+    assert_eq!(
+        payload_message(&FeedPayload::CiphersRefunded {
+            ciphers: 10,
+            refund: 400,
+            owed: 1_000,
+        }),
+        "CIPHERS CASHED OUT: 10 ciphers refunded for 400 of 1000 lamports, reserve dry."
+    );
+}
+
+/// Record events with timestamps `timestamps` in `feed`
+fn record(feed: &mut PlayerFeed, timestamps: std::ops::Range<i64>, capacity: u8) {
+    for timestamp in timestamps {
+        let payload = FeedPayload::PersonalBest { position: 1 };
+        feed.push(SocialFeedEvent::new(payload, timestamp), capacity)
+            .unwrap();
+    }
+}
+
+fn timestamps(feed: &PlayerFeed) -> Vec<i64> {
+    feed.iter().map(|event| event.timestamp).collect()
+}
+
+#[test]
+fn full_feed_overwrites_its_oldest_events() {
+    let mut feed = PlayerFeed::default();
+    record(&mut feed, 0..25, 20);
+
+    assert_eq!(feed.len(), 20);
+    assert_eq!(feed.next_sequence, 25);
+    assert_eq!(timestamps(&feed), (5..25).collect::<Vec<_>>());
+    assert_eq!(feed.last().unwrap().timestamp, 24);
+
+    // Clients page by sequence number, here the events recorded at 22, 23 and 24
+    let page = feed
+        .since(22)
+        .map(|entry| (entry.sequence, entry.event.timestamp))
+        .collect::<Vec<_>>();
+    assert_eq!(page, vec![(22, 22), (23, 23), (24, 24)]);
+}
+
+#[test]
+fn feed_follows_capacity_changes() {
+    let mut feed = PlayerFeed::default();
+    record(&mut feed, 0..25, 20);
+
+    record(&mut feed, 25..26, 5);
+    assert_eq!(timestamps(&feed), (21..26).collect::<Vec<_>>());
+
+    record(&mut feed, 26..28, 5);
+    record(&mut feed, 28..31, 10);
+    assert_eq!(timestamps(&feed), (23..31).collect::<Vec<_>>());
+    assert_eq!(feed.since(0).next().unwrap().sequence, 23);
+}
@@ -0,0 +1,235 @@
+use anchor_lang::error::ErrorCode;
+use blockrunners::{
+    constants::FEED_PAGE_EVENTS,
+    errors::BlockrunnersError,
+    state::{FeedPage, FeedPayload, PauseFlags},
+};
+use blockrunners_client::{instructions, pda};
+use blockrunners_tests::{assert_error, program_error, TestGame, GAME_ID, OPEN_FEED_PAGES};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+
+/// Announce `count` pause changes to the game feed
+async fn toggle_pause(game: &mut TestGame, count: u8) {
+    for i in 0..count {
+        game.set_pause(PauseFlags {
+            purchases: i % 2 == 0,
+            ..PauseFlags::default()
+        })
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn full_page_moves_the_feed_to_the_next_page() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    toggle_pause(&mut game, FEED_PAGE_EVENTS - 1).await;
+
+    let game_state = game.game_state().await;
+    assert_eq!(game_state.feed_page, 1);
+    assert_eq!(game_state.feed_sequence, u64::from(FEED_PAGE_EVENTS));
+    let page: FeedPage = game.account(pda::feed_page(GAME_ID, 0)).await;
+    assert_eq!(page.game_id, GAME_ID);
+    assert_eq!(page.page_index, 0);
+    assert_eq!(page.payer, game.admin.pubkey());
+    assert_eq!(page.entries.len(), usize::from(FEED_PAGE_EVENTS));
+
+    // The next event goes to the page the authority opened in advance
+    toggle_pause(&mut game, 1).await;
+    let page: FeedPage = game.account(pda::feed_page(GAME_ID, 1)).await;
+    assert_eq!(page.page_index, 1);
+    assert_eq!(page.payer, game.admin.pubkey());
+    assert_eq!(page.entries.len(), 1);
+    assert_eq!(page.entries[0].sequence, u64::from(FEED_PAGE_EVENTS));
+}
+
+#[tokio::test]
+async fn pages_keep_the_whole_history() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    toggle_pause(&mut game, 2 * FEED_PAGE_EVENTS).await;
+
+    let feed = game.game_feed().await;
+    assert_eq!(feed.len(), usize::from(2 * FEED_PAGE_EVENTS) + 1);
+    assert!(feed
+        .iter()
+        .enumerate()
+        .all(|(sequence, entry)| entry.sequence == sequence as u64));
+    assert_eq!(
+        feed[0].event.payload,
+        FeedPayload::PlayerJoined {
+            player: player.pubkey()
+        }
+    );
+}
+
+#[tokio::test]
+async fn closing_an_old_page_refunds_its_payer() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    toggle_pause(&mut game, FEED_PAGE_EVENTS).await;
+
+    let admin = game.admin.insecure_clone();
+    let page = pda::feed_page(GAME_ID, 0);
+    let rent = game.lamports(&page).await;
+    let admin_before = game.lamports(&admin.pubkey()).await;
+
+    game.process(
+        &[instructions::close_feed_page(
+            admin.pubkey(),
+            GAME_ID,
+            0,
+            admin.pubkey(),
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("page is closed");
+
+    assert_eq!(game.lamports(&page).await, 0);
+    assert_eq!(game.lamports(&admin.pubkey()).await - admin_before, rent);
+
+    // The rest of the history is still there
+    assert_eq!(game.game_feed().await.len(), 1);
+}
+
+#[tokio::test]
+async fn current_page_cannot_be_closed() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    let admin = game.admin.insecure_clone();
+
+    let result = game
+        .process(
+            &[instructions::close_feed_page(
+                admin.pubkey(),
+                GAME_ID,
+                0,
+                admin.pubkey(),
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::FeedPageInUse);
+}
+
+#[tokio::test]
+async fn stranger_cannot_close_pages() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    toggle_pause(&mut game, FEED_PAGE_EVENTS).await;
+    let stranger = Keypair::new();
+    let admin = game.admin.pubkey();
+
+    let result = game
+        .process(
+            &[instructions::close_feed_page(
+                stranger.pubkey(),
+                GAME_ID,
+                0,
+                admin,
+            )],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
+
+#[tokio::test]
+async fn players_wait_for_the_authority_to_open_the_next_page() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    toggle_pause(&mut game, OPEN_FEED_PAGES as u8 * FEED_PAGE_EVENTS - 1).await;
+    let feed_page = game.feed_page().await;
+    assert_eq!(feed_page, OPEN_FEED_PAGES);
+
+    // Players never pay for a page, their events wait until the authority opens it
+    let player = Keypair::new();
+    game.airdrop(&player.pubkey(), 2 * LAMPORTS_PER_SOL).await;
+    game.process(
+        &[instructions::initialize_player(player.pubkey())],
+        &[&player],
+    )
+    .await
+    .expect("player initializes");
+    let join = || instructions::join_game(player.pubkey(), GAME_ID, feed_page);
+    let result = game.process(&[join()], &[&player]).await;
+    assert_eq!(
+        program_error(result),
+        Some(u32::from(ErrorCode::AccountNotInitialized))
+    );
+
+    let admin = game.admin.insecure_clone();
+    game.process(
+        &[instructions::open_feed_page(
+            admin.pubkey(),
+            GAME_ID,
+            feed_page,
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("page opens");
+    game.process(&[join()], &[&player])
+        .await
+        .expect("player joins");
+
+    let page: FeedPage = game.account(pda::feed_page(GAME_ID, feed_page)).await;
+    assert_eq!(page.page_index, feed_page);
+    assert_eq!(page.payer, admin.pubkey());
+    assert_eq!(
+        page.entries[0].event.payload,
+        FeedPayload::PlayerJoined {
+            player: player.pubkey()
+        }
+    );
+}
+
+#[tokio::test]
+async fn pages_the_game_moved_on_from_cannot_be_opened() {
+    let mut game = TestGame::start().await;
+    game.join_player().await;
+    toggle_pause(&mut game, FEED_PAGE_EVENTS).await;
+    let admin = game.admin.insecure_clone();
+    game.process(
+        &[instructions::close_feed_page(
+            admin.pubkey(),
+            GAME_ID,
+            0,
+            admin.pubkey(),
+        )],
+        &[&admin],
+    )
+    .await
+    .expect("page is closed");
+
+    let result = game
+        .process(
+            &[instructions::open_feed_page(admin.pubkey(), GAME_ID, 0)],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::FeedPageOutdated);
+}
+
+#[tokio::test]
+async fn stranger_cannot_open_pages() {
+    let mut game = TestGame::start().await;
+    let stranger = Keypair::new();
+    game.airdrop(&stranger.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let result = game
+        .process(
+            &[instructions::open_feed_page(
+                stranger.pubkey(),
+                GAME_ID,
+                OPEN_FEED_PAGES,
+            )],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
@@ -0,0 +1,335 @@
+use blockrunners::{
+    constants::MAX_FEED_EVENTS,
+    errors::BlockrunnersError,
+    state::{CardUsage, FeedPayload, GameConfigParams, SocialFeedEvent, SocialFeedEventType},
+};
+use blockrunners_client::{
+    events::decode_social_feed_events,
+    feed::{feed_message, VersionedFeedEvent},
+    instructions, pda,
+};
+use blockrunners_tests::{assert_error, opposite, TestGame, GAME_ID};
+use solana_sdk::{rent::Rent, signature::Signer, signer::keypair::Keypair};
+
+#[tokio::test]
+async fn initializes_game_with_funded_vault() {
+    let mut game = TestGame::start().await;
+
+    let game_state = game.game_state().await;
+    assert_eq!(game_state.game_id, GAME_ID);
+    assert_eq!(game_state.authority, game.admin.pubkey());
+    assert_eq!(game_state.pending_authority, None);
+    assert_eq!(game_state.fee_recipient, game.admin.pubkey());
+    assert_eq!(game_state.prize_pool, 0);
+
+    // The vault only holds its rent reserve
+    let vault_lamports = game.lamports(&pda::prize_vault(GAME_ID)).await;
+    assert_eq!(vault_lamports, Rent::default().minimum_balance(0));
+}
+
+#[tokio::test]
+async fn player_joins_with_starting_hand() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    let game_state = game.game_state().await;
+    assert_eq!(player_state.game_id, Some(GAME_ID));
+    assert_eq!(player_state.game_start, Some(game_state.start));
+    assert_eq!(player_state.position, 0);
+    assert_eq!(
+        player_state.cards,
+        GameConfigParams::default()
+            .rules()
+            .starting_hand(game_state.prize_pool)
+    );
+}
+
+#[tokio::test]
+async fn purchase_splits_revenue_and_conserves_lamports() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    let admin = game.admin.pubkey();
+    let vault = pda::prize_vault(GAME_ID);
+
+    let player_before = game.lamports(&player.pubkey()).await;
+    let admin_before = game.lamports(&admin).await;
+    let vault_before = game.lamports(&vault).await;
+
+    let amount = 37;
+    game.purchase_ciphers(&player, amount).await;
+
+    let params = GameConfigParams::default();
+    let cost = amount * params.cipher_cost;
+    let prize_share = cost * u64::from(params.prize_pool_percentage) / 100;
+
+    // The test payer pays the fees, so the player pays exactly the cost
+    assert_eq!(player_before - game.lamports(&player.pubkey()).await, cost);
+    assert_eq!(game.lamports(&vault).await - vault_before, prize_share);
+    assert_eq!(
+        game.lamports(&admin).await - admin_before,
+        cost - prize_share
+    );
+
+    assert_eq!(game.game_state().await.prize_pool, prize_share);
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.ciphers, amount);
+    assert_eq!(player_state.total_ciphers_bought, amount);
+}
+
+#[tokio::test]
+async fn correct_move_advances_and_collects_a_card() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+    let before = game.player_state(&player.pubkey()).await;
+
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    let logs = game
+        .reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    let after = game.player_state(&player.pubkey()).await;
+    assert_eq!(after.position, 1);
+    assert_eq!(
+        after.ciphers,
+        before.ciphers - GameConfigParams::default().base_move_cost
+    );
+    assert_eq!(after.cards.total_cards(), before.cards.total_cards() + 1);
+    assert_eq!(after.move_direction, None);
+    assert_eq!(after.randomness_value, None);
+
+    let advanced = |event: &SocialFeedEvent| {
+        event.event_type == SocialFeedEventType::PlayerMoved
+            && event.payload
+                == FeedPayload::Advanced {
+                    position: 1,
+                    card_usage: CardUsage::default(),
+                }
+    };
+    let event = game
+        .player_feed(&player.pubkey())
+        .await
+        .iter()
+        .find(|event| advanced(event))
+        .expect("advance is recorded");
+    assert_eq!(feed_message(&event), "Advanced to position 1!");
+
+    // The feed events are also logged with the transaction
+    assert!(decode_social_feed_events(&logs)
+        .iter()
+        .any(|event| matches!(event, VersionedFeedEvent::Current(event) if advanced(event))));
+}
+
+/// Play a correct first move and get the compute units its reveal took
+async fn reveal_compute_units(game: &mut TestGame, player: &Keypair) -> u64 {
+    let randomness = Keypair::new().pubkey();
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.reveal(player, &randomness, value)
+        .await
+        .expect("move is revealed");
+    game.compute_units
+}
+
+#[tokio::test]
+async fn reveal_costs_the_same_with_a_full_feed() {
+    // The baseline is the same reveal with an almost empty feed
+    let mut fresh = TestGame::start().await;
+    let player = fresh.join_player().await;
+    fresh.purchase_ciphers(&player, 100).await;
+    let fresh_units = reveal_compute_units(&mut fresh, &player).await;
+
+    let mut busy = TestGame::start().await;
+    let player = busy.join_player().await;
+    busy.purchase_ciphers(&player, 100).await;
+    for _ in 0..MAX_FEED_EVENTS + 5 {
+        busy.purchase_ciphers(&player, 1).await;
+    }
+    let feed = busy.player_feed(&player.pubkey()).await;
+    assert_eq!(feed.len(), usize::from(MAX_FEED_EVENTS));
+    let busy_units = reveal_compute_units(&mut busy, &player).await;
+
+    assert!(
+        busy_units <= fresh_units + 1_000,
+        "reveal took {busy_units} compute units with a full feed, {fresh_units} with an empty one"
+    );
+}
+
+#[tokio::test]
+async fn wrong_move_resets_to_start() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    // Take one step, then step the wrong way
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(
+        &player,
+        &randomness,
+        opposite(direction),
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.position, 0);
+    assert_eq!(player_state.total_resets, 1);
+}
+
+#[tokio::test]
+async fn shield_keeps_position_on_wrong_move() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    let shield = CardUsage {
+        shield: true,
+        ..CardUsage::default()
+    };
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, opposite(direction), shield)
+        .await
+        .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.position, 1);
+    assert_eq!(player_state.shields_used, 1);
+    assert_eq!(player_state.total_resets, 0);
+}
+
+#[tokio::test]
+async fn win_pays_out_the_prize_pool_and_conserves_lamports() {
+    let mut game = TestGame::start().await;
+    game.update_config(GameConfigParams {
+        path_length: 1,
+        min_path_length: 1,
+        ..GameConfigParams::default()
+    })
+    .await;
+
+    // A new game instance picks up the shorter path, so start one by winning the current one
+    let first = game.join_player().await;
+    game.purchase_ciphers(&first, 100).await;
+    game.win_game(&first).await;
+    assert_eq!(game.game_state().await.path_length, 1);
+
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 1_000).await;
+    let randomness = Keypair::new().pubkey();
+    let vault = pda::prize_vault(GAME_ID);
+    let prize_pool = game.game_state().await.prize_pool;
+    assert!(prize_pool > 0);
+    let player_before = game.lamports(&player.pubkey()).await;
+    let vault_before = game.lamports(&vault).await;
+
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.reveal(&player, &randomness, value)
+        .await
+        .expect("move is revealed");
+
+    // The whole pool moves from the vault to the winner, the vault keeps its rent reserve
+    assert_eq!(
+        game.lamports(&player.pubkey()).await - player_before,
+        prize_pool
+    );
+    assert_eq!(vault_before - game.lamports(&vault).await, prize_pool);
+    assert_eq!(
+        game.lamports(&vault).await,
+        Rent::default().minimum_balance(0)
+    );
+
+    let game_state = game.game_state().await;
+    assert_eq!(game_state.prize_pool, 0);
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.games_won, 1);
+    assert_eq!(player_state.consecutive_wins, 1);
+}
+
+#[tokio::test]
+async fn get_next_step_price_refuses_other_games() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+    let other_game = GAME_ID + 1;
+    game.process(
+        &[instructions::initialize_game(admin.pubkey(), other_game)],
+        &[&admin],
+    )
+    .await
+    .expect("other game initializes");
+    let player = game.join_player().await;
+
+    let result = game
+        .process(
+            &[instructions::get_next_step_price(
+                player.pubkey(),
+                other_game,
+            )],
+            &[],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::PlayerNotInGame);
+}
+
+#[tokio::test]
+async fn get_next_step_price_returns_the_step_price() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+
+    let result = game
+        .context
+        .banks_client
+        .simulate_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+            &[instructions::get_next_step_price(player.pubkey(), GAME_ID)],
+            Some(&game.context.payer.pubkey()),
+            &[&game.context.payer],
+            game.context.last_blockhash,
+        ))
+        .await
+        .expect("simulation runs");
+
+    assert!(result.result.expect("simulation runs").is_ok());
+    let return_data = result
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .expect("price is returned");
+    assert_eq!(
+        u64::from_le_bytes(return_data.data.try_into().unwrap()),
+        GameConfigParams::default().base_move_cost
+    );
+}
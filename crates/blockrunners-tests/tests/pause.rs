@@ -0,0 +1,194 @@
+use blockrunners::{
+    errors::BlockrunnersError,
+    state::{CardUsage, PauseFlags, SocialFeedEventType},
+};
+use blockrunners_client::{feed::feed_message, instructions};
+use blockrunners_tests::{assert_error, TestGame, GAME_ID};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    signature::{Keypair, Signer},
+};
+
+const ALL: PauseFlags = PauseFlags {
+    purchases: true,
+    commits: true,
+    reveals: true,
+    joins: true,
+};
+
+#[tokio::test]
+async fn stranger_cannot_pause() {
+    let mut game = TestGame::start().await;
+    let stranger = Keypair::new();
+    // Funded, so the feed page can be paid for and only the authority check fails
+    game.airdrop(&stranger.pubkey(), LAMPORTS_PER_SOL).await;
+
+    let result = game
+        .process(
+            &[instructions::set_pause(stranger.pubkey(), GAME_ID, ALL, 0)],
+            &[&stranger],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::Unauthorized);
+}
+
+#[tokio::test]
+async fn pause_is_announced_in_the_game_feed() {
+    let mut game = TestGame::start().await;
+
+    game.set_pause(PauseFlags {
+        purchases: true,
+        ..PauseFlags::default()
+    })
+    .await;
+    assert!(game.game_state().await.paused.purchases);
+    let feed = game.game_feed().await;
+    let event = &feed.last().expect("pause is announced").event;
+    assert_eq!(event.event_type, SocialFeedEventType::SystemLockdown);
+    assert_eq!(
+        feed_message(event),
+        "SYSTEM LOCKDOWN: The Consensus froze purchases. Stand by, runners."
+    );
+
+    game.set_pause(PauseFlags::default()).await;
+    assert!(!game.game_state().await.paused.is_paused());
+    let feed = game.game_feed().await;
+    let event = &feed.last().expect("resume is announced").event;
+    assert_eq!(event.event_type, SocialFeedEventType::SystemLockdown);
+    assert!(feed_message(event).starts_with("SYSTEM RESTORED"));
+}
+
+#[tokio::test]
+async fn paused_actions_are_rejected_until_resumed() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.set_pause(ALL).await;
+    let game_state = game.game_state().await;
+
+    let newcomer = Keypair::new();
+    game.airdrop(&newcomer.pubkey(), LAMPORTS_PER_SOL).await;
+    let result = game
+        .process(
+            &[
+                instructions::initialize_player(newcomer.pubkey()),
+                instructions::join_game(newcomer.pubkey(), GAME_ID, game_state.feed_page),
+            ],
+            &[&newcomer],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::GamePaused);
+
+    let result = game
+        .process(
+            &[instructions::purchase_ciphers(
+                player.pubkey(),
+                game_state.fee_recipient,
+                GAME_ID,
+                10,
+                game_state.feed_page,
+            )],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::GamePaused);
+
+    game.set_pause(PauseFlags::default()).await;
+    game.purchase_ciphers(&player, 10).await;
+    assert_eq!(game.player_state(&player.pubkey()).await.ciphers, 10);
+}
+
+#[tokio::test]
+async fn paused_reveals_also_block_commits() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    game.set_pause(PauseFlags {
+        reveals: true,
+        ..PauseFlags::default()
+    })
+    .await;
+
+    let randomness = Keypair::new().pubkey();
+    let (direction, _) = game.winning_move(&player.pubkey()).await;
+    let result = game
+        .commit(&player, &randomness, direction, CardUsage::default())
+        .await;
+    assert_error(result, BlockrunnersError::GamePaused);
+}
+
+#[tokio::test]
+async fn committed_move_is_refunded_when_cancelled_while_reveals_are_paused() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+    let before = game.player_state(&player.pubkey()).await;
+
+    let (direction, _) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.set_pause(PauseFlags {
+        reveals: true,
+        ..PauseFlags::default()
+    })
+    .await;
+
+    // Cancelling doesn't wait for the move to expire and gives the price back
+    game.process(
+        &[instructions::cancel_move(
+            player.pubkey(),
+            GAME_ID,
+            randomness,
+        )],
+        &[&player],
+    )
+    .await
+    .expect("move is cancelled");
+
+    let after = game.player_state(&player.pubkey()).await;
+    assert_eq!(after.move_direction, None);
+    assert_eq!(after.position, before.position);
+    assert_eq!(after.ciphers, before.ciphers);
+    assert_eq!(after.total_resets, 0);
+}
+
+#[tokio::test]
+async fn revealed_move_is_forfeited_even_while_reveals_are_paused() {
+    let mut game = TestGame::start().await;
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 10).await;
+    let randomness = Keypair::new().pubkey();
+    let before = game.player_state(&player.pubkey()).await;
+
+    let (direction, value) = game.winning_move(&player.pubkey()).await;
+    game.commit(&player, &randomness, direction, CardUsage::default())
+        .await
+        .expect("move is committed");
+    game.set_pause(PauseFlags {
+        reveals: true,
+        ..PauseFlags::default()
+    })
+    .await;
+
+    // The randomness is revealed, but the move can't be settled
+    let result = game.reveal(&player, &randomness, value).await;
+    assert_error(result, BlockrunnersError::GamePaused);
+
+    // The outcome is public, so cancelling counts as a wrong step
+    game.process(
+        &[instructions::cancel_move(
+            player.pubkey(),
+            GAME_ID,
+            randomness,
+        )],
+        &[&player],
+    )
+    .await
+    .expect("move is cancelled");
+
+    let after = game.player_state(&player.pubkey()).await;
+    assert_eq!(after.move_direction, None);
+    assert!(after.ciphers < before.ciphers);
+    assert_eq!(after.total_resets, 1);
+}
@@ -0,0 +1,207 @@
+use blockrunners::{
+    errors::BlockrunnersError,
+    state::{CardUsage, GameConfigParams, PathDirection, SocialFeedEventType},
+};
+use blockrunners_client::{feed::feed_message, instructions, pda};
+use blockrunners_tests::{assert_error, TestGame, GAME_ID};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Start a game with a funded reserve and a player holding 100 ciphers
+async fn game_with_reserve(reserve: u64) -> (TestGame, Keypair) {
+    let mut game = TestGame::start().await;
+    if reserve > 0 {
+        let admin = game.admin.insecure_clone();
+        game.process(
+            &[instructions::fund_reserve(admin.pubkey(), GAME_ID, reserve)],
+            &[&admin],
+        )
+        .await
+        .expect("reserve is funded");
+    }
+    let player = game.join_player().await;
+    game.purchase_ciphers(&player, 100).await;
+
+    (game, player)
+}
+
+#[tokio::test]
+async fn ciphers_are_redeemed_from_the_reserve() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+    let reserve = pda::reserve_vault(GAME_ID);
+    let vault = pda::prize_vault(GAME_ID);
+    let player_before = game.lamports(&player.pubkey()).await;
+    let reserve_before = game.lamports(&reserve).await;
+    let vault_before = game.lamports(&vault).await;
+    let prize_pool_before = game.game_state().await.prize_pool;
+
+    game.process(
+        &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 40)],
+        &[&player],
+    )
+    .await
+    .expect("ciphers are redeemed");
+
+    let params = GameConfigParams::default();
+    let payout = 40 * params.cipher_cost * params.redeem_percentage as u64 / 100;
+    assert_eq!(
+        game.lamports(&player.pubkey()).await - player_before,
+        payout
+    );
+    assert_eq!(reserve_before - game.lamports(&reserve).await, payout);
+
+    // The prize pool isn't touched
+    assert_eq!(game.lamports(&vault).await, vault_before);
+    assert_eq!(game.game_state().await.prize_pool, prize_pool_before);
+
+    let player_state = game.player_state(&player.pubkey()).await;
+    assert_eq!(player_state.ciphers, 60);
+    let event = game
+        .player_feed(&player.pubkey())
+        .await
+        .last()
+        .expect("redemption is recorded");
+    assert_eq!(event.event_type, SocialFeedEventType::CiphersRedeemed);
+    assert_eq!(
+        feed_message(&event),
+        "CIPHERS LIQUIDATED: 40 ciphers redeemed for 4000 lamports. Your current reserves: 60"
+    );
+}
+
+#[tokio::test]
+async fn buying_and_redeeming_pays_back_less_than_the_house_earned() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+    let fee_recipient = game.game_state().await.fee_recipient;
+    let reserve = pda::reserve_vault(GAME_ID);
+    let house_before = game.lamports(&fee_recipient).await + game.lamports(&reserve).await;
+
+    game.purchase_ciphers(&player, 100).await;
+    game.process(
+        &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 100)],
+        &[&player],
+    )
+    .await
+    .expect("ciphers are redeemed");
+
+    let house_after = game.lamports(&fee_recipient).await + game.lamports(&reserve).await;
+    assert!(house_after >= house_before);
+}
+
+#[tokio::test]
+async fn ciphers_are_redeemed_at_the_price_they_were_bought_at() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+    let cipher_cost = GameConfigParams::default().cipher_cost;
+    game.update_config(GameConfigParams {
+        cipher_cost: 10 * cipher_cost,
+        ..GameConfigParams::default()
+    })
+    .await;
+
+    // 100 ciphers at the old cost and 100 at the new one
+    game.purchase_ciphers(&player, 100).await;
+    assert_eq!(
+        game.player_state(&player.pubkey()).await.cipher_price,
+        11 * cipher_cost / 2
+    );
+
+    let player_before = game.lamports(&player.pubkey()).await;
+    game.process(
+        &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 200)],
+        &[&player],
+    )
+    .await
+    .expect("ciphers are redeemed");
+
+    // 10% of what was paid, not of what the ciphers cost now
+    let paid = 100 * cipher_cost + 100 * 10 * cipher_cost;
+    assert_eq!(
+        game.lamports(&player.pubkey()).await - player_before,
+        paid / 10
+    );
+}
+
+#[tokio::test]
+async fn redemption_must_be_covered_by_the_reserve() {
+    let (mut game, player) = game_with_reserve(0).await;
+
+    let result = game
+        .process(
+            &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 10)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InsufficientReserve);
+}
+
+#[tokio::test]
+async fn cannot_redeem_more_ciphers_than_owned() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+
+    let result = game
+        .process(
+            &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 101)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::NotEnoughCiphers);
+}
+
+#[tokio::test]
+async fn cannot_redeem_with_a_committed_move() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+    let randomness = Keypair::new().pubkey();
+    game.commit(
+        &player,
+        &randomness,
+        PathDirection::Left,
+        CardUsage::default(),
+    )
+    .await
+    .expect("move is committed");
+
+    let result = game
+        .process(
+            &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 10)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::MovePending);
+}
+
+#[tokio::test]
+async fn redemption_can_be_turned_off() {
+    let (mut game, player) = game_with_reserve(1_000_000).await;
+    game.update_config(GameConfigParams {
+        redeem_percentage: 0,
+        ..GameConfigParams::default()
+    })
+    .await;
+
+    let result = game
+        .process(
+            &[instructions::redeem_ciphers(player.pubkey(), GAME_ID, 10)],
+            &[&player],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::RedemptionDisabled);
+}
+
+#[tokio::test]
+async fn redeem_percentage_above_the_admin_share_is_rejected() {
+    let mut game = TestGame::start().await;
+    let admin = game.admin.insecure_clone();
+
+    let result = game
+        .process(
+            &[instructions::update_config(
+                admin.pubkey(),
+                GAME_ID,
+                GameConfigParams {
+                    redeem_percentage: 13,
+                    ..GameConfigParams::default()
+                },
+            )],
+            &[&admin],
+        )
+        .await;
+    assert_error(result, BlockrunnersError::InvalidRedeemPercentage);
+}
@@ -12,18 +12,72 @@ pub const INITIAL_PRIZE_POOL: u64 = 0;
 #[constant]
 pub const INITIAL_PATH_LENGTH: u8 = 10;
 
+/// Bounds of the path length when it changes between games
+#[constant]
+pub const MIN_PATH_LENGTH: u8 = 5;
+
+#[constant]
+pub const MAX_PATH_LENGTH: u8 = 50;
+
 #[constant]
 pub const GAME_STATE_SEED: &[u8] = b"game_state";
 
+#[constant]
+pub const GAME_CONFIG_SEED: &[u8] = b"game_config";
+
+#[constant]
+pub const PRIZE_VAULT_SEED: &[u8] = b"prize_vault";
+
+/// Lamports paying for refunds of unspent ciphers, never part of the prize pool
+#[constant]
+pub const RESERVE_VAULT_SEED: &[u8] = b"reserve_vault";
+
 #[constant]
 pub const MAX_FEED_EVENTS: u8 = 20;
 
+/// History of the game feed, one account per page
+#[constant]
+pub const FEED_PAGE_SEED: &[u8] = b"feed_page";
+
+/// Number of events after which the game feed moves on to the next page
+#[constant]
+pub const FEED_PAGE_EVENTS: u8 = 16;
+
+/// Room on a page for the rest of the events of the instruction that fills it, a winning move
+/// announces up to four
+pub const MAX_FEED_PAGE_EVENTS: u8 = FEED_PAGE_EVENTS + 3;
+
+/// Layout version of the social feed events. Versions count down from 0xFF whenever the layout
+/// changes, out of the range of the event types legacy events start with.
+#[constant]
+pub const FEED_EVENT_VERSION: u8 = 0xFF;
+
+/// Bytes of a player feed slot, enough for the largest encoded feed entry
+pub const FEED_SLOT_SIZE: usize = 64;
+
+/// Most cards collected in a single step, two with a Doubler
+pub const MAX_CARDS_PER_STEP: u8 = 2;
+
 #[constant]
 pub const MAX_RANDOMNESS_VALUES: u8 = 32;
 
 #[constant]
 pub const PLAYER_STATE_SEED: &[u8] = b"player_state";
 
+/// Feed of the events that only concern the player
+#[constant]
+pub const PLAYER_FEED_SEED: &[u8] = b"player_feed";
+
+/// Lifetime statistics of closed player accounts
+#[constant]
+pub const PLAYER_ARCHIVE_SEED: &[u8] = b"player_archive";
+
+/// Test-only queue of randomness values
+#[constant]
+pub const SCRIPTED_RANDOMNESS_SEED: &[u8] = b"scripted_randomness";
+
+pub const MAX_SCRIPTED_RANDOMNESS: usize = 16;
+
 /// Move success probability as a percentage (out of 100)
 #[constant]
 pub const MOVE_SUCCESS_PROBABILITY: u8 = 75;
@@ -31,3 +85,27 @@ pub const MOVE_SUCCESS_PROBABILITY: u8 = 75;
 /// Revenue distribution percentages
 #[constant]
 pub const PRIZE_POOL_PERCENTAGE: u8 = 88;
+
+#[constant]
+pub const ADMIN_PERCENTAGE: u8 = 12;
+
+/// Percentage of the cipher cost paid back when redeeming ciphers, at most the admin percentage
+#[constant]
+pub const REDEEM_PERCENTAGE: u8 = 10;
+
+/// Move costs in ciphers
+#[constant]
+pub const BASE_MOVE_COST: u64 = 1;
+
+#[constant]
+pub const CARD_COST: u64 = 1;
+
+#[constant]
+pub const SWIFT_DISCOUNT: u64 = 2;
+
+/// Number of slots after which a pending move can no longer be revealed and can be cancelled
+#[constant]
+pub const MOVE_TIMEOUT_SLOTS: u64 = 150;
+
+// Sizes of the rules' tables stored in the game config
+pub use blockrunners_rules::{MAX_STEP_PRICE_TIERS, STARTING_HAND_TIERS};
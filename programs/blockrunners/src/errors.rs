@@ -76,4 +76,125 @@ pub enum BlockrunnersError {
 
     #[msg("Player has already joined the game")]
     PlayerAlreadyJoinedGame,
+
+    #[msg("Cipher cost must be greater than zero")]
+    InvalidCipherCost,
+
+    #[msg("Path length must be greater than zero and within the min and max path length")]
+    InvalidPathLength,
+
+    #[msg("Move success probability must be between 1 and 99")]
+    InvalidMoveSuccessProbability,
+
+    #[msg("Revenue percentages must sum up to 100")]
+    InvalidRevenueSplit,
+
+    #[msg("Feed capacity is out of range")]
+    InvalidFeedCapacity,
+
+    #[msg("Prize vault balance doesn't cover the prize pool")]
+    PrizeVaultMismatch,
+
+    #[msg("Step price curve is invalid")]
+    InvalidStepPriceCurve,
+
+    #[msg("Path length rule is invalid")]
+    InvalidPathLengthRule,
+
+    #[msg("Starting hand tiers must start at zero and be in ascending order")]
+    InvalidStartingHandTiers,
+
+    #[msg("Move timeout must be greater than zero")]
+    InvalidMoveTimeout,
+
+    #[msg("Move commitment has expired, cancel it to make a new move")]
+    MoveCommitExpired,
+
+    #[msg("Move commitment can only be cancelled after it expires")]
+    MoveCommitNotExpired,
+
+    #[msg("Randomness account is not owned by the Switchboard program")]
+    InvalidRandomnessAccountOwner,
+
+    #[msg("Randomness account has an invalid size")]
+    InvalidRandomnessAccountSize,
+
+    #[msg("Randomness has already been revealed")]
+    RandomnessAlreadyRevealed,
+
+    #[msg("Randomness account doesn't match the game's randomness provider")]
+    InvalidRandomnessAccount,
+
+    #[msg("Slot hash is no longer available")]
+    SlotHashUnavailable,
+
+    #[msg("Local oracle has no pending commitment")]
+    OracleCommitmentMissing,
+
+    #[msg("Local oracle already has a pending commitment")]
+    OracleCommitmentPending,
+
+    #[msg("Secret doesn't match the local oracle commitment")]
+    InvalidOracleSecret,
+
+    #[msg("Scripted randomness queue is full")]
+    ScriptedRandomnessFull,
+
+    #[msg("Signer is not the proposed authority")]
+    NotPendingAuthority,
+
+    #[msg("This action is paused in the game")]
+    GamePaused,
+
+    #[msg("Cipher refund percentage can't exceed the admin percentage")]
+    InvalidCipherCarryOver,
+
+    #[msg("Unspent ciphers belong to another game")]
+    UnspentCiphers,
+
+    #[msg("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[msg("Cipher redeem percentage can't exceed the admin percentage")]
+    InvalidRedeemPercentage,
+
+    #[msg("Cipher redemption is turned off in this game")]
+    RedemptionDisabled,
+
+    #[msg("Reserve vault can't cover the redemption")]
+    InsufficientReserve,
+
+    #[msg("Committed move must be revealed or cancelled first")]
+    MovePending,
+
+    #[msg("Unspent ciphers must be redeemed or explicitly forfeited first")]
+    UnspentCiphersRemaining,
+
+    #[msg("The game still writes to this feed page")]
+    FeedPageInUse,
+
+    #[msg("Fee recipient must be a rent-exempt system account")]
+    FeeRecipientNotRentExempt,
+
+    #[msg("The game already moved on from this feed page")]
+    FeedPageOutdated,
+
+    #[msg("Player hasn't joined this game")]
+    PlayerNotInGame,
+}
+
+impl From<blockrunners_rules::RulesError> for BlockrunnersError {
+    fn from(error: blockrunners_rules::RulesError) -> Self {
+        match error {
+            blockrunners_rules::RulesError::InsufficientBalance => {
+                BlockrunnersError::InsufficientBalance
+            }
+            blockrunners_rules::RulesError::InsufficientCards => {
+                BlockrunnersError::InsufficientCards
+            }
+            blockrunners_rules::RulesError::RandomnessFinished => {
+                BlockrunnersError::RandomnessFinished
+            }
+        }
+    }
 }
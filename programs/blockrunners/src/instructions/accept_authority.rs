@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::GAME_STATE_SEED,
+    errors::BlockrunnersError,
+    state::{AuthorityChanged, GameState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct AcceptAuthority<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.pending_authority == Some(new_authority.key())
+            @ BlockrunnersError::NotPendingAuthority
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>, game_id: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let clock = Clock::get()?;
+
+    let old_authority = game_state.authority;
+    game_state.authority = ctx.accounts.new_authority.key();
+    game_state.pending_authority = None;
+
+    emit!(AuthorityChanged {
+        game_id,
+        old_authority,
+        new_authority: game_state.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Game {} authority transferred", game_id);
+    Ok(())
+}
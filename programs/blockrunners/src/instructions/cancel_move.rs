@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED},
+    errors::BlockrunnersError,
+    instructions::update_last_login,
+    state::{FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
+    utils::{randomness_is_revealed, save_and_emit_event, save_player_rule_events},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct CancelMove<'info> {
+    pub player: Signer<'info>,
+
+    #[account(mut,
+        seeds = [PLAYER_STATE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(mut,
+        seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    #[account(
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// CHECK: This account is validated in the instruction handler
+    #[account()]
+    pub randomness_account: AccountInfo<'info>,
+}
+
+pub fn cancel_move(ctx: Context<CancelMove>, _game_id: u64) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+    let game_config = &ctx.accounts.game_config;
+    let player_state = &mut ctx.accounts.player_state;
+    let player_feed = &mut ctx.accounts.player_feed.load_mut()?;
+    let randomness_account = &ctx.accounts.randomness_account;
+
+    update_last_login(player_state)?;
+
+    // The commitment was made in this game, so its timeout comes from this game's config
+    require!(
+        player_state.game_id == Some(game_state.game_id),
+        BlockrunnersError::PlayingInDifferentGame
+    );
+
+    let used_cards = player_state
+        .move_cards
+        .ok_or(BlockrunnersError::MoveNotCommitted)?;
+    require!(
+        player_state.move_direction.is_some(),
+        BlockrunnersError::MoveNotCommitted
+    );
+
+    // Only expired commitments can be cancelled, otherwise the player could cancel any move
+    // they don't like before revealing it. The move was paid on commit and its price isn't
+    // refunded: the player may know the outcome from the oracle without posting it. While
+    // reveals are paused the move can't be settled, so it can be cancelled right away and is
+    // refunded, and no new moves can be committed to pick from. A revealed move is forfeited
+    // either way, its outcome is already public.
+    let reveals_paused = game_state.paused.reveals;
+    let clock = Clock::get()?;
+    require!(
+        reveals_paused
+            || player_state.is_move_expired(clock.slot, game_config.params.move_timeout_slots),
+        BlockrunnersError::MoveCommitNotExpired
+    );
+
+    let revealed = randomness_is_revealed(player_state, game_state, randomness_account)?;
+    if revealed && player_state.is_in_game(game_state) {
+        // The outcome is already public, so a cancelled move counts as a failed one
+        msg!("Randomness already revealed, forfeiting the move");
+        let mut runner = player_state.runner();
+        let mut events = Vec::new();
+        game_config.params.rules().fail_move(
+            &mut runner,
+            &game_state.game_view(),
+            used_cards,
+            &mut events,
+        );
+        player_state.apply_runner(&runner);
+
+        // A failed step only concerns the player's own feed
+        save_player_rule_events(player_feed, game_config.params.max_feed_events, events)?;
+    } else if reveals_paused && !revealed {
+        player_state.refund_move();
+        let private_payload = FeedPayload::MoveCancelledInLockdown {
+            position: player_state.position,
+        };
+        save_and_emit_event(
+            player_feed,
+            game_config.params.max_feed_events,
+            private_payload,
+        )?;
+    } else {
+        let private_payload = FeedPayload::MoveTimedOut {
+            position: player_state.position,
+        };
+        save_and_emit_event(
+            player_feed,
+            game_config.params.max_feed_events,
+            private_payload,
+        )?;
+    }
+
+    player_state.clear_move_commitment();
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEED_PAGE_SEED, GAME_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{FeedPage, GameState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64, page_index: u64)]
+pub struct CloseFeedPage<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(mut,
+        close = payer,
+        seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &page_index.to_le_bytes()],
+        bump = feed_page.bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    /// Gets back the rent it paid for the page
+    #[account(mut, address = feed_page.payer)]
+    pub payer: SystemAccount<'info>,
+}
+
+pub fn close_feed_page(ctx: Context<CloseFeedPage>, game_id: u64, page_index: u64) -> Result<()> {
+    // The game still writes to its current page
+    require!(
+        page_index < ctx.accounts.game_state.feed_page,
+        BlockrunnersError::FeedPageInUse
+    );
+
+    msg!("Feed page {} of game {} closed", page_index, game_id);
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, PLAYER_ARCHIVE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{PlayerArchive, PlayerFeed, PlayerState},
+};
+
+#[derive(Accounts)]
+pub struct ClosePlayer<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(mut,
+        close = player,
+        seeds = [PLAYER_STATE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(mut,
+        close = player,
+        seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    /// Pass the archive to keep the lifetime statistics, leave it out to drop them
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = usize::from(DISCRIMINATOR_SIZE) + PlayerArchive::INIT_SPACE,
+        seeds = [PLAYER_ARCHIVE_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_archive: Option<Account<'info, PlayerArchive>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_player(ctx: Context<ClosePlayer>, forfeit_ciphers: bool) -> Result<()> {
+    let player_state = &ctx.accounts.player_state;
+
+    // A committed move still has to be paid for or cancelled
+    require!(
+        player_state.move_direction.is_none(),
+        BlockrunnersError::MovePending
+    );
+
+    // Ciphers were paid for, so they're only given up on purpose
+    require!(
+        player_state.ciphers == 0 || forfeit_ciphers,
+        BlockrunnersError::UnspentCiphersRemaining
+    );
+    if player_state.ciphers > 0 {
+        msg!("Forfeiting {} unspent ciphers", player_state.ciphers);
+    }
+
+    if let Some(player_archive) = ctx.accounts.player_archive.as_mut() {
+        player_archive.player = ctx.accounts.player.key();
+        player_archive.bump = ctx.bumps.player_archive.unwrap_or_default();
+        player_archive.record(player_state);
+        msg!(
+            "Player statistics archived, {} accounts closed",
+            player_archive.accounts_closed
+        );
+    }
+
+    msg!("Player closed");
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, MAX_SCRIPTED_RANDOMNESS, SCRIPTED_RANDOMNESS_SEED},
+    errors::BlockrunnersError,
+    state::ScriptedRandomness,
+};
+
+#[derive(Accounts)]
+pub struct DebugScriptRandomness<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = usize::from(DISCRIMINATOR_SIZE) + ScriptedRandomness::INIT_SPACE,
+        seeds = [SCRIPTED_RANDOMNESS_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub scripted_randomness: Account<'info, ScriptedRandomness>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn debug_script_randomness(
+    ctx: Context<DebugScriptRandomness>,
+    values: Vec<[u8; 32]>,
+) -> Result<()> {
+    let scripted_randomness = &mut ctx.accounts.scripted_randomness;
+    require!(
+        scripted_randomness.values.len() + values.len() <= MAX_SCRIPTED_RANDOMNESS,
+        BlockrunnersError::ScriptedRandomnessFull
+    );
+
+    scripted_randomness.values.extend(values);
+    scripted_randomness.bump = ctx.bumps.scripted_randomness;
+    msg!(
+        "Queued randomness, {} values pending",
+        scripted_randomness.values.len()
+    );
+
+    Ok(())
+}
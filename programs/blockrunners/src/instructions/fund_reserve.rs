@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{GAME_STATE_SEED, RESERVE_VAULT_SEED},
+    errors::BlockrunnersError,
+    state::GameState,
+    utils::{reserve_available, transfer},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct FundReserve<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(mut,
+        seeds = [RESERVE_VAULT_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub reserve_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_reserve(ctx: Context<FundReserve>, game_id: u64, amount: u64) -> Result<()> {
+    require!(amount > 0, BlockrunnersError::ZeroAmount);
+
+    // Typically the fee recipient tops up the reserve from the admin share
+    transfer(
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.funder.to_account_info(),
+        ctx.accounts.reserve_vault.to_account_info(),
+        amount,
+        None,
+    )?;
+
+    msg!(
+        "Game {} reserve funded with {} lamports, {} available",
+        game_id,
+        amount,
+        reserve_available(&ctx.accounts.reserve_vault)?
+    );
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{GAME_CONFIG_SEED, PLAYER_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{GameConfig, PlayerState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct GetNextStepPrice<'info> {
+    /// CHECK: Only used to derive the player state PDA, this is a read-only view
+    pub player: UncheckedAccount<'info>,
+
+    /// The price depends on the position, which only means something in the player's game
+    #[account(
+      seeds = [PLAYER_STATE_SEED, player.key().as_ref()],
+      bump,
+      constraint = player_state.game_id == Some(game_id) @ BlockrunnersError::PlayerNotInGame
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
+
+/// View returning the base price in ciphers of the player's next step, before card effects
+pub fn get_next_step_price(ctx: Context<GetNextStepPrice>, _game_id: u64) -> Result<u64> {
+    let price = ctx
+        .accounts
+        .game_config
+        .step_price(ctx.accounts.player_state.position);
+
+    Ok(price)
+}
@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DISCRIMINATOR_SIZE, GAME_STATE_SEED, INITIAL_PATH_LENGTH, INITIAL_PRIZE_POOL},
-    state::GameState,
+    constants::{
+        DISCRIMINATOR_SIZE, FEED_PAGE_SEED, GAME_CONFIG_SEED, GAME_STATE_SEED, INITIAL_PRIZE_POOL,
+        PRIZE_VAULT_SEED, RESERVE_VAULT_SEED,
+    },
+    state::{FeedPage, GameConfig, GameConfigParams, GameState, PauseFlags},
+    utils::{generate_path_seed, transfer},
 };
 
 #[derive(Accounts)]
+#[instruction(game_id: u64)]
 pub struct InitializeGame<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
@@ -14,25 +19,97 @@ pub struct InitializeGame<'info> {
         init,
         payer = admin,
         space = usize::from(DISCRIMINATOR_SIZE) + GameState::INIT_SPACE,
-        seeds = [GAME_STATE_SEED],
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
         bump
     )]
     pub game_state: Account<'info, GameState>,
 
+    #[account(
+        init,
+        payer = admin,
+        space = usize::from(DISCRIMINATOR_SIZE) + GameConfig::INIT_SPACE,
+        seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// First page of the game feed, later ones are opened with `open_feed_page`
+    #[account(
+        init,
+        payer = admin,
+        space = usize::from(DISCRIMINATOR_SIZE) + FeedPage::INIT_SPACE,
+        seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &0u64.to_le_bytes()],
+        bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    #[account(mut,
+        seeds = [PRIZE_VAULT_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub prize_vault: SystemAccount<'info>,
+
+    #[account(mut,
+        seeds = [RESERVE_VAULT_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub reserve_vault: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_game(ctx: Context<InitializeGame>) -> Result<()> {
+pub fn initialize_game(ctx: Context<InitializeGame>, game_id: u64) -> Result<()> {
     let game_state = &mut ctx.accounts.game_state;
+    let game_config = &mut ctx.accounts.game_config;
 
     let clock = Clock::get()?;
 
+    // Start with the default parameters, they can be changed later with `update_config`
+    game_config.set_params(GameConfigParams::default())?;
+    game_config.bump = ctx.bumps.game_config;
+
+    game_state.game_id = game_id;
     game_state.authority = ctx.accounts.admin.key();
+    game_state.pending_authority = None;
+    game_state.fee_recipient = ctx.accounts.admin.key();
+    game_state.paused = PauseFlags::default();
     game_state.prize_pool = INITIAL_PRIZE_POOL;
-    game_state.path_length = INITIAL_PATH_LENGTH;
+    game_state.prize_vault_bump = ctx.bumps.prize_vault;
+    game_state.reserve_vault_bump = ctx.bumps.reserve_vault;
+    game_state.path_length = game_config.params.path_length;
     game_state.start = clock.unix_timestamp;
-    game_state.game_events = Vec::new();
+    game_state.path_seed = generate_path_seed(
+        &[0; 32],
+        game_id,
+        game_state.start,
+        ctx.accounts.admin.key().as_ref(),
+    )?;
+    game_state.feed_sequence = 0;
+    game_state.feed_page = 0;
+    game_state.oracle_commitment = None;
+    game_state.oracle_reveal = None;
+
+    ctx.accounts
+        .feed_page
+        .open(game_state, 0, ctx.bumps.feed_page, ctx.accounts.admin.key());
+
+    // Fund the vaults with their rent reserve, so they only ever pay out what they hold for
+    // the game
+    for vault in [&ctx.accounts.prize_vault, &ctx.accounts.reserve_vault] {
+        let rent_reserve = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(vault.lamports());
+        if rent_reserve > 0 {
+            transfer(
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.admin.to_account_info(),
+                vault.to_account_info(),
+                rent_reserve,
+                None,
+            )?;
+        }
+    }
 
-    msg!("Game initialized by admin");
+    msg!("Game {} initialized by admin", game_id);
     Ok(())
 }
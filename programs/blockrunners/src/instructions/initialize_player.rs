@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{DISCRIMINATOR_SIZE, GAME_STATE_SEED, PLAYER_STATE_SEED},
+    constants::{DISCRIMINATOR_SIZE, PLAYER_FEED_SEED, PLAYER_STATE_SEED},
     instructions::update_last_login,
-    state::{CardCounts, GameState, PlayerState},
+    state::{CardCounts, PlayerFeed, PlayerState},
 };
 
 #[derive(Accounts)]
@@ -21,10 +21,13 @@ pub struct InitializePlayer<'info> {
     pub player_state: Account<'info, PlayerState>,
 
     #[account(
-        seeds = [GAME_STATE_SEED],
+        init,
+        payer = player,
+        space = usize::from(DISCRIMINATOR_SIZE) + std::mem::size_of::<PlayerFeed>(),
+        seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
         bump
     )]
-    pub game_state: Account<'info, GameState>,
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
 
     pub system_program: Program<'info, System>,
 }
@@ -35,10 +38,10 @@ pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
 
     // Initialize player state with default values
     player_state.ciphers = 0;
+    player_state.cipher_price = 0;
     player_state.cards = CardCounts::default();
     player_state.position = 0;
     player_state.bump = ctx.bumps.player_state;
-    player_state.player_events = Vec::new();
 
     // Initialize player statistics
     player_state.first_login = clock.unix_timestamp;
@@ -46,13 +49,22 @@ pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
     player_state.total_ciphers_bought = 0;
     update_last_login(player_state)?;
 
+    player_state.game_id = None;
     player_state.game_start = None;
 
     player_state.randomness_account = None;
     player_state.randomness_slot = None;
+    player_state.randomness_provider = None;
+    player_state.randomness_commitment = None;
     player_state.randomness_value = None;
     player_state.move_direction = None;
     player_state.move_cards = None;
+    player_state.move_cost = 0;
+
+    // The feed starts out zeroed, so empty
+    let mut player_feed = ctx.accounts.player_feed.load_init()?;
+    player_feed.player = ctx.accounts.player.key();
+    player_feed.bump = ctx.bumps.player_feed;
 
     msg!("Player initialized");
 
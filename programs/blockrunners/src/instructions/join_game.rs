@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{GAME_STATE_SEED, PLAYER_STATE_SEED},
+    constants::{
+        FEED_PAGE_SEED, GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED,
+        RESERVE_VAULT_SEED,
+    },
     errors::BlockrunnersError,
-    state::{CardCounts, GameState, PlayerState, SocialFeedEventType},
-    utils::save_and_emit_event,
+    state::{FeedPage, FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
+    utils::{deal_starting_hand, save_and_emit_game_event, settle_unspent_ciphers},
 };
 
 #[derive(Accounts)]
+#[instruction(game_id: u64)]
 pub struct JoinGame<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -19,41 +23,92 @@ pub struct JoinGame<'info> {
     pub player_state: Account<'info, PlayerState>,
 
     #[account(mut,
-      seeds = [GAME_STATE_SEED],
+      seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+      bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    #[account(mut,
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
       bump
     )]
     pub game_state: Account<'info, GameState>,
+
+    /// Current page of the game feed, opened in advance by the game authority
+    #[account(mut,
+      seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &game_state.feed_page.to_le_bytes()],
+      bump = feed_page.bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Pays refunds of unspent ciphers
+    #[account(mut,
+      seeds = [RESERVE_VAULT_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub reserve_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
-pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
+pub fn join_game(ctx: Context<JoinGame>, game_id: u64) -> Result<()> {
     let player_state = &mut ctx.accounts.player_state;
+    let player_feed = &mut ctx.accounts.player_feed.load_mut()?;
     let game_state = &mut ctx.accounts.game_state;
+    let feed_page = &mut ctx.accounts.feed_page;
+    let game_config = &ctx.accounts.game_config;
+
+    require!(!game_state.paused.joins, BlockrunnersError::GamePaused);
 
     // Don't allow to re-start the current game
     require!(
-        player_state.game_start != Some(game_state.start),
+        !player_state.is_in_game(game_state),
         BlockrunnersError::PlayerAlreadyJoinedGame
     );
 
-    let player_key = ctx.accounts.player.key();
+    // Ciphers are bought at the price of one game, so they're only settled by the next instance
+    // of the same game. Anywhere else they have to be redeemed or explicitly forfeited first
+    require!(
+        player_state.ciphers == 0 || player_state.game_id == Some(game_id),
+        BlockrunnersError::UnspentCiphers
+    );
+    settle_unspent_ciphers(
+        player_state,
+        player_feed,
+        game_state,
+        game_config,
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.reserve_vault.to_account_info(),
+        ctx.accounts.player.to_account_info(),
+    )?;
 
     // Announce to game feed
-    let global_message = format!(
-        "Player {}...{} joining the game!",
-        &player_key.to_string()[0..2],
-        &player_key.to_string()[player_key.to_string().len() - 4..],
-    );
-    save_and_emit_event(
-        &mut game_state.game_events,
-        SocialFeedEventType::PlayerJoined,
-        global_message,
+    save_and_emit_game_event(
+        game_state,
+        feed_page,
+        FeedPayload::PlayerJoined {
+            player: ctx.accounts.player.key(),
+        },
     )?;
 
+    player_state.game_id = Some(game_id);
     player_state.game_start = Some(game_state.start);
-    player_state.cards = CardCounts::default();
-    player_state.ciphers = 0;
     player_state.position = 0;
 
-    msg!("Player joined the game");
+    // Deal the starting hand based on the prize pool size
+    deal_starting_hand(
+        player_state,
+        player_feed,
+        game_config,
+        game_state.prize_pool,
+    )?;
+
+    msg!("Player joined game {}", game_id);
     Ok(())
 }
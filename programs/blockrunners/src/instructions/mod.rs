@@ -1,19 +1,51 @@
 #![allow(unused_imports)]
 
+pub mod accept_authority;
+pub mod cancel_move;
+pub mod close_feed_page;
+pub mod close_player;
 pub mod debug_give_card;
+#[cfg(feature = "test")]
+pub mod debug_script_randomness;
+pub mod fund_reserve;
+pub mod get_next_step_price;
 pub mod initialize_game;
 pub mod initialize_player;
 pub mod join_game;
 pub mod move_commit;
 pub mod move_reveal;
+pub mod open_feed_page;
+pub mod oracle_commit;
+pub mod oracle_reveal;
+pub mod propose_authority;
 pub mod purchase_ciphers;
+pub mod redeem_ciphers;
+pub mod set_fee_recipient;
+pub mod set_pause;
+pub mod update_config;
 pub mod update_last_login;
 
+pub use accept_authority::*;
+pub use cancel_move::*;
+pub use close_feed_page::*;
+pub use close_player::*;
 pub use debug_give_card::*;
+#[cfg(feature = "test")]
+pub use debug_script_randomness::*;
+pub use fund_reserve::*;
+pub use get_next_step_price::*;
 pub use initialize_game::*;
 pub use initialize_player::*;
 pub use join_game::*;
 pub use move_commit::*;
 pub use move_reveal::*;
+pub use open_feed_page::*;
+pub use oracle_commit::*;
+pub use oracle_reveal::*;
+pub use propose_authority::*;
 pub use purchase_ciphers::*;
+pub use redeem_ciphers::*;
+pub use set_fee_recipient::*;
+pub use set_pause::*;
+pub use update_config::*;
 pub use update_last_login::*;
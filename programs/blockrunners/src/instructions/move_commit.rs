@@ -1,14 +1,15 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{GAME_STATE_SEED, PLAYER_STATE_SEED},
+    constants::{GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_STATE_SEED},
     errors::BlockrunnersError,
     instructions::update_last_login,
-    state::{CardUsage, GameState, PathDirection, PlayerState},
-    utils::{get_move_cost, randomness_request},
+    state::{CardUsage, GameConfig, GameState, PathDirection, PlayerState},
+    utils::{get_move_cost, pay_for_move, randomness_request},
 };
 
 #[derive(Accounts)]
+#[instruction(game_id: u64)]
 pub struct MoveCommit<'info> {
     pub player: Signer<'info>,
 
@@ -19,11 +20,17 @@ pub struct MoveCommit<'info> {
     pub player_state: Account<'info, PlayerState>,
 
     #[account(
-      seeds = [GAME_STATE_SEED],
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
       bump
     )]
     pub game_state: Account<'info, GameState>,
 
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
     /// CHECK: This account is validated in the instruction handler
     #[account()]
     pub randomness_account: AccountInfo<'info>,
@@ -31,21 +38,35 @@ pub struct MoveCommit<'info> {
 
 pub fn move_commit(
     ctx: Context<MoveCommit>,
+    _game_id: u64,
     direction: PathDirection,
     card_usage: CardUsage,
 ) -> Result<()> {
     let game_state = &ctx.accounts.game_state;
+    let game_config = &ctx.accounts.game_config;
     let player_state = &mut ctx.accounts.player_state;
     let randomness_account = &ctx.accounts.randomness_account;
 
+    require!(
+        !game_state.paused.blocks_commits(),
+        BlockrunnersError::GamePaused
+    );
+
     update_last_login(player_state)?;
 
     // Check if player is part of the current game
     require!(
-        player_state.game_start == Some(game_state.start),
+        player_state.is_in_game(game_state),
         BlockrunnersError::PlayingInDifferentGame
     );
 
+    // A committed move is revealed or cancelled before the next one, so its outcome can't be
+    // replaced once the randomness is known
+    require!(
+        player_state.move_direction.is_none(),
+        BlockrunnersError::MovePending
+    );
+
     // Check if player has already completed the path
     require!(
         player_state.position < game_state.path_length,
@@ -53,18 +74,23 @@ pub fn move_commit(
     );
 
     // Check if player has enough ciphers to pay for the move
-    let total_cost = get_move_cost(player_state, &card_usage)?;
+    let total_cost = get_move_cost(game_config, player_state, &card_usage)?;
     require!(
         player_state.ciphers >= total_cost,
         BlockrunnersError::InsufficientBalance
     );
 
+    // The move is paid before its randomness exists, so a move the player doesn't reveal, or
+    // lets expire, still costs its price
+    pay_for_move(player_state, game_config, &card_usage)?;
+
     // Save commitment
     player_state.move_direction = Some(direction);
     player_state.move_cards = Some(card_usage);
+    player_state.move_cost = total_cost;
 
     // Request randomness
-    randomness_request(player_state, randomness_account)?;
+    randomness_request(player_state, game_state, game_config, randomness_account)?;
 
     Ok(())
 }
@@ -1,16 +1,22 @@
 use anchor_lang::prelude::*;
+use blockrunners_rules::MoveOutcome;
 
 use crate::{
-    constants::{GAME_STATE_SEED, MOVE_SUCCESS_PROBABILITY, PLAYER_STATE_SEED},
+    constants::{
+        FEED_PAGE_SEED, GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED,
+        PRIZE_VAULT_SEED,
+    },
     errors::BlockrunnersError,
     instructions::update_last_login,
-    state::{Card, CardCounts, CardUsage, GameState, PlayerState, SocialFeedEventType},
+    state::{FeedPage, FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
     utils::{
-        get_move_cost, give_random_cards, randomness_reveal, randomness_use, save_and_emit_event,
+        generate_path_seed, randomness_reveal, save_and_emit_event, save_and_emit_game_event,
+        save_rule_events, transfer, verify_prize_vault,
     },
 };
 
 #[derive(Accounts)]
+#[instruction(game_id: u64)]
 pub struct MoveReveal<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -22,11 +28,36 @@ pub struct MoveReveal<'info> {
     pub player_state: Account<'info, PlayerState>,
 
     #[account(mut,
-      seeds = [GAME_STATE_SEED],
+        seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+        bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    #[account(mut,
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
       bump
     )]
     pub game_state: Account<'info, GameState>,
 
+    /// Current page of the game feed, opened in advance by the game authority
+    #[account(mut,
+      seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &game_state.feed_page.to_le_bytes()],
+      bump = feed_page.bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(mut,
+      seeds = [PRIZE_VAULT_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub prize_vault: SystemAccount<'info>,
+
     /// CHECK: This account is validated in the instruction handler
     #[account()]
     pub randomness_account: AccountInfo<'info>,
@@ -34,17 +65,23 @@ pub struct MoveReveal<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn move_reveal(ctx: Context<MoveReveal>) -> Result<()> {
+pub fn move_reveal(ctx: Context<MoveReveal>, _game_id: u64) -> Result<()> {
     let player = &mut ctx.accounts.player;
     let game_state = &mut ctx.accounts.game_state;
     let player_state = &mut ctx.accounts.player_state;
+    let player_feed = &mut ctx.accounts.player_feed.load_mut()?;
+    let feed_page = &mut ctx.accounts.feed_page;
+    let game_config = &ctx.accounts.game_config;
     let randomness_account = &ctx.accounts.randomness_account;
 
+    // Paused moves stay committed until reveals resume or the player cancels them
+    require!(!game_state.paused.reveals, BlockrunnersError::GamePaused);
+
     update_last_login(player_state)?;
 
     // Check if player is part of the current game
     require!(
-        player_state.game_start == Some(game_state.start),
+        player_state.is_in_game(game_state),
         BlockrunnersError::PlayingInDifferentGame
     );
 
@@ -55,312 +92,168 @@ pub fn move_reveal(ctx: Context<MoveReveal>) -> Result<()> {
     );
 
     // Process card usage - validate and apply effects
+    let direction = player_state
+        .move_direction
+        .ok_or(BlockrunnersError::MoveNotCommitted)?;
     let used_cards = player_state
         .move_cards
         .ok_or(BlockrunnersError::MoveNotCommitted)?;
-    let total_cost = get_move_cost(player_state, &used_cards)?;
+
+    // Expired commitments can't be revealed anymore, only cancelled
+    let clock = Clock::get()?;
     require!(
-        player_state.ciphers >= total_cost,
-        BlockrunnersError::InsufficientBalance
+        !player_state.is_move_expired(clock.slot, game_config.params.move_timeout_slots),
+        BlockrunnersError::MoveCommitExpired
     );
-    player_state.ciphers = player_state.ciphers.saturating_sub(total_cost);
-
-    // Remove each used card from player's inventory
-    if used_cards.shield {
-        require!(
-            player_state.cards.remove_card(Card::Shield),
-            BlockrunnersError::InsufficientCards
-        );
-    }
-    if used_cards.doubler {
-        require!(
-            player_state.cards.remove_card(Card::Doubler),
-            BlockrunnersError::InsufficientCards
-        );
-    }
-    if used_cards.swift {
-        require!(
-            player_state.cards.remove_card(Card::Swift),
-            BlockrunnersError::InsufficientCards
-        );
-    }
 
     // Reveal randomness
-    randomness_reveal(player_state, randomness_account)?;
-
-    // Determine the correct direction based on the randomness value and success probability
-    let random_value = randomness_use(player_state)?;
-    let is_move_successful = (random_value % 100) < MOVE_SUCCESS_PROBABILITY;
-
-    if is_move_successful {
-        handle_correct_move(player_state, game_state, used_cards)?;
-
-        if player_state.position == game_state.path_length {
-            handle_win(player, game_state, player_state)?;
-        }
-    } else {
-        handle_incorrect_move(player_state, used_cards)?;
-    };
-
-    // Reset player's move & cards commitment
-    player_state.move_direction = None;
-    player_state.move_cards = None;
-    player_state.randomness_account = None;
-    player_state.randomness_slot = None;
-    player_state.randomness_value = None;
-
-    Ok(())
-}
-
-fn handle_correct_move(
-    player_state: &mut Account<PlayerState>,
-    game_state: &mut Account<GameState>,
-    card_usage: CardUsage,
-) -> Result<()> {
-    // Correct move: advance one step
-    player_state.position += 1;
-    let new_position = player_state.position;
-
-    // Update statistics
-    player_state.total_steps += 1;
-
-    // Check for personal best position
-    if new_position > player_state.best_position {
-        player_state.best_position = new_position;
-
-        let personal_message = format!(
-            "PERSONAL BEST: New furthest infiltration depth reached: {} steps!",
-            new_position
-        );
-        save_and_emit_event(
-            &mut player_state.player_events,
-            SocialFeedEventType::PersonalBest,
-            personal_message,
-        )?;
-    }
-
-    // Check for milestone notifications
-    crate::utils::check_milestones(player_state, game_state)?;
-
-    // Base message
-    let mut private_message = format!("Advanced to position {}!", new_position);
-
-    // Apply doubler effect
-    let collect_cards_count = if card_usage.doubler { 2 } else { 1 };
-
-    // Collect cards based on success and doubler
-    give_random_cards(player_state, collect_cards_count)?;
-    player_state.cards_collected += collect_cards_count as u64;
-
-    // Build event message
-    let mut card_effects = Vec::new();
-    if card_usage.doubler {
-        card_effects.push("Doubler (2 cards collected)");
-    }
-    if card_usage.shield {
-        card_effects.push("Shield");
-        player_state.shields_used += 1;
-    }
-    if card_usage.swift {
-        card_effects.push("Swift (cipher refund)");
-    }
-
-    if !card_effects.is_empty() {
-        private_message = format!(
-            "{} Cards used: {}",
-            private_message,
-            card_effects.join(", ")
-        );
-    }
+    randomness_reveal(player_state, game_state, randomness_account)?;
+
+    // Tests can script the randomness of each move
+    #[cfg(feature = "test")]
+    crate::utils::use_scripted_randomness(&player.key(), player_state, ctx.remaining_accounts)?;
+
+    // Resolve the move by the game rules
+    let position = player_state.position;
+    let mut runner = player_state.runner();
+    let mut randomness = player_state.randomness_stream()?;
+    let result = game_config
+        .params
+        .rules()
+        .resolve_move(
+            &mut runner,
+            &game_state.game_view(),
+            direction,
+            used_cards,
+            &mut randomness,
+        )
+        .map_err(BlockrunnersError::from)?;
+    player_state.apply_runner(&runner);
+    player_state.randomness_value = Some(randomness.as_slice().to_vec());
+    msg!(
+        "Path step {}: {:?}, committed direction: {:?}",
+        position,
+        result.path_step,
+        direction
+    );
 
-    save_and_emit_event(
-        &mut player_state.player_events,
-        SocialFeedEventType::PlayerMoved,
-        private_message,
+    save_rule_events(
+        player_feed,
+        game_state,
+        feed_page,
+        game_config.params.max_feed_events,
+        result.events,
     )?;
 
-    Ok(())
-}
-
-fn handle_incorrect_move(
-    player_state: &mut Account<PlayerState>,
-    card_usage: CardUsage,
-) -> Result<()> {
-    if card_usage.shield {
-        let mut private_message = format!(
-            "Shield protocol activated! Maintaining position {}.",
-            player_state.position
-        );
-
-        let mut other_cards = Vec::new();
-        if card_usage.doubler {
-            other_cards.push("Doubler");
-        }
-        if card_usage.swift {
-            other_cards.push("Swift (cipher refund)");
-        }
-
-        if !other_cards.is_empty() {
-            private_message = format!("{}. Also used: {}", private_message, other_cards.join(", "));
-        }
-
-        player_state.shields_used += 1;
-
-        save_and_emit_event(
-            &mut player_state.player_events,
-            SocialFeedEventType::PlayerMoved,
-            private_message,
+    if result.outcome == MoveOutcome::Won {
+        let prize = handle_win(
+            player,
+            game_state,
+            feed_page,
+            game_config,
+            player_state,
+            &ctx.accounts.prize_vault,
+            &ctx.accounts.system_program,
         )?;
-    } else {
-        // No shield = reset to start
-        let reset_position = player_state.position;
-        player_state.position = 0;
-        player_state.cards = CardCounts::default();
-        player_state.total_resets += 1;
-
-        // Reset consecutive wins
-        player_state.consecutive_wins = 0;
-
-        // Build event message for incorrect move
-        let mut private_message = format!(
-            "CONSENSUS DETECTED: Connection severed at depth {}. Returning to entry point.",
-            reset_position
-        );
-
-        let mut cards_used = Vec::new();
-        if card_usage.doubler {
-            cards_used.push("Doubler");
-        }
-        if card_usage.swift {
-            cards_used.push("Swift (cipher refund)");
-        }
-
-        if !cards_used.is_empty() {
-            private_message = format!("{} Cards used: {}", private_message, cards_used.join(", "));
-        }
 
+        // Announce to player's feed
+        let private_payload = FeedPayload::MissionComplete {
+            streak: player_state.consecutive_wins,
+            prize,
+        };
         save_and_emit_event(
-            &mut player_state.player_events,
-            SocialFeedEventType::ResetAlert,
-            private_message,
+            player_feed,
+            game_config.params.max_feed_events,
+            private_payload,
         )?;
     }
 
+    // Reset player's move & cards commitment
+    player_state.clear_move_commitment();
+
     Ok(())
 }
 
-fn handle_win(
-    player: &Signer,
-    game_state: &mut Account<GameState>,
-    player_state: &mut Account<PlayerState>,
-) -> Result<()> {
-    // Update win statistics
-    player_state.games_won = player_state
-        .games_won
-        .checked_add(1)
-        .ok_or(BlockrunnersError::UnknownError)?;
-
-    // Update win streak
-    player_state.consecutive_wins += 1;
-    if player_state.consecutive_wins > player_state.best_win_streak {
-        player_state.best_win_streak = player_state.consecutive_wins;
-
-        // Notify about new win streak record
-        let streak_message = format!(
-            "STREAK RECORD: New personal best win streak of {} successful infiltrations!",
-            player_state.consecutive_wins
-        );
-        save_and_emit_event(
-            &mut player_state.player_events,
-            SocialFeedEventType::PersonalBest,
-            streak_message,
-        )?;
-    }
-
-    // Verify we have enough lamports before transfer
-    let current_lamports = game_state.to_account_info().lamports();
-    require!(
-        current_lamports >= game_state.prize_pool,
-        BlockrunnersError::InsufficientBalance
-    );
+/// Pay out the prize pool and start the next game, returning the prize
+fn handle_win<'info>(
+    player: &Signer<'info>,
+    game_state: &mut Account<'info, GameState>,
+    feed_page: &mut FeedPage,
+    game_config: &GameConfig,
+    player_state: &mut Account<'info, PlayerState>,
+    prize_vault: &SystemAccount<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    // Verify the vault holds the whole prize pool before transfer
+    verify_prize_vault(game_state, prize_vault)?;
 
     // check if there's any prize to distribute from the pool
     let prize_amount = game_state.prize_pool;
 
     if prize_amount > 0 {
-        let global_message =
-            format!("PROTOCOL BREACH SUCCESSFUL: Distributing recovered data fragments!");
-        save_and_emit_event(
-            &mut game_state.game_events,
-            SocialFeedEventType::GameWon,
-            global_message,
+        save_and_emit_game_event(game_state, feed_page, FeedPayload::PrizeDistributing)?;
+
+        // Transfer the prize from the vault to the player, signed with the vault's PDA seeds
+        let game_id_bytes = game_state.game_id.to_le_bytes();
+        let vault_seeds: &[&[u8]] = &[
+            PRIZE_VAULT_SEED,
+            &game_id_bytes,
+            &[game_state.prize_vault_bump],
+        ];
+        transfer(
+            system_program.to_account_info(),
+            prize_vault.to_account_info(),
+            player.to_account_info(),
+            prize_amount,
+            Some(&[vault_seeds]),
         )?;
 
-        // Subtract prize from game state
-        **game_state.to_account_info().try_borrow_mut_lamports()? = game_state
-            .to_account_info()
-            .lamports()
-            .checked_sub(prize_amount)
-            .ok_or(BlockrunnersError::ArithmeticOverflow)?;
-
-        // Add prize to player
-        **player.try_borrow_mut_lamports()? = player
-            .lamports()
-            .checked_add(prize_amount)
-            .ok_or(BlockrunnersError::ArithmeticOverflow)?;
-
         msg!("Prize transferred successfully!");
 
         // Reset the prize pool
         game_state.prize_pool = 0;
         msg!("Prize pool reset to 0.");
+
+        // Only the rent reserve stays in the vault
+        verify_prize_vault(game_state, prize_vault)?;
     } else {
         msg!("Player won, but there's nothing in the pool.");
     }
 
-    // Create global win message for everyone
-    let player_key_str = player.key().to_string();
-    let global_message = format!(
-        "CONSENSUS BREACH: Runner {}...{} extracted protocol fragment after {} steps. Reward: {} lamports",
-        &player_key_str[0..4],
-        &player_key_str[player_key_str.len() - 4..],
-        player_state.position,
-        prize_amount
-    );
-
     // Announce to global feed
-    save_and_emit_event(
-        &mut game_state.game_events,
-        SocialFeedEventType::GameWon,
-        global_message,
-    )?;
-
-    // Create personal congrats for winner
-    let private_message = if player_state.consecutive_wins > 1 {
-        format!(
-            "MISSION COMPLETE: Protocol fragment secured! Streak: {} | Reward: {} lamports",
-            player_state.consecutive_wins, prize_amount
-        )
-    } else {
-        format!(
-            "MISSION COMPLETE: Protocol fragment secured! Reward: {} lamports",
-            prize_amount
-        )
+    let global_payload = FeedPayload::GameWon {
+        player: player.key(),
+        steps: player_state.position,
+        prize: prize_amount,
     };
-
-    // Announce to player's feed
-    save_and_emit_event(
-        &mut player_state.player_events,
-        SocialFeedEventType::GameWon,
-        private_message,
-    )?;
-
-    // Check for achievements
-    crate::utils::check_and_award_achievements(player_state, game_state)?;
+    save_and_emit_game_event(game_state, feed_page, global_payload)?;
 
     // Update game start time to trigger resets for all players with a new timestamp set
     let clock = Clock::get()?;
+    let game_duration = clock.unix_timestamp.saturating_sub(game_state.start);
     game_state.start = clock.unix_timestamp;
 
-    Ok(())
+    // Recompute the path length for the new game
+    let previous_path_length = game_state.path_length;
+    game_state.path_length =
+        game_config.next_path_length(previous_path_length, prize_amount, game_duration);
+    if game_state.path_length != previous_path_length {
+        let global_payload = FeedPayload::PathLengthChanged {
+            previous: previous_path_length,
+            next: game_state.path_length,
+        };
+        save_and_emit_game_event(game_state, feed_page, global_payload)?;
+    }
+
+    // Publish the finished game's path seed so its steps can be verified, then seed the next path
+    let previous_seed = game_state.path_seed;
+    msg!("Path seed of the finished game: {:?}", previous_seed);
+    game_state.path_seed = generate_path_seed(
+        &previous_seed,
+        game_state.game_id,
+        game_state.start,
+        player_state.randomness_value.as_deref().unwrap_or_default(),
+    )?;
+
+    Ok(prize_amount)
 }
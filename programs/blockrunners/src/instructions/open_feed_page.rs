@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, FEED_PAGE_SEED, GAME_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{FeedPage, GameState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64, page_index: u64)]
+pub struct OpenFeedPage<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = usize::from(DISCRIMINATOR_SIZE) + FeedPage::INIT_SPACE,
+        seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &page_index.to_le_bytes()],
+        bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_feed_page(ctx: Context<OpenFeedPage>, game_id: u64, page_index: u64) -> Result<()> {
+    let game_state = &ctx.accounts.game_state;
+
+    // Pages are opened ahead of the game, the ones it moved on from stay closed
+    require!(
+        page_index >= game_state.feed_page,
+        BlockrunnersError::FeedPageOutdated
+    );
+
+    ctx.accounts.feed_page.open(
+        game_state,
+        page_index,
+        ctx.bumps.feed_page,
+        ctx.accounts.authority.key(),
+    );
+
+    msg!("Feed page {} of game {} opened", page_index, game_id);
+    Ok(())
+}
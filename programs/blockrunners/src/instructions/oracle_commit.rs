@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::GAME_STATE_SEED, errors::BlockrunnersError, state::GameState};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct OracleCommit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+/// Commit the local oracle to the hash of the next secret it will reveal
+pub fn oracle_commit(ctx: Context<OracleCommit>, game_id: u64, commitment: [u8; 32]) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    // Moves are bound to the pending commitment, so it can't be replaced before it's revealed
+    require!(
+        game_state.oracle_commitment.is_none(),
+        BlockrunnersError::OracleCommitmentPending
+    );
+    game_state.oracle_commitment = Some(commitment);
+
+    msg!(
+        "Local oracle of game {} committed to the next secret",
+        game_id
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::{
+    constants::GAME_STATE_SEED,
+    errors::BlockrunnersError,
+    state::{GameState, OracleReveal},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct OracleRevealSecret<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+/// Reveal the secret of the pending local oracle commitment and optionally commit to the next one
+pub fn oracle_reveal(
+    ctx: Context<OracleRevealSecret>,
+    game_id: u64,
+    secret: [u8; 32],
+    next_commitment: Option<[u8; 32]>,
+) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+
+    let commitment = game_state
+        .oracle_commitment
+        .ok_or(BlockrunnersError::OracleCommitmentMissing)?;
+    require!(
+        hashv(&[&secret]).to_bytes() == commitment,
+        BlockrunnersError::InvalidOracleSecret
+    );
+
+    // Moves bound to the revealed commitment can be revealed until the next secret is revealed
+    game_state.oracle_reveal = Some(OracleReveal { commitment, secret });
+    game_state.oracle_commitment = next_commitment;
+
+    msg!("Local oracle of game {} revealed its secret", game_id);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::GAME_STATE_SEED, errors::BlockrunnersError, state::GameState};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+}
+
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
+    game_id: u64,
+    new_authority: Option<Pubkey>,
+) -> Result<()> {
+    // The proposed key only takes over once it signs `accept_authority`, so a typo can't lock
+    // the game. Proposing `None` withdraws a pending proposal.
+    ctx.accounts.game_state.pending_authority = new_authority;
+
+    match new_authority {
+        Some(new_authority) => msg!("Game {} authority proposed: {}", game_id, new_authority),
+        None => msg!("Game {} authority proposal withdrawn", game_id),
+    }
+    Ok(())
+}
@@ -1,14 +1,18 @@
 use anchor_lang::{prelude::*, system_program};
 
 use crate::{
-    constants::{CIPHER_COST, GAME_STATE_SEED, PLAYER_STATE_SEED, PRIZE_POOL_PERCENTAGE},
+    constants::{
+        FEED_PAGE_SEED, GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED,
+        PRIZE_VAULT_SEED,
+    },
     errors::BlockrunnersError,
     instructions::update_last_login,
-    state::{GameState, PlayerState, SocialFeedEventType},
-    utils::save_and_emit_event,
+    state::{Achievement, FeedPage, FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
+    utils::{save_and_emit_event, save_and_emit_game_event, verify_prize_vault},
 };
 
 #[derive(Accounts)]
+#[instruction(game_id: u64)]
 pub struct PurchaseCiphers<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
@@ -20,29 +24,67 @@ pub struct PurchaseCiphers<'info> {
     pub player_state: Account<'info, PlayerState>,
 
     #[account(mut,
-      seeds = [GAME_STATE_SEED],
+      seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+      bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    #[account(mut,
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
       bump
     )]
     pub game_state: Account<'info, GameState>,
 
-    /// CHECK: This is the admin wallet that receives the admin share
-    #[account(mut, address = game_state.authority)]
+    /// Current page of the game feed, opened in advance by the game authority
+    #[account(mut,
+      seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &game_state.feed_page.to_le_bytes()],
+      bump = feed_page.bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(mut,
+      seeds = [PRIZE_VAULT_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub prize_vault: SystemAccount<'info>,
+
+    /// CHECK: This is the fee recipient of the game that receives the admin share
+    #[account(mut, address = game_state.fee_recipient)]
     pub admin_wallet: SystemAccount<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
-pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, amount: u64) -> Result<()> {
+pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, _game_id: u64, amount: u64) -> Result<()> {
     let player_state = &mut ctx.accounts.player_state;
+    let player_feed = &mut ctx.accounts.player_feed.load_mut()?;
     let game_state = &mut ctx.accounts.game_state;
+    let feed_page = &mut ctx.accounts.feed_page;
+    let game_config = &ctx.accounts.game_config;
+
+    require!(!game_state.paused.purchases, BlockrunnersError::GamePaused);
 
     // Check if amount is positive
     require!(amount > 0, BlockrunnersError::NegativeCiphersAmount);
 
     update_last_login(player_state)?;
 
+    // Ciphers are paid into one game, so only its runners can buy them
+    require!(
+        player_state.is_in_game(game_state),
+        BlockrunnersError::PlayingInDifferentGame
+    );
+
     // Calculate cost in lamports
-    let cost = amount * CIPHER_COST;
+    let cost = amount
+        .checked_mul(game_config.params.cipher_cost)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
 
     // Check if player has enough balance
     require!(
@@ -52,17 +94,17 @@ pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, amount: u64) -> Result<()
 
     // Calculate revenue distribution
     let prize_pool_amount = cost
-        .checked_mul(PRIZE_POOL_PERCENTAGE as u64)
+        .checked_mul(game_config.params.prize_pool_percentage as u64)
         .ok_or(ProgramError::ArithmeticOverflow)?
         / 100;
     let admin_amount = cost - prize_pool_amount; // Ensures no rounding loss
 
-    // Transfer prize pool portion to the game state
+    // Transfer prize pool portion to the prize vault
     let cpi_context = CpiContext::new(
         ctx.accounts.system_program.to_account_info(),
         system_program::Transfer {
             from: ctx.accounts.player.to_account_info(),
-            to: game_state.to_account_info(),
+            to: ctx.accounts.prize_vault.to_account_info(),
         },
     );
     system_program::transfer(cpi_context, prize_pool_amount)?;
@@ -79,10 +121,9 @@ pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, amount: u64) -> Result<()
         system_program::transfer(cpi_context_admin, admin_amount)?;
     }
 
-    // Update player's cipher count
-    player_state.ciphers = player_state
-        .ciphers
-        .checked_add(amount)
+    // Update player's cipher count and the price they were paid at
+    player_state
+        .buy_ciphers(amount, cost)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
     player_state.total_ciphers_bought = player_state
@@ -98,44 +139,44 @@ pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, amount: u64) -> Result<()
         .checked_add(prize_pool_amount)
         .ok_or(ProgramError::ArithmeticOverflow)?;
 
+    // The vault must hold exactly the prize pool plus rent
+    verify_prize_vault(game_state, &ctx.accounts.prize_vault)?;
+
     // Check for significant prize pool increases (>10% increase)
     if old_prize_pool > 0 {
         let increase_percentage =
             ((prize_pool_amount as f64 / old_prize_pool as f64) * 100.0) as u64;
         if increase_percentage >= 10 {
-            let pool_message = format!(
-                "FUNDING SURGE: Protocol recovery fund increased by {}% to {} lamports. Mission priority escalating.",
+            let global_payload = FeedPayload::PrizePoolSurge {
                 increase_percentage,
-                game_state.prize_pool
-            );
-            save_and_emit_event(
-                &mut game_state.game_events,
-                SocialFeedEventType::PrizePoolChange,
-                pool_message,
-            )?;
+                prize_pool: game_state.prize_pool,
+            };
+            save_and_emit_game_event(game_state, feed_page, global_payload)?;
         }
     }
 
     // Announce to player's feed
-    let private_message = format!(
-        "RESOURCES ACQUIRED: {} computational ciphers purchased for {} lamports. Your current reserves: {}",
-        amount, cost, player_state.ciphers
-    );
+    let private_payload = FeedPayload::CiphersPurchased {
+        amount,
+        cost,
+        ciphers: player_state.ciphers,
+    };
     save_and_emit_event(
-        &mut player_state.player_events,
-        SocialFeedEventType::CiphersPurchased,
-        private_message,
+        player_feed,
+        game_config.params.max_feed_events,
+        private_payload,
     )?;
 
     // Check if player achieved Cipher Lord status
     if player_state.total_ciphers_bought >= 1000 && !player_state.cipher_lord {
         player_state.cipher_lord = true;
-        let achievement_message =
-            "ACHIEVEMENT UNLOCKED: Cipher Lord - Mastered computational resource acquisition!";
+        let private_payload = FeedPayload::AchievementUnlocked {
+            achievement: Achievement::CipherLord,
+        };
         save_and_emit_event(
-            &mut player_state.player_events,
-            SocialFeedEventType::ProtocolFragment,
-            achievement_message.to_string(),
+            player_feed,
+            game_config.params.max_feed_events,
+            private_payload,
         )?;
     }
 
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        GAME_CONFIG_SEED, GAME_STATE_SEED, PLAYER_FEED_SEED, PLAYER_STATE_SEED, RESERVE_VAULT_SEED,
+    },
+    errors::BlockrunnersError,
+    instructions::update_last_login,
+    state::{FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
+    utils::{pay_from_reserve, reserve_available, save_and_emit_event},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct RedeemCiphers<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    #[account(mut,
+      seeds = [PLAYER_STATE_SEED, player.key().as_ref()],
+      bump
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(mut,
+      seeds = [PLAYER_FEED_SEED, player.key().as_ref()],
+      bump
+    )]
+    pub player_feed: AccountLoader<'info, PlayerFeed>,
+
+    #[account(
+      seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(
+      seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// Pays the redemption, the prize vault is never touched
+    #[account(mut,
+      seeds = [RESERVE_VAULT_SEED, &game_id.to_le_bytes()],
+      bump
+    )]
+    pub reserve_vault: SystemAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn redeem_ciphers(ctx: Context<RedeemCiphers>, game_id: u64, amount: u64) -> Result<()> {
+    let player_state = &mut ctx.accounts.player_state;
+    let player_feed = &mut ctx.accounts.player_feed.load_mut()?;
+    let game_state = &ctx.accounts.game_state;
+    let game_config = &ctx.accounts.game_config;
+
+    // Redemptions are cipher trades, so they stop together with purchases
+    require!(!game_state.paused.purchases, BlockrunnersError::GamePaused);
+    require!(amount > 0, BlockrunnersError::ZeroAmount);
+    require!(
+        game_config.params.redeem_percentage > 0,
+        BlockrunnersError::RedemptionDisabled
+    );
+
+    update_last_login(player_state)?;
+
+    // Ciphers are priced per game
+    require!(
+        player_state.game_id == Some(game_id),
+        BlockrunnersError::PlayingInDifferentGame
+    );
+
+    // The ciphers of a committed move pay for it on reveal, redeeming them would let the
+    // player back out of moves whose randomness they've already seen
+    require!(
+        player_state.move_direction.is_none(),
+        BlockrunnersError::MovePending
+    );
+    require!(
+        player_state.ciphers >= amount,
+        BlockrunnersError::NotEnoughCiphers
+    );
+
+    // Paid at the price the ciphers were bought at, the cipher cost may have changed since
+    let payout = player_state
+        .ciphers_value(amount, game_config.params.redeem_percentage)
+        .ok_or(BlockrunnersError::ArithmeticOverflow)?;
+    require!(
+        reserve_available(&ctx.accounts.reserve_vault)? >= payout,
+        BlockrunnersError::InsufficientReserve
+    );
+
+    player_state.ciphers -= amount;
+    pay_from_reserve(
+        game_state,
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.reserve_vault.to_account_info(),
+        ctx.accounts.player.to_account_info(),
+        payout,
+    )?;
+
+    // Announce to player's feed
+    let private_payload = FeedPayload::CiphersRedeemed {
+        amount,
+        payout,
+        ciphers: player_state.ciphers,
+    };
+    save_and_emit_event(
+        player_feed,
+        game_config.params.max_feed_events,
+        private_payload,
+    )?;
+
+    msg!("Player redeemed {} ciphers in game {}", amount, game_id);
+    Ok(())
+}
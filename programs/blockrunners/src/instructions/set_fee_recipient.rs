@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::GAME_STATE_SEED,
+    errors::BlockrunnersError,
+    state::{FeeRecipientChanged, GameState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct SetFeeRecipient<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    /// Receives the admin share of cipher purchases as plain transfers
+    pub fee_recipient: SystemAccount<'info>,
+}
+
+pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, game_id: u64) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let fee_recipient = ctx.accounts.fee_recipient.key();
+    let clock = Clock::get()?;
+
+    // Transfers of an admin share below the rent-exempt minimum into an empty account would fail
+    // and block every purchase
+    require!(
+        ctx.accounts.fee_recipient.lamports() >= Rent::get()?.minimum_balance(0),
+        BlockrunnersError::FeeRecipientNotRentExempt
+    );
+
+    let old_fee_recipient = game_state.fee_recipient;
+    game_state.fee_recipient = fee_recipient;
+
+    emit!(FeeRecipientChanged {
+        game_id,
+        authority: ctx.accounts.authority.key(),
+        old_fee_recipient,
+        new_fee_recipient: fee_recipient,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Game {} fee recipient set to {}", game_id, fee_recipient);
+    Ok(())
+}
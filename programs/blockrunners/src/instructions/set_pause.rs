@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, FEED_PAGE_SEED, GAME_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{FeedPage, FeedPayload, GameState, PauseFlags},
+    utils::save_and_emit_game_event,
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct SetPause<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut,
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(init_if_needed,
+        payer = authority,
+        space = usize::from(DISCRIMINATOR_SIZE) + FeedPage::INIT_SPACE,
+        seeds = [FEED_PAGE_SEED, &game_id.to_le_bytes(), &game_state.feed_page.to_le_bytes()],
+        bump
+    )]
+    pub feed_page: Account<'info, FeedPage>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_pause(ctx: Context<SetPause>, game_id: u64, paused: PauseFlags) -> Result<()> {
+    let game_state = &mut ctx.accounts.game_state;
+    let feed_page = &mut ctx.accounts.feed_page;
+
+    feed_page.open(
+        game_state,
+        game_state.feed_page,
+        ctx.bumps.feed_page,
+        ctx.accounts.authority.key(),
+    );
+
+    game_state.paused = paused;
+
+    // Announce to game feed
+    save_and_emit_game_event(game_state, feed_page, FeedPayload::PauseChanged { paused })?;
+
+    msg!("Game {} pause set to {:?}", game_id, paused);
+    Ok(())
+}
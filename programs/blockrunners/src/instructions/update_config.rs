@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{GAME_CONFIG_SEED, GAME_STATE_SEED},
+    errors::BlockrunnersError,
+    state::{GameConfig, GameConfigChanged, GameConfigParams, GameState},
+};
+
+#[derive(Accounts)]
+#[instruction(game_id: u64)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GAME_STATE_SEED, &game_id.to_le_bytes()],
+        bump,
+        constraint = game_state.authority == authority.key() @ BlockrunnersError::Unauthorized
+    )]
+    pub game_state: Account<'info, GameState>,
+
+    #[account(mut,
+        seeds = [GAME_CONFIG_SEED, &game_id.to_le_bytes()],
+        bump
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    game_id: u64,
+    params: GameConfigParams,
+) -> Result<()> {
+    let game_config = &mut ctx.accounts.game_config;
+    let clock = Clock::get()?;
+
+    let old_params = game_config.params;
+    game_config.set_params(params)?;
+
+    emit!(GameConfigChanged {
+        authority: ctx.accounts.authority.key(),
+        old_params,
+        new_params: params,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Game {} config updated", game_id);
+    Ok(())
+}
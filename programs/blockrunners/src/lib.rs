@@ -2,13 +2,13 @@
 
 use anchor_lang::prelude::*;
 
-mod constants;
-mod errors;
+pub mod constants;
+pub mod errors;
 mod instructions;
-mod state;
+pub mod state;
 mod utils;
 use instructions::*;
-use state::{Card, CardUsage, PathDirection};
+use state::{Card, CardUsage, GameConfigParams, PathDirection, PauseFlags};
 
 declare_id!("6BwNv3aA437eCifxaUymtRov1bw17eK4xXuYVXodNWjC");
 
@@ -17,36 +17,126 @@ pub mod blockrunners {
 
     use super::*;
 
-    pub fn initialize_game(ctx: Context<InitializeGame>) -> Result<()> {
-        initialize_game::initialize_game(ctx)
+    pub fn initialize_game(ctx: Context<InitializeGame>, game_id: u64) -> Result<()> {
+        initialize_game::initialize_game(ctx, game_id)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        game_id: u64,
+        params: GameConfigParams,
+    ) -> Result<()> {
+        update_config::update_config(ctx, game_id, params)
+    }
+
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        game_id: u64,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        propose_authority::propose_authority(ctx, game_id, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>, game_id: u64) -> Result<()> {
+        accept_authority::accept_authority(ctx, game_id)
+    }
+
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>, game_id: u64) -> Result<()> {
+        set_fee_recipient::set_fee_recipient(ctx, game_id)
+    }
+
+    pub fn set_pause(ctx: Context<SetPause>, game_id: u64, paused: PauseFlags) -> Result<()> {
+        set_pause::set_pause(ctx, game_id, paused)
+    }
+
+    pub fn fund_reserve(ctx: Context<FundReserve>, game_id: u64, amount: u64) -> Result<()> {
+        fund_reserve::fund_reserve(ctx, game_id, amount)
+    }
+
+    pub fn open_feed_page(ctx: Context<OpenFeedPage>, game_id: u64, page_index: u64) -> Result<()> {
+        open_feed_page::open_feed_page(ctx, game_id, page_index)
+    }
+
+    pub fn close_feed_page(
+        ctx: Context<CloseFeedPage>,
+        game_id: u64,
+        page_index: u64,
+    ) -> Result<()> {
+        close_feed_page::close_feed_page(ctx, game_id, page_index)
     }
 
     pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
         initialize_player::initialize_player(ctx)
     }
 
-    pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
-        join_game::join_game(ctx)
+    pub fn close_player(ctx: Context<ClosePlayer>, forfeit_ciphers: bool) -> Result<()> {
+        close_player::close_player(ctx, forfeit_ciphers)
     }
 
-    pub fn purchase_ciphers(ctx: Context<PurchaseCiphers>, amount: u64) -> Result<()> {
-        purchase_ciphers::purchase_ciphers(ctx, amount)
+    pub fn join_game(ctx: Context<JoinGame>, game_id: u64) -> Result<()> {
+        join_game::join_game(ctx, game_id)
+    }
+
+    pub fn purchase_ciphers(
+        ctx: Context<PurchaseCiphers>,
+        game_id: u64,
+        amount: u64,
+    ) -> Result<()> {
+        purchase_ciphers::purchase_ciphers(ctx, game_id, amount)
+    }
+
+    pub fn redeem_ciphers(ctx: Context<RedeemCiphers>, game_id: u64, amount: u64) -> Result<()> {
+        redeem_ciphers::redeem_ciphers(ctx, game_id, amount)
     }
 
     pub fn move_commit(
         ctx: Context<MoveCommit>,
+        game_id: u64,
         direction: PathDirection,
         card_usage: CardUsage,
     ) -> Result<()> {
-        move_commit::move_commit(ctx, direction, card_usage)
+        move_commit::move_commit(ctx, game_id, direction, card_usage)
     }
 
-    pub fn move_reveal(ctx: Context<MoveReveal>) -> Result<()> {
-        move_reveal::move_reveal(ctx)
+    pub fn move_reveal(ctx: Context<MoveReveal>, game_id: u64) -> Result<()> {
+        move_reveal::move_reveal(ctx, game_id)
+    }
+
+    pub fn cancel_move(ctx: Context<CancelMove>, game_id: u64) -> Result<()> {
+        cancel_move::cancel_move(ctx, game_id)
+    }
+
+    pub fn oracle_commit(
+        ctx: Context<OracleCommit>,
+        game_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        oracle_commit::oracle_commit(ctx, game_id, commitment)
+    }
+
+    pub fn oracle_reveal(
+        ctx: Context<OracleRevealSecret>,
+        game_id: u64,
+        secret: [u8; 32],
+        next_commitment: Option<[u8; 32]>,
+    ) -> Result<()> {
+        oracle_reveal::oracle_reveal(ctx, game_id, secret, next_commitment)
+    }
+
+    pub fn get_next_step_price(ctx: Context<GetNextStepPrice>, game_id: u64) -> Result<u64> {
+        get_next_step_price::get_next_step_price(ctx, game_id)
     }
 
     #[cfg(feature = "test")]
     pub fn debug_give_card(ctx: Context<DebugGiveCard>, card: Card) -> Result<()> {
         debug_give_card::debug_give_card(ctx, card)
     }
+
+    #[cfg(feature = "test")]
+    pub fn debug_script_randomness(
+        ctx: Context<DebugScriptRandomness>,
+        values: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        debug_script_randomness::debug_script_randomness(ctx, values)
+    }
 }
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEED_PAGE_EVENTS, MAX_FEED_PAGE_EVENTS},
+    state::{FeedEntry, GameState},
+};
+
+/// A page of the game feed's history. The game writes its feed events to its current page until
+/// the page holds `FEED_PAGE_EVENTS` events, then moves on to the next page index. Pages are
+/// opened in advance by the game authority, so players never pay their rent.
+#[account]
+#[derive(InitSpace)]
+pub struct FeedPage {
+    pub game_id: u64,
+
+    pub page_index: u64,
+
+    /// Store bump to save compute
+    pub bump: u8,
+
+    /// Account that paid the rent, refunded when the page is closed
+    pub payer: Pubkey,
+
+    #[max_len(MAX_FEED_PAGE_EVENTS)]
+    pub entries: Vec<FeedEntry>,
+}
+
+impl FeedPage {
+    /// Set up page `page_index` the instruction just created, pages in use are left as they are
+    pub fn open(&mut self, game_state: &GameState, page_index: u64, bump: u8, payer: Pubkey) {
+        if self.payer != Pubkey::default() {
+            return;
+        }
+
+        self.game_id = game_state.game_id;
+        self.page_index = page_index;
+        self.bump = bump;
+        self.payer = payer;
+    }
+
+    /// Whether the game has moved on to the next page
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= usize::from(FEED_PAGE_EVENTS)
+    }
+}
@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        ADMIN_PERCENTAGE, BASE_MOVE_COST, CARD_COST, CIPHER_COST, INITIAL_PATH_LENGTH,
+        MAX_FEED_EVENTS, MAX_PATH_LENGTH, MIN_PATH_LENGTH, MOVE_SUCCESS_PROBABILITY,
+        MOVE_TIMEOUT_SLOTS, PRIZE_POOL_PERCENTAGE, REDEEM_PERCENTAGE, STARTING_HAND_TIERS,
+        SWIFT_DISCOUNT,
+    },
+    errors::BlockrunnersError,
+    state::{CardCounts, PathLengthRule, StartingHandTier, StepPriceCurve},
+};
+
+/// Source of the randomness that decides the outcome of a move
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub enum RandomnessProvider {
+    /// Switchboard on-demand randomness accounts, requested and paid for by the player
+    Switchboard,
+
+    /// Hash of the slot the move was committed in, free but the slot leader can influence it,
+    /// so only meant for low-stakes tables
+    SlotHash,
+
+    /// Secrets committed and revealed by the game authority with `oracle_commit` and `oracle_reveal`
+    LocalOracle,
+}
+
+/// What happens to the ciphers a player didn't spend when their game is won by someone else
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub enum CipherCarryOver {
+    /// The ciphers are lost
+    Forfeit,
+
+    /// The ciphers can be spent in the next game
+    CarryOver,
+
+    /// The ciphers are refunded from the reserve vault at `refund_percentage` percent of the
+    /// price they were bought at, as far as the reserve covers it. The percentage can't exceed
+    /// the admin share, so refunds never pay out more than the house earned on the ciphers.
+    Refund { refund_percentage: u8 },
+}
+
+/// Default tiers: one card of each type, plus one more of each for every tenfold pool increase
+pub const DEFAULT_STARTING_HAND_TIERS: [StartingHandTier; STARTING_HAND_TIERS] = [
+    StartingHandTier {
+        min_prize_pool: 0,
+        cards: CardCounts {
+            shield: 1,
+            doubler: 1,
+            swift: 1,
+        },
+    },
+    StartingHandTier {
+        min_prize_pool: 1_000_000,
+        cards: CardCounts {
+            shield: 2,
+            doubler: 2,
+            swift: 2,
+        },
+    },
+    StartingHandTier {
+        min_prize_pool: 10_000_000,
+        cards: CardCounts {
+            shield: 3,
+            doubler: 3,
+            swift: 3,
+        },
+    },
+    StartingHandTier {
+        min_prize_pool: 100_000_000,
+        cards: CardCounts {
+            shield: 4,
+            doubler: 4,
+            swift: 4,
+        },
+    },
+];
+
+/// Tunable game parameters, as passed to `update_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct GameConfigParams {
+    /// Price of one cipher in lamports
+    pub cipher_cost: u64,
+
+    /// Base cost of a move in ciphers
+    pub base_move_cost: u64,
+
+    /// Cost of using one card in ciphers
+    pub card_cost: u64,
+
+    /// Ciphers saved on a move by using the Swift card
+    pub swift_discount: u64,
+
+    /// How the base move cost grows with the position on the path
+    pub step_price_curve: StepPriceCurve,
+
+    /// The length of the path for new games
+    pub path_length: u8,
+
+    /// How the path length changes between games, bounded by the min and max path length
+    pub path_length_rule: PathLengthRule,
+    pub min_path_length: u8,
+    pub max_path_length: u8,
+
+    /// Move success probability as a percentage (out of 100)
+    pub move_success_probability: u8,
+
+    /// Number of slots after which a pending move expires
+    pub move_timeout_slots: u64,
+
+    /// Where the randomness of moves comes from
+    pub randomness_provider: RandomnessProvider,
+
+    /// Revenue distribution percentages, must sum up to 100
+    pub prize_pool_percentage: u8,
+    pub admin_percentage: u8,
+
+    /// Number of events kept in the game and player feeds
+    pub max_feed_events: u8,
+
+    /// Starting hands by prize pool size, in ascending order of `min_prize_pool`
+    pub starting_hand_tiers: [StartingHandTier; STARTING_HAND_TIERS],
+
+    /// What happens to unspent ciphers when a new game starts
+    pub cipher_carry_over: CipherCarryOver,
+
+    /// Percentage of the price paid for the ciphers `redeem_ciphers` pays back from the reserve
+    /// vault, 0 turns redemptions off. Can't exceed the admin percentage, so players can't
+    /// profit from buying and redeeming and the prize pool is never touched.
+    pub redeem_percentage: u8,
+}
+
+impl Default for GameConfigParams {
+    fn default() -> Self {
+        Self {
+            cipher_cost: CIPHER_COST,
+            base_move_cost: BASE_MOVE_COST,
+            card_cost: CARD_COST,
+            swift_discount: SWIFT_DISCOUNT,
+            step_price_curve: StepPriceCurve::Flat,
+            path_length: INITIAL_PATH_LENGTH,
+            path_length_rule: PathLengthRule::Fixed,
+            min_path_length: MIN_PATH_LENGTH,
+            max_path_length: MAX_PATH_LENGTH,
+            move_success_probability: MOVE_SUCCESS_PROBABILITY,
+            move_timeout_slots: MOVE_TIMEOUT_SLOTS,
+            randomness_provider: RandomnessProvider::Switchboard,
+            prize_pool_percentage: PRIZE_POOL_PERCENTAGE,
+            admin_percentage: ADMIN_PERCENTAGE,
+            max_feed_events: MAX_FEED_EVENTS,
+            starting_hand_tiers: DEFAULT_STARTING_HAND_TIERS,
+            cipher_carry_over: CipherCarryOver::CarryOver,
+            redeem_percentage: REDEEM_PERCENTAGE,
+        }
+    }
+}
+
+impl GameConfigParams {
+    /// Check that the parameters describe a playable game
+    pub fn validate(&self) -> Result<()> {
+        require!(self.cipher_cost > 0, BlockrunnersError::InvalidCipherCost);
+        require!(
+            self.min_path_length > 0
+                && self.min_path_length <= self.path_length
+                && self.path_length <= self.max_path_length,
+            BlockrunnersError::InvalidPathLength
+        );
+        match self.path_length_rule {
+            PathLengthRule::Fixed => {}
+            PathLengthRule::PrizePool { lamports_per_step } => {
+                require!(
+                    lamports_per_step > 0,
+                    BlockrunnersError::InvalidPathLengthRule
+                )
+            }
+            PathLengthRule::WinSpeed { target_duration } => {
+                require!(
+                    target_duration > 0,
+                    BlockrunnersError::InvalidPathLengthRule
+                )
+            }
+        }
+        require!(
+            (1..=99).contains(&self.move_success_probability),
+            BlockrunnersError::InvalidMoveSuccessProbability
+        );
+        require!(
+            self.move_timeout_slots > 0,
+            BlockrunnersError::InvalidMoveTimeout
+        );
+        require!(
+            u16::from(self.prize_pool_percentage) + u16::from(self.admin_percentage) == 100,
+            BlockrunnersError::InvalidRevenueSplit
+        );
+        require!(
+            (1..=MAX_FEED_EVENTS).contains(&self.max_feed_events),
+            BlockrunnersError::InvalidFeedCapacity
+        );
+        require!(
+            self.starting_hand_tiers[0].min_prize_pool == 0
+                && self
+                    .starting_hand_tiers
+                    .windows(2)
+                    .all(|tiers| tiers[0].min_prize_pool <= tiers[1].min_prize_pool),
+            BlockrunnersError::InvalidStartingHandTiers
+        );
+        if let StepPriceCurve::Tiered {
+            tier_length,
+            prices,
+        } = self.step_price_curve
+        {
+            require!(
+                tier_length > 0 && prices.iter().all(|&price| price > 0),
+                BlockrunnersError::InvalidStepPriceCurve
+            );
+        }
+        if let CipherCarryOver::Refund { refund_percentage } = self.cipher_carry_over {
+            require!(
+                refund_percentage <= self.admin_percentage,
+                BlockrunnersError::InvalidCipherCarryOver
+            );
+        }
+        require!(
+            self.redeem_percentage <= self.admin_percentage,
+            BlockrunnersError::InvalidRedeemPercentage
+        );
+
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GameConfig {
+    /// Parameters the game is currently played with
+    pub params: GameConfigParams,
+
+    /// Store bump to save compute
+    pub bump: u8,
+}
+
+impl GameConfig {
+    /// Validate and apply new parameters
+    pub fn set_params(&mut self, params: GameConfigParams) -> Result<()> {
+        params.validate()?;
+        self.params = params;
+
+        Ok(())
+    }
+
+    /// Get the base price in ciphers of the step taken from `position`
+    pub fn step_price(&self, position: u8) -> u64 {
+        self.params.rules().step_price(position)
+    }
+
+    /// Get the path length of the next game from the previous path length, the prize paid out
+    /// and how many seconds the previous game lasted
+    pub fn next_path_length(&self, previous_length: u8, prize_amount: u64, duration: i64) -> u8 {
+        self.params
+            .path_rules()
+            .next_path_length(previous_length, prize_amount, duration)
+    }
+
+    /// Get the starting hand for the given prize pool size
+    pub fn starting_hand(&self, prize_pool: u64) -> CardCounts {
+        self.params.rules().starting_hand(prize_pool)
+    }
+}
+
+#[event]
+pub struct GameConfigChanged {
+    pub authority: Pubkey,
+
+    pub old_params: GameConfigParams,
+
+    pub new_params: GameConfigParams,
+
+    pub timestamp: i64,
+}
@@ -1,22 +1,108 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::MAX_FEED_EVENTS, state::SocialFeedEvent};
-
 #[account]
 #[derive(InitSpace)]
 pub struct GameState {
+    /// Identifier of this game instance, part of the PDA seeds
+    pub game_id: u64,
+
     /// Authority
     pub authority: Pubkey,
 
-    /// The current prize pool amount in lamports
+    /// Key proposed with `propose_authority`, becomes the authority once it signs `accept_authority`
+    pub pending_authority: Option<Pubkey>,
+
+    /// Account receiving the admin share of cipher purchases
+    pub fee_recipient: Pubkey,
+
+    /// Player actions the authority halted with `set_pause`
+    pub paused: PauseFlags,
+
+    /// The current prize pool amount in lamports, held by the prize vault
     pub prize_pool: u64,
 
+    /// Bump of the prize vault PDA, used to sign payouts
+    pub prize_vault_bump: u8,
+
+    /// Bump of the reserve vault PDA, used to sign refunds
+    pub reserve_vault_bump: u8,
+
     /// The length of the path players need to navigate
     pub path_length: u8,
 
     /// The Unix timestamp when the current game started
     pub start: i64,
 
-    #[max_len(MAX_FEED_EVENTS)]
-    pub game_events: Vec<SocialFeedEvent>,
+    /// Seed of the hidden left/right path for the current game
+    pub path_seed: [u8; 32],
+
+    /// Sequence number of the next game feed event, also the number of events ever announced
+    pub feed_sequence: u64,
+
+    /// Index of the feed page the game feed is written to
+    pub feed_page: u64,
+
+    /// Hash of the secret the local oracle reveals next
+    pub oracle_commitment: Option<[u8; 32]>,
+
+    /// The last secret revealed by the local oracle
+    pub oracle_reveal: Option<OracleReveal>,
+}
+
+/// Player actions that can be halted, e.g. while a bug or randomness issue is investigated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, InitSpace)]
+pub struct PauseFlags {
+    pub purchases: bool,
+
+    pub commits: bool,
+
+    /// Committed moves can't be revealed, their owners can cancel them for free instead
+    pub reveals: bool,
+
+    pub joins: bool,
+}
+
+impl PauseFlags {
+    /// Whether any player action is halted
+    pub fn is_paused(&self) -> bool {
+        self.purchases || self.commits || self.reveals || self.joins
+    }
+
+    /// Whether new moves can't be committed. Paused reveals also block commits, otherwise
+    /// players could commit, see the randomness and cancel bad moves for free.
+    pub fn blocks_commits(&self) -> bool {
+        self.commits || self.reveals
+    }
+}
+
+/// A local oracle secret together with the commitment it opens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct OracleReveal {
+    pub commitment: [u8; 32],
+
+    pub secret: [u8; 32],
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub game_id: u64,
+
+    pub old_authority: Pubkey,
+
+    pub new_authority: Pubkey,
+
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeRecipientChanged {
+    pub game_id: u64,
+
+    pub authority: Pubkey,
+
+    pub old_fee_recipient: Pubkey,
+
+    pub new_fee_recipient: Pubkey,
+
+    pub timestamp: i64,
 }
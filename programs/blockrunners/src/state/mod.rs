@@ -1,7 +1,19 @@
+pub mod feed_page;
+pub mod game_config;
 pub mod game_state;
+pub mod player_archive;
+pub mod player_feed;
 pub mod player_state;
+pub mod rules;
+pub mod scripted_randomness;
 pub mod social_feed_event;
 
+pub use feed_page::*;
+pub use game_config::*;
 pub use game_state::*;
+pub use player_archive::*;
+pub use player_feed::*;
 pub use player_state::*;
+pub use rules::*;
+pub use scripted_randomness::*;
 pub use social_feed_event::*;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PlayerState;
+
+/// Lifetime statistics of the player accounts a player closed, kept for leaderboards and
+/// achievements after the much larger `PlayerState` is gone
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerArchive {
+    pub player: Pubkey,
+
+    /// Store bump to save compute
+    pub bump: u8,
+
+    /// Number of player accounts merged into this archive
+    pub accounts_closed: u32,
+
+    pub first_login: i64,
+    pub last_login: i64,
+    pub games_won: u64,
+    pub total_ciphers_bought: u64,
+    pub best_position: u8,
+    pub total_resets: u64,
+    pub total_steps: u64,
+    pub cards_collected: u64,
+    pub shields_used: u64,
+    pub system_breaches: u64,
+    pub best_win_streak: u64,
+
+    pub ghost_protocol: bool,
+    pub data_hoarder: bool,
+    pub consensus_breaker: bool,
+    pub cipher_lord: bool,
+}
+
+impl PlayerArchive {
+    /// Add the statistics of a closing player account, totals add up and bests are kept
+    pub fn record(&mut self, player_state: &PlayerState) {
+        self.first_login = if self.accounts_closed == 0 {
+            player_state.first_login
+        } else {
+            self.first_login.min(player_state.first_login)
+        };
+        self.last_login = self.last_login.max(player_state.last_login);
+        self.accounts_closed = self.accounts_closed.saturating_add(1);
+
+        self.games_won = self.games_won.saturating_add(player_state.games_won);
+        self.total_ciphers_bought = self
+            .total_ciphers_bought
+            .saturating_add(player_state.total_ciphers_bought);
+        self.best_position = self.best_position.max(player_state.best_position);
+        self.total_resets = self.total_resets.saturating_add(player_state.total_resets);
+        self.total_steps = self.total_steps.saturating_add(player_state.total_steps);
+        self.cards_collected = self
+            .cards_collected
+            .saturating_add(player_state.cards_collected);
+        self.shields_used = self.shields_used.saturating_add(player_state.shields_used);
+        self.system_breaches = self
+            .system_breaches
+            .saturating_add(player_state.system_breaches);
+        self.best_win_streak = self.best_win_streak.max(player_state.best_win_streak);
+
+        self.ghost_protocol |= player_state.ghost_protocol;
+        self.data_hoarder |= player_state.data_hoarder;
+        self.consensus_breaker |= player_state.consensus_breaker;
+        self.cipher_lord |= player_state.cipher_lord;
+    }
+}
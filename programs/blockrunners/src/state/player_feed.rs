@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{FEED_SLOT_SIZE, MAX_FEED_EVENTS},
+    state::{FeedEntry, SocialFeedEvent},
+};
+
+// Every entry has to fit into a slot
+const _: () = assert!(FeedEntry::INIT_SPACE <= FEED_SLOT_SIZE);
+
+/// A feed entry, Borsh-encoded into a fixed number of bytes
+#[zero_copy]
+pub struct FeedSlot {
+    pub data: [u8; FEED_SLOT_SIZE],
+}
+
+/// The latest events of a player in a fixed-capacity ring buffer. The account is read and
+/// written in place, and once the feed is full new events overwrite the oldest one.
+#[account(zero_copy)]
+pub struct PlayerFeed {
+    pub player: Pubkey,
+
+    /// Sequence number of the next event, which is also the number of events ever recorded
+    pub next_sequence: u64,
+
+    /// Number of slots in use
+    pub len: u8,
+
+    /// Index of the oldest entry once the feed is full, overwritten by the next event
+    pub head: u8,
+
+    /// Store bump to save compute
+    pub bump: u8,
+
+    pub padding: [u8; 5],
+
+    pub slots: [FeedSlot; MAX_FEED_EVENTS as usize],
+}
+
+impl Default for PlayerFeed {
+    fn default() -> Self {
+        Self {
+            player: Pubkey::default(),
+            next_sequence: 0,
+            len: 0,
+            head: 0,
+            bump: 0,
+            padding: [0; 5],
+            slots: [FeedSlot {
+                data: [0; FEED_SLOT_SIZE],
+            }; MAX_FEED_EVENTS as usize],
+        }
+    }
+}
+
+impl PlayerFeed {
+    /// Record `event`, keeping at most `capacity` events
+    pub fn push(&mut self, event: SocialFeedEvent, capacity: u8) -> Result<()> {
+        let capacity = usize::from(capacity).clamp(1, self.slots.len());
+        let entry = FeedEntry {
+            sequence: self.next_sequence,
+            event,
+        };
+        self.next_sequence = self.next_sequence.saturating_add(1);
+
+        let len = usize::from(self.len);
+        let index = if len == capacity {
+            let head = usize::from(self.head);
+            self.head = ((head + 1) % capacity) as u8;
+            head
+        } else {
+            // Not full yet, unless the capacity changed since the feed filled up. Then the slots
+            // are put back in order once, dropping the oldest ones if the feed got smaller.
+            self.slots[..len].rotate_left(usize::from(self.head));
+            self.head = 0;
+            let kept = len.min(capacity - 1);
+            self.slots[..len].rotate_left(len - kept);
+            self.len = (kept + 1) as u8;
+            kept
+        };
+
+        entry
+            .serialize(&mut &mut self.slots[index].data[..])
+            .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+        Ok(())
+    }
+
+    /// Decode the entries from the oldest to the newest
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = FeedEntry> + '_ {
+        let (newest, oldest) = self.slots[..usize::from(self.len)].split_at(usize::from(self.head));
+        oldest
+            .iter()
+            .chain(newest)
+            .filter_map(|slot| FeedEntry::deserialize(&mut &slot.data[..]).ok())
+    }
+
+    /// Decode the events from the oldest to the newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = SocialFeedEvent> + '_ {
+        self.entries().map(|entry| entry.event)
+    }
+
+    /// Get the entries from sequence number `sequence` on, the ones still in the feed
+    pub fn since(&self, sequence: u64) -> impl DoubleEndedIterator<Item = FeedEntry> + '_ {
+        self.entries()
+            .filter(move |entry| entry.sequence >= sequence)
+    }
+
+    /// Get the newest event
+    pub fn last(&self) -> Option<SocialFeedEvent> {
+        self.iter().next_back()
+    }
+
+    pub fn len(&self) -> usize {
+        usize::from(self.len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
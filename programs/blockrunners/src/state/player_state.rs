@@ -1,136 +1,21 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{MAX_FEED_EVENTS, MAX_RANDOMNESS_VALUES},
-    state::SocialFeedEvent,
+    constants::MAX_RANDOMNESS_VALUES,
+    state::{CardCounts, CardUsage, GameState, PathDirection, RandomnessProvider},
 };
 
-#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Hash, Eq)]
-pub enum Card {
-    Shield,
-    Doubler,
-    Swift,
-}
-
-impl Card {
-    pub const COUNT: u8 = 3;
-}
-
-impl Space for Card {
-    const INIT_SPACE: usize = 1;
-}
-
-#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
-pub enum PathDirection {
-    Left,
-    Right,
-}
-
-impl Space for PathDirection {
-    const INIT_SPACE: usize = 1;
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
-pub struct CardUsage {
-    pub shield: bool,
-    pub doubler: bool,
-    pub swift: bool,
-}
-
-impl Space for CardUsage {
-    const INIT_SPACE: usize = 3;
-}
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
-pub struct CardCounts {
-    pub shield: u8,
-    pub doubler: u8,
-    pub swift: u8,
-}
-
-impl Default for CardCounts {
-    fn default() -> Self {
-        Self {
-            shield: 1,
-            doubler: 1,
-            swift: 1,
-        }
-    }
-}
-
-impl Space for CardCounts {
-    const INIT_SPACE: usize = 3;
-}
-
-impl CardCounts {
-    /// Get the count for a specific card type
-    pub fn get_count(&self, card: Card) -> u8 {
-        match card {
-            Card::Shield => self.shield,
-            Card::Doubler => self.doubler,
-            Card::Swift => self.swift,
-        }
-    }
-
-    /// Add a card to the collection, returns true if successful (not at max)
-    pub fn add_card(&mut self, card: Card) -> bool {
-        match card {
-            Card::Shield => {
-                let old_count = self.shield;
-                self.shield = self.shield.saturating_add(1);
-                old_count < u8::MAX
-            }
-            Card::Doubler => {
-                let old_count = self.doubler;
-                self.doubler = self.doubler.saturating_add(1);
-                old_count < u8::MAX
-            }
-            Card::Swift => {
-                let old_count = self.swift;
-                self.swift = self.swift.saturating_add(1);
-                old_count < u8::MAX
-            }
-        }
-    }
-
-    /// Remove a card from the collection, returns true if successful
-    pub fn remove_card(&mut self, card: Card) -> bool {
-        match card {
-            Card::Shield => {
-                let old_count = self.shield;
-                self.shield = self.shield.saturating_sub(1);
-                old_count > 0
-            }
-            Card::Doubler => {
-                let old_count = self.doubler;
-                self.doubler = self.doubler.saturating_sub(1);
-                old_count > 0
-            }
-            Card::Swift => {
-                let old_count = self.swift;
-                self.swift = self.swift.saturating_sub(1);
-                old_count > 0
-            }
-        }
-    }
-
-    /// Get the total number of cards
-    pub fn total_cards(&self) -> u16 {
-        self.shield as u16 + self.doubler as u16 + self.swift as u16
-    }
-
-    /// Check if the player has a specific card
-    pub fn has_card(&self, card: Card) -> bool {
-        self.get_count(card) > 0
-    }
-}
-
 #[account]
 #[derive(InitSpace)]
 pub struct PlayerState {
     /// Number of ciphers owned
     pub ciphers: u64,
 
+    /// Average price in lamports the owned ciphers were bought at, rounded down. Redemptions and
+    /// refunds are paid from it, so they never exceed what was paid into the game, whatever the
+    /// cipher cost is now.
+    pub cipher_price: u64,
+
     /// Cards owned - using counts instead of Vec for efficiency
     pub cards: CardCounts,
 
@@ -140,10 +25,6 @@ pub struct PlayerState {
     /// Store bump to save compute
     pub bump: u8,
 
-    /// Social feed events history
-    #[max_len(MAX_FEED_EVENTS)]
-    pub player_events: Vec<SocialFeedEvent>,
-
     /// Player statistics
     pub first_login: i64,
     pub last_login: i64,
@@ -166,6 +47,9 @@ pub struct PlayerState {
     pub consensus_breaker: bool, // Reached 90%+ of path length
     pub cipher_lord: bool,       // Purchased 1000+ ciphers total
 
+    /// The id of the game this player is part of
+    pub game_id: Option<u64>,
+
     /// The Unix timestamp of the game instance this player is part of
     pub game_start: Option<i64>,
 
@@ -175,6 +59,12 @@ pub struct PlayerState {
     /// The slot at which the randomness was committed
     pub randomness_slot: Option<u64>,
 
+    /// The randomness provider the move was committed with
+    pub randomness_provider: Option<RandomnessProvider>,
+
+    /// The local oracle commitment the move is bound to
+    pub randomness_commitment: Option<[u8; 32]>,
+
     /// The randomness values generated for the player
     #[max_len(MAX_RANDOMNESS_VALUES)]
     pub randomness_value: Option<Vec<u8>>,
@@ -184,4 +74,58 @@ pub struct PlayerState {
 
     /// Commitment to use cards
     pub move_cards: Option<CardUsage>,
+
+    /// Ciphers paid when the move was committed
+    pub move_cost: u64,
+}
+
+impl PlayerState {
+    /// Check if the player has joined the current instance of the given game
+    pub fn is_in_game(&self, game_state: &GameState) -> bool {
+        self.game_id == Some(game_state.game_id) && self.game_start == Some(game_state.start)
+    }
+
+    /// Check if the pending move was committed more than `timeout_slots` slots ago
+    pub fn is_move_expired(&self, current_slot: u64, timeout_slots: u64) -> bool {
+        self.randomness_slot
+            .is_some_and(|slot| current_slot > slot.saturating_add(timeout_slots))
+    }
+
+    /// Add `amount` ciphers bought for `cost` lamports, averaging their price with the owned ones
+    pub fn buy_ciphers(&mut self, amount: u64, cost: u64) -> Option<()> {
+        let ciphers = self.ciphers.checked_add(amount)?;
+        let paid = u128::from(self.ciphers) * u128::from(self.cipher_price) + u128::from(cost);
+        self.cipher_price = u64::try_from(paid / u128::from(ciphers)).ok()?;
+        self.ciphers = ciphers;
+        Some(())
+    }
+
+    /// Get `percentage` percent of the price paid for `amount` of the owned ciphers
+    pub fn ciphers_value(&self, amount: u64, percentage: u8) -> Option<u64> {
+        let value = u128::from(amount) * u128::from(self.cipher_price) * u128::from(percentage);
+        u64::try_from(value / 100).ok()
+    }
+
+    /// Give back the ciphers and cards paid for the committed move
+    pub fn refund_move(&mut self) {
+        self.ciphers = self.ciphers.saturating_add(self.move_cost);
+        if let Some(move_cards) = self.move_cards {
+            for card in move_cards.cards() {
+                self.cards.add_card(card);
+            }
+        }
+        self.move_cost = 0;
+    }
+
+    /// Reset the move & cards commitment
+    pub fn clear_move_commitment(&mut self) {
+        self.move_direction = None;
+        self.move_cards = None;
+        self.move_cost = 0;
+        self.randomness_account = None;
+        self.randomness_slot = None;
+        self.randomness_provider = None;
+        self.randomness_commitment = None;
+        self.randomness_value = None;
+    }
 }
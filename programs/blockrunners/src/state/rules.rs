@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use blockrunners_rules as rules;
+
+use crate::{
+    errors::BlockrunnersError,
+    state::{FeedPayload, GameConfigParams, GameState, PlayerState},
+};
+
+// The rules' types are stored in the accounts as they are
+pub use rules::{
+    Achievement, Card, CardCounts, CardUsage, PathDirection, PathLengthRule, StartingHandTier,
+    StepPriceCurve,
+};
+
+impl From<rules::RuleEvent> for FeedPayload {
+    fn from(event: rules::RuleEvent) -> Self {
+        match event {
+            rules::RuleEvent::PersonalBest { position } => FeedPayload::PersonalBest { position },
+            rules::RuleEvent::MilestoneReached { percentage } => {
+                FeedPayload::MilestoneReached { percentage }
+            }
+            rules::RuleEvent::StepPriceChanged { previous, next } => {
+                FeedPayload::StepPriceChanged { previous, next }
+            }
+            rules::RuleEvent::CardsCollected { cards } => FeedPayload::CardsCollected { cards },
+            rules::RuleEvent::CardsRejected { count } => FeedPayload::CardsRejected { count },
+            rules::RuleEvent::Advanced {
+                position,
+                card_usage,
+            } => FeedPayload::Advanced {
+                position,
+                card_usage,
+            },
+            rules::RuleEvent::ShieldSaved {
+                position,
+                card_usage,
+            } => FeedPayload::ShieldSaved {
+                position,
+                card_usage,
+            },
+            rules::RuleEvent::Reset {
+                from_position,
+                card_usage,
+            } => FeedPayload::Reset {
+                from_position,
+                card_usage,
+            },
+            rules::RuleEvent::StartingHandDealt { cards, prize_pool } => {
+                FeedPayload::StartingHandDealt { cards, prize_pool }
+            }
+            rules::RuleEvent::WinStreakRecord { streak } => FeedPayload::WinStreakRecord { streak },
+            rules::RuleEvent::SystemIntrusion { percentage } => {
+                FeedPayload::SystemIntrusion { percentage }
+            }
+            rules::RuleEvent::AchievementUnlocked { achievement } => {
+                FeedPayload::AchievementUnlocked { achievement }
+            }
+        }
+    }
+}
+
+impl GameConfigParams {
+    /// Get the rules moves are played by
+    pub fn rules(&self) -> rules::Rules {
+        rules::Rules {
+            base_move_cost: self.base_move_cost,
+            card_cost: self.card_cost,
+            swift_discount: self.swift_discount,
+            step_price_curve: self.step_price_curve,
+            move_success_probability: self.move_success_probability,
+            starting_hand_tiers: self.starting_hand_tiers,
+        }
+    }
+
+    /// Get the rules deciding the path length of new games
+    pub fn path_rules(&self) -> rules::PathRules {
+        rules::PathRules {
+            path_length: self.path_length,
+            rule: self.path_length_rule,
+            min_path_length: self.min_path_length,
+            max_path_length: self.max_path_length,
+        }
+    }
+}
+
+impl GameState {
+    /// Get the part of the game the rules read
+    pub fn game_view(&self) -> rules::GameView {
+        rules::GameView {
+            path_seed: self.path_seed,
+            path_length: self.path_length,
+            prize_pool: self.prize_pool,
+        }
+    }
+}
+
+impl PlayerState {
+    /// Get the part of the player's state the rules read and change
+    pub fn runner(&self) -> rules::Runner {
+        rules::Runner {
+            ciphers: self.ciphers,
+            cards: self.cards,
+            position: self.position,
+            games_won: self.games_won,
+            total_ciphers_bought: self.total_ciphers_bought,
+            best_position: self.best_position,
+            total_resets: self.total_resets,
+            total_steps: self.total_steps,
+            cards_collected: self.cards_collected,
+            shields_used: self.shields_used,
+            system_breaches: self.system_breaches,
+            consecutive_wins: self.consecutive_wins,
+            best_win_streak: self.best_win_streak,
+            ghost_protocol: self.ghost_protocol,
+            data_hoarder: self.data_hoarder,
+            consensus_breaker: self.consensus_breaker,
+            cipher_lord: self.cipher_lord,
+        }
+    }
+
+    /// Store the runner changed by the rules
+    pub fn apply_runner(&mut self, runner: &rules::Runner) {
+        self.ciphers = runner.ciphers;
+        self.cards = runner.cards;
+        self.position = runner.position;
+        self.games_won = runner.games_won;
+        self.total_ciphers_bought = runner.total_ciphers_bought;
+        self.best_position = runner.best_position;
+        self.total_resets = runner.total_resets;
+        self.total_steps = runner.total_steps;
+        self.cards_collected = runner.cards_collected;
+        self.shields_used = runner.shields_used;
+        self.system_breaches = runner.system_breaches;
+        self.consecutive_wins = runner.consecutive_wins;
+        self.best_win_streak = runner.best_win_streak;
+        self.ghost_protocol = runner.ghost_protocol;
+        self.data_hoarder = runner.data_hoarder;
+        self.consensus_breaker = runner.consensus_breaker;
+        self.cipher_lord = runner.cipher_lord;
+    }
+
+    /// Get the revealed randomness as a stream of bytes
+    pub fn randomness_stream(&self) -> Result<rules::RandomnessStream> {
+        let values: [u8; 32] = self
+            .randomness_value
+            .as_deref()
+            .and_then(|values| values.try_into().ok())
+            .ok_or(BlockrunnersError::RandomnessNotResolved)?;
+
+        Ok(rules::RandomnessStream::new(values))
+    }
+}
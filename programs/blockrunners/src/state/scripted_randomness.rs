@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SCRIPTED_RANDOMNESS;
+
+/// Randomness values queued by tests, used by `move_reveal` in test mode instead of the
+/// revealed randomness, one value per move
+#[account]
+#[derive(InitSpace)]
+pub struct ScriptedRandomness {
+    #[max_len(MAX_SCRIPTED_RANDOMNESS)]
+    pub values: Vec<[u8; 32]>,
+
+    /// Store bump to save compute
+    pub bump: u8,
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+use crate::{
+    constants::{FEED_EVENT_VERSION, MAX_CARDS_PER_STEP},
+    state::{Achievement, Card, CardCounts, CardUsage, PauseFlags},
+};
+
+#[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, Debug, PartialEq)]
 pub enum SocialFeedEventType {
     CardUsed,
     CiphersPurchased,
@@ -19,19 +24,184 @@ pub enum SocialFeedEventType {
     DataBreach,
     SystemIntrusion,
     ProtocolFragment,
+    SystemLockdown,
+    CiphersSettled,
+    CiphersRedeemed,
 }
 
 impl Space for SocialFeedEventType {
     const INIT_SPACE: usize = 1;
 }
 
+/// What happened, with the values clients need to render the message in their own words.
+///
+/// New variants are only ever appended, so clients keep decoding the events they know.
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, Debug, PartialEq, InitSpace)]
+pub enum FeedPayload {
+    PlayerJoined {
+        player: Pubkey,
+    },
+    CiphersPurchased {
+        amount: u64,
+        cost: u64,
+        ciphers: u64,
+    },
+    PrizePoolSurge {
+        increase_percentage: u64,
+        prize_pool: u64,
+    },
+    Advanced {
+        position: u8,
+        card_usage: CardUsage,
+    },
+    ShieldSaved {
+        position: u8,
+        card_usage: CardUsage,
+    },
+    Reset {
+        from_position: u8,
+        card_usage: CardUsage,
+    },
+    CardsCollected {
+        #[max_len(MAX_CARDS_PER_STEP)]
+        cards: Vec<Card>,
+    },
+    CardsRejected {
+        count: u8,
+    },
+    StartingHandDealt {
+        cards: CardCounts,
+        prize_pool: u64,
+    },
+    PersonalBest {
+        position: u8,
+    },
+    WinStreakRecord {
+        streak: u64,
+    },
+    MilestoneReached {
+        percentage: u8,
+    },
+    SystemIntrusion {
+        percentage: u8,
+    },
+    StepPriceChanged {
+        previous: u64,
+        next: u64,
+    },
+    AchievementUnlocked {
+        achievement: Achievement,
+    },
+    /// The winner is about to receive the prize pool
+    PrizeDistributing,
+    GameWon {
+        player: Pubkey,
+        steps: u8,
+        prize: u64,
+    },
+    /// The winner's own record of the win
+    MissionComplete {
+        streak: u64,
+        prize: u64,
+    },
+    PathLengthChanged {
+        previous: u8,
+        next: u8,
+    },
+    MoveTimedOut {
+        position: u8,
+    },
+    MoveCancelledInLockdown {
+        position: u8,
+    },
+    /// The actions the authority halted, none of them once the game resumes
+    PauseChanged {
+        paused: PauseFlags,
+    },
+    CiphersCarriedOver {
+        ciphers: u64,
+    },
+    CiphersForfeited {
+        ciphers: u64,
+    },
+    /// `refund` is less than `owed` when the reserve ran dry
+    CiphersRefunded {
+        ciphers: u64,
+        refund: u64,
+        owed: u64,
+    },
+    CiphersRedeemed {
+        amount: u64,
+        payout: u64,
+        ciphers: u64,
+    },
+}
+
+impl FeedPayload {
+    /// Get the category of the event, which clients can filter the feed by
+    pub fn event_type(&self) -> SocialFeedEventType {
+        match self {
+            FeedPayload::PlayerJoined { .. } => SocialFeedEventType::PlayerJoined,
+            FeedPayload::CiphersPurchased { .. } => SocialFeedEventType::CiphersPurchased,
+            FeedPayload::PrizePoolSurge { .. } => SocialFeedEventType::PrizePoolChange,
+            FeedPayload::Advanced { .. }
+            | FeedPayload::ShieldSaved { .. }
+            | FeedPayload::MoveTimedOut { .. }
+            | FeedPayload::MoveCancelledInLockdown { .. } => SocialFeedEventType::PlayerMoved,
+            FeedPayload::Reset { .. } => SocialFeedEventType::ResetAlert,
+            FeedPayload::CardsCollected { .. } | FeedPayload::StartingHandDealt { .. } => {
+                SocialFeedEventType::PlayerCardCollected
+            }
+            FeedPayload::CardsRejected { .. } => SocialFeedEventType::PlayerCardsMaxRange,
+            FeedPayload::PersonalBest { .. } | FeedPayload::WinStreakRecord { .. } => {
+                SocialFeedEventType::PersonalBest
+            }
+            FeedPayload::MilestoneReached { .. } => SocialFeedEventType::MilestoneReached,
+            FeedPayload::SystemIntrusion { .. } => SocialFeedEventType::SystemIntrusion,
+            FeedPayload::StepPriceChanged { .. } => SocialFeedEventType::StepPriceChange,
+            FeedPayload::AchievementUnlocked { .. } => SocialFeedEventType::ProtocolFragment,
+            FeedPayload::PrizeDistributing
+            | FeedPayload::GameWon { .. }
+            | FeedPayload::MissionComplete { .. } => SocialFeedEventType::GameWon,
+            FeedPayload::PathLengthChanged { .. } => SocialFeedEventType::PathLengthChange,
+            FeedPayload::PauseChanged { .. } => SocialFeedEventType::SystemLockdown,
+            FeedPayload::CiphersCarriedOver { .. }
+            | FeedPayload::CiphersForfeited { .. }
+            | FeedPayload::CiphersRefunded { .. } => SocialFeedEventType::CiphersSettled,
+            FeedPayload::CiphersRedeemed { .. } => SocialFeedEventType::CiphersRedeemed,
+        }
+    }
+}
+
 #[event]
-#[derive(Clone, InitSpace)]
+#[derive(Clone, Debug, InitSpace)]
 pub struct SocialFeedEvent {
+    /// Layout of the event, `FEED_EVENT_VERSION` for the events written by this program
+    pub version: u8,
+
     pub event_type: SocialFeedEventType,
 
-    #[max_len(100)]
-    pub message: String,
+    pub payload: FeedPayload,
 
     pub timestamp: i64,
 }
+
+impl SocialFeedEvent {
+    pub fn new(payload: FeedPayload, timestamp: i64) -> Self {
+        Self {
+            version: FEED_EVENT_VERSION,
+            event_type: payload.event_type(),
+            payload,
+            timestamp,
+        }
+    }
+}
+
+/// A feed event together with its position in the feed's history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct FeedEntry {
+    /// Number of events recorded in the feed before this one
+    pub sequence: u64,
+
+    pub event: SocialFeedEvent,
+}
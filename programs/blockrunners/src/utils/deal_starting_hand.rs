@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{GameConfig, PlayerFeed, PlayerState},
+    utils::save_player_rule_events,
+};
+
+/// Replace the player's cards with the starting hand for the current prize pool size
+pub fn deal_starting_hand(
+    player_state: &mut PlayerState,
+    player_feed: &mut PlayerFeed,
+    game_config: &GameConfig,
+    prize_pool: u64,
+) -> Result<()> {
+    let mut runner = player_state.runner();
+    let mut events = Vec::new();
+    game_config
+        .params
+        .rules()
+        .deal_starting_hand(&mut runner, prize_pool, &mut events);
+    player_state.apply_runner(&runner);
+
+    save_player_rule_events(player_feed, game_config.params.max_feed_events, events)?;
+
+    Ok(())
+}
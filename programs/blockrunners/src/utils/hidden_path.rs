@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Derives the path seed for a new game.
+///
+/// The seed binds the hidden path to a single game instance. It is public, so on its own
+/// it does not reveal anything: every step is only decided once the player's committed
+/// randomness is revealed (see `blockrunners_rules::path_step`).
+#[cfg(not(feature = "test"))]
+pub fn generate_path_seed(
+    previous_seed: &[u8; 32],
+    game_id: u64,
+    start: i64,
+    entropy: &[u8],
+) -> Result<[u8; 32]> {
+    let clock = Clock::get()?;
+
+    Ok(hashv(&[
+        previous_seed,
+        &game_id.to_le_bytes(),
+        &start.to_le_bytes(),
+        &clock.slot.to_le_bytes(),
+        entropy,
+    ])
+    .to_bytes())
+}
+
+#[cfg(feature = "test")]
+#[allow(unused_variables)]
+pub fn generate_path_seed(
+    previous_seed: &[u8; 32],
+    game_id: u64,
+    start: i64,
+    entropy: &[u8],
+) -> Result<[u8; 32]> {
+    msg!("TEST MODE: Running generate_path_seed");
+
+    // A zeroed seed combined with the test randomness makes "Right" the correct direction on every step
+    Ok([0; 32])
+}
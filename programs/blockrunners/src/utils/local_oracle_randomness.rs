@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::{
+    errors::BlockrunnersError,
+    state::{GameState, PlayerState},
+};
+
+/// Bind the move to the secret the game's local oracle has committed to reveal next
+pub fn local_oracle_request(player_state: &mut PlayerState, game_state: &GameState) -> Result<()> {
+    let commitment = game_state
+        .oracle_commitment
+        .ok_or(BlockrunnersError::OracleCommitmentMissing)?;
+
+    player_state.randomness_commitment = Some(commitment);
+    player_state.randomness_slot = Some(Clock::get()?.slot);
+
+    Ok(())
+}
+
+/// Derive the move randomness from the secret opening the commitment the move is bound to
+pub fn local_oracle_reveal(
+    player_state: &PlayerState,
+    player_state_key: &Pubkey,
+    game_state: &GameState,
+) -> Result<[u8; 32]> {
+    let commitment = player_state
+        .randomness_commitment
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?;
+    let commit_slot = player_state
+        .randomness_slot
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?;
+
+    match game_state.oracle_reveal {
+        Some(oracle_reveal) if oracle_reveal.commitment == commitment => Ok(hashv(&[
+            &oracle_reveal.secret,
+            player_state_key.as_ref(),
+            &commit_slot.to_le_bytes(),
+        ])
+        .to_bytes()),
+        _ if game_state.oracle_commitment == Some(commitment) => {
+            Err(BlockrunnersError::RandomnessNotResolved.into())
+        }
+        // The oracle has moved past the secret the move was bound to
+        _ => Err(BlockrunnersError::RandomnessExpired.into()),
+    }
+}
+
+/// The secret is public once the oracle has moved on from the commitment the move is bound to
+pub fn local_oracle_is_revealed(
+    player_state: &PlayerState,
+    game_state: &GameState,
+) -> Result<bool> {
+    let commitment = player_state
+        .randomness_commitment
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?;
+
+    Ok(game_state.oracle_commitment != Some(commitment))
+}
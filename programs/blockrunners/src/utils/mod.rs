@@ -1,15 +1,41 @@
-pub mod achievement_tracker;
+pub mod deal_starting_hand;
 pub mod get_move_cost;
-pub mod give_random_cards;
+pub mod hidden_path;
+pub mod local_oracle_randomness;
+pub mod pay_for_move;
+pub mod prize_vault;
+pub mod randomness_is_revealed;
 pub mod randomness_request;
 pub mod randomness_reveal;
-pub mod randomness_use;
+pub mod reserve_vault;
 pub mod save_and_emit_event;
+pub mod save_and_emit_game_event;
+pub mod save_rule_events;
+#[cfg(feature = "test")]
+pub mod scripted_randomness;
+pub mod settle_unspent_ciphers;
+pub mod slot_hash_randomness;
+pub mod switchboard_randomness;
+pub mod transfer;
+pub mod verify_randomness_account;
 
-pub use achievement_tracker::*;
+pub use deal_starting_hand::*;
 pub use get_move_cost::*;
-pub use give_random_cards::*;
+pub use hidden_path::*;
+pub use local_oracle_randomness::*;
+pub use pay_for_move::*;
+pub use prize_vault::*;
+pub use randomness_is_revealed::*;
 pub use randomness_request::*;
 pub use randomness_reveal::*;
-pub use randomness_use::*;
+pub use reserve_vault::*;
 pub use save_and_emit_event::*;
+pub use save_and_emit_game_event::*;
+pub use save_rule_events::*;
+#[cfg(feature = "test")]
+pub use scripted_randomness::*;
+pub use settle_unspent_ciphers::*;
+pub use slot_hash_randomness::*;
+pub use switchboard_randomness::*;
+pub use transfer::*;
+pub use verify_randomness_account::*;
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::BlockrunnersError,
+    state::{CardUsage, GameConfig, PlayerState},
+};
+
+/// Charge the ciphers for the committed move and remove the used cards from the inventory
+pub fn pay_for_move(
+    player_state: &mut PlayerState,
+    game_config: &GameConfig,
+    used_cards: &CardUsage,
+) -> Result<()> {
+    let mut runner = player_state.runner();
+    game_config
+        .params
+        .rules()
+        .pay_for_move(&mut runner, used_cards)
+        .map_err(BlockrunnersError::from)?;
+    player_state.apply_runner(&runner);
+
+    Ok(())
+}
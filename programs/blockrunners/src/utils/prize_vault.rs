@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::BlockrunnersError, state::GameState};
+
+/// Checks the invariant that the prize vault holds exactly the prize pool plus its rent reserve.
+///
+/// Anybody can send lamports to a system account, so a surplus is treated as a donation and
+/// added to the prize pool instead of failing the instruction. A deficit is never expected and
+/// fails with `PrizeVaultMismatch`.
+pub fn verify_prize_vault(game_state: &mut GameState, prize_vault: &AccountInfo) -> Result<()> {
+    let rent_reserve = Rent::get()?.minimum_balance(0);
+    let expected_lamports = game_state
+        .prize_pool
+        .checked_add(rent_reserve)
+        .ok_or(BlockrunnersError::ArithmeticOverflow)?;
+    let vault_lamports = prize_vault.lamports();
+
+    require!(
+        vault_lamports >= expected_lamports,
+        BlockrunnersError::PrizeVaultMismatch
+    );
+
+    if vault_lamports > expected_lamports {
+        let donation = vault_lamports - expected_lamports;
+        game_state.prize_pool = game_state
+            .prize_pool
+            .checked_add(donation)
+            .ok_or(BlockrunnersError::ArithmeticOverflow)?;
+        msg!("Added {} donated lamports to the prize pool", donation);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{GameState, PlayerState, RandomnessProvider},
+    utils::{
+        local_oracle_is_revealed, slot_hash_is_revealed, switchboard_is_revealed,
+        verify_randomness_commitment,
+    },
+};
+
+/// Check if the value the pending move was committed to is already public, in which case the
+/// outcome of the move is known even if it can no longer be revealed
+pub fn randomness_is_revealed(
+    player_state: &PlayerState,
+    game_state: &GameState,
+    randomness_account: &AccountInfo,
+) -> Result<bool> {
+    match verify_randomness_commitment(player_state, randomness_account)? {
+        RandomnessProvider::Switchboard => {
+            switchboard_is_revealed(player_state, randomness_account)
+        }
+        RandomnessProvider::SlotHash => slot_hash_is_revealed(player_state),
+        RandomnessProvider::LocalOracle => local_oracle_is_revealed(player_state, game_state),
+    }
+}
@@ -1,55 +1,45 @@
-#![allow(unused_imports)]
+use anchor_lang::{prelude::*, solana_program::sysvar::slot_hashes};
 
-use anchor_lang::prelude::*;
-use switchboard_on_demand::RandomnessAccountData;
+use crate::{
+    errors::BlockrunnersError,
+    state::{GameConfig, GameState, PlayerState, RandomnessProvider},
+    utils::{local_oracle_request, slot_hash_request, switchboard_request},
+};
 
-use crate::errors::BlockrunnersError;
-use crate::state::PlayerState;
-
-#[cfg(not(feature = "test"))]
+/// Bind the committed move to randomness of the game's provider that nobody knows yet
 pub fn randomness_request(
     player_state: &mut Account<PlayerState>,
+    game_state: &Account<GameState>,
+    game_config: &GameConfig,
     randomness_account: &AccountInfo,
 ) -> Result<()> {
-    // TODO ??? verify the randomness account, eg. (randomness_account.owner == &get_sb_program_id(cluster) || randomness_account.owner == &SWITCHBOARD_PROGRAM_ID) && randomness_account.data_len() == RandomnessAccountData::size()
-
-    // Save randomness account to verify later
+    let randomness_provider = game_config.params.randomness_provider;
+    match randomness_provider {
+        RandomnessProvider::Switchboard => switchboard_request(player_state, randomness_account)?,
+        RandomnessProvider::SlotHash => {
+            require_keys_eq!(
+                randomness_account.key(),
+                slot_hashes::ID,
+                BlockrunnersError::InvalidRandomnessAccount
+            );
+            slot_hash_request(player_state)?
+        }
+        RandomnessProvider::LocalOracle => {
+            require_keys_eq!(
+                randomness_account.key(),
+                game_state.key(),
+                BlockrunnersError::InvalidRandomnessAccount
+            );
+            local_oracle_request(player_state, game_state)?
+        }
+    }
+
+    // Save randomness account and provider to verify later
     player_state.randomness_account = Some(randomness_account.key());
+    player_state.randomness_provider = Some(randomness_provider);
 
     // Reset the randomness values without removing the account reference
     player_state.randomness_value = None;
 
-    // Parse the randomness account data
-    let randomness_data = RandomnessAccountData::parse(randomness_account.data.borrow())
-        .map_err(|_| BlockrunnersError::RandomnessAccountParsing)?;
-
-    // TODO ??? Error if randomness_data.seed_slot != clock.slot - 1
-    let clock = Clock::get()?;
-    msg!("seed_slot: {}", randomness_data.seed_slot);
-    msg!("slot: {}", clock.slot);
-
-    // Store the seed slot when the randomness was committed
-    player_state.randomness_slot = Some(randomness_data.seed_slot);
-
-    msg!(
-        "Randomness request successful - slot: {}",
-        randomness_data.seed_slot
-    );
-
-    Ok(())
-}
-
-#[cfg(feature = "test")]
-#[allow(unused_variables)]
-pub fn randomness_request(
-    player_state: &mut Account<PlayerState>,
-    randomness_account: &AccountInfo,
-) -> Result<()> {
-    msg!("TEST MODE: Running randomness_request");
-
-    player_state.randomness_account = Some(randomness_account.key());
-    player_state.randomness_value = None;
-    player_state.randomness_slot = Some((Clock::get()?).slot - 1);
-
     Ok(())
 }
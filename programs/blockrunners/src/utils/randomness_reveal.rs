@@ -1,63 +1,41 @@
-#![allow(unused_imports)]
-
 use anchor_lang::prelude::*;
-use switchboard_on_demand::RandomnessAccountData;
 
-use crate::{errors::BlockrunnersError, state::PlayerState};
+use crate::{
+    errors::BlockrunnersError,
+    state::{GameState, PlayerState, RandomnessProvider},
+    utils::{local_oracle_reveal, slot_hash_reveal, switchboard_reveal},
+};
 
-#[cfg(not(feature = "test"))]
+/// Get the verified 32-byte value of the committed move from the provider it was committed with
 pub fn randomness_reveal(
     player_state: &mut Account<PlayerState>,
+    game_state: &GameState,
     randomness_account: &AccountInfo,
 ) -> Result<()> {
-    // Verify that the randomness account matches the one stored in player_state
-    if let Some(randomness_account_key) = player_state.randomness_account {
-        require_keys_eq!(
-            randomness_account.key(),
-            randomness_account_key,
-            BlockrunnersError::Unauthorized
-        );
-    } else {
-        // No randomness account stored, this is a state error
-        return Err(BlockrunnersError::RandomnessUnavailable.into());
-    }
-
-    // Parse the randomness account data
-    let randomness_data = RandomnessAccountData::parse(randomness_account.data.borrow())
-        .map_err(|_| BlockrunnersError::RandomnessAccountParsingReveal)?;
-
-    // Verify that the randomness slot matches what was stored at request time
-    require!(
-        Some(randomness_data.seed_slot) == player_state.randomness_slot,
-        BlockrunnersError::RandomnessExpired
-    );
+    let randomness_provider = verify_randomness_commitment(player_state, randomness_account)?;
+    let player_state_key = player_state.key();
 
-    let clock = Clock::get()?;
-
-    // Call the switchboard on-demand get_value function to get the revealed random value
     // TODO: Refactor not to store the randomness value in the player state
-    player_state.randomness_value = Some(
-        randomness_data
-            .get_value(clock.slot)
-            .map_err(|_| {
-                msg!("Randomness not yet resolved");
-                BlockrunnersError::RandomnessNotResolved
-            })?
-            .to_vec(),
-    );
+    let value = match randomness_provider {
+        RandomnessProvider::Switchboard => switchboard_reveal(player_state, randomness_account)?,
+        RandomnessProvider::SlotHash => {
+            slot_hash_reveal(player_state, &player_state_key, randomness_account)?
+        }
+        RandomnessProvider::LocalOracle => {
+            local_oracle_reveal(player_state, &player_state_key, game_state)?
+        }
+    };
+    player_state.randomness_value = Some(value.to_vec());
 
     Ok(())
 }
 
-#[cfg(feature = "test")]
-#[allow(unused_variables)]
-pub fn randomness_reveal(
-    player_state: &mut Account<PlayerState>,
+/// Verify that the randomness account matches the one stored in player_state and get the
+/// provider the move was committed with
+pub fn verify_randomness_commitment(
+    player_state: &PlayerState,
     randomness_account: &AccountInfo,
-) -> Result<()> {
-    msg!("TEST MODE: Running randomness_reveal");
-
-    // Verify that the randomness account matches the one stored in player_state
+) -> Result<RandomnessProvider> {
     if let Some(randomness_account_key) = player_state.randomness_account {
         require_keys_eq!(
             randomness_account.key(),
@@ -69,10 +47,7 @@ pub fn randomness_reveal(
         return Err(BlockrunnersError::RandomnessUnavailable.into());
     }
 
-    // All randomness values are 1 in test mode
-    // This means the move is always successful in test mode
-    // And the card given is always "Doubler"
-    player_state.randomness_value = Some(vec![1; 32]);
-
-    Ok(())
+    Ok(player_state
+        .randomness_provider
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?)
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::RESERVE_VAULT_SEED, state::GameState, utils::transfer};
+
+/// Get the lamports the reserve vault can pay out on top of its rent reserve
+pub fn reserve_available(reserve_vault: &AccountInfo) -> Result<u64> {
+    let rent_reserve = Rent::get()?.minimum_balance(0);
+    Ok(reserve_vault.lamports().saturating_sub(rent_reserve))
+}
+
+/// Pay `amount` lamports from the reserve vault, signed with the vault's PDA seeds
+pub fn pay_from_reserve<'a>(
+    game_state: &GameState,
+    system_program: AccountInfo<'a>,
+    reserve_vault: AccountInfo<'a>,
+    to: AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    let game_id_bytes = game_state.game_id.to_le_bytes();
+    let vault_seeds: &[&[u8]] = &[
+        RESERVE_VAULT_SEED,
+        &game_id_bytes,
+        &[game_state.reserve_vault_bump],
+    ];
+    transfer(
+        system_program,
+        reserve_vault,
+        to,
+        amount,
+        Some(&[vault_seeds]),
+    )
+}
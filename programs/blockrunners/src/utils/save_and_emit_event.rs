@@ -1,32 +1,20 @@
 use anchor_lang::prelude::*;
 
-use crate::{
-    constants::MAX_FEED_EVENTS, 
-    state::{SocialFeedEvent, SocialFeedEventType}
-};
+use crate::state::{FeedPayload, PlayerFeed, SocialFeedEvent};
 
 pub fn save_and_emit_event (
-    events: &mut Vec<SocialFeedEvent>,
-    event_type: SocialFeedEventType,
-    message: String,
+    events: &mut PlayerFeed,
+    max_events: u8,
+    payload: FeedPayload,
 ) -> Result<()> {
      let clock = Clock::get()?; // Get the current timestamp
 
-    // Limit the feed to the latest 20 events
-    if events.len() >= usize::from(MAX_FEED_EVENTS) {
-        events.remove(0); // Remove the oldest event
-    }
-
-    // Add the new event
-    let new_event = SocialFeedEvent {
-        event_type,
-        message,
-        timestamp: clock.unix_timestamp,
-    };
+    // Add the new event, the feed keeps the latest `max_events` events
+    let new_event = SocialFeedEvent::new(payload, clock.unix_timestamp);
 
     emit!(new_event);
 
-    events.push(new_event);
+    events.push(new_event, max_events)?;
     
     Ok(())
 }
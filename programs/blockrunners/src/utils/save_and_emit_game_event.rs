@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{FeedEntry, FeedPage, FeedPayload, GameState, SocialFeedEvent};
+
+/// Announce an event to the game feed, on the game's current feed page
+pub fn save_and_emit_game_event(
+    game_state: &mut GameState,
+    feed_page: &mut FeedPage,
+    payload: FeedPayload,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = SocialFeedEvent::new(payload, clock.unix_timestamp);
+
+    emit!(event);
+
+    feed_page.entries.push(FeedEntry {
+        sequence: game_state.feed_sequence,
+        event,
+    });
+    game_state.feed_sequence = game_state.feed_sequence.saturating_add(1);
+
+    // The rest of the instruction's events still go to this page, the next instruction opens
+    // the next one
+    if feed_page.is_full() {
+        game_state.feed_page = feed_page.page_index + 1;
+    }
+
+    Ok(())
+}
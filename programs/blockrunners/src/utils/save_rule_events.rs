@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use blockrunners_rules::RuleEvent;
+
+use crate::{
+    state::{FeedPage, GameState, PlayerFeed},
+    utils::{save_and_emit_event, save_and_emit_game_event},
+};
+
+/// Announce the events of the rules, global ones to the game feed and the rest to the player's
+pub fn save_rule_events(
+    player_feed: &mut PlayerFeed,
+    game_state: &mut GameState,
+    feed_page: &mut FeedPage,
+    max_feed_events: u8,
+    events: Vec<RuleEvent>,
+) -> Result<()> {
+    for event in events {
+        if event.is_global() {
+            save_and_emit_game_event(game_state, feed_page, event.into())?;
+        } else {
+            save_and_emit_event(player_feed, max_feed_events, event.into())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Announce events that only concern the player to the player's feed
+pub fn save_player_rule_events(
+    player_feed: &mut PlayerFeed,
+    max_feed_events: u8,
+    events: Vec<RuleEvent>,
+) -> Result<()> {
+    for event in events {
+        save_and_emit_event(player_feed, max_feed_events, event.into())?;
+    }
+
+    Ok(())
+}
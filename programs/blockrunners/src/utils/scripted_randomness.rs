@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SCRIPTED_RANDOMNESS_SEED,
+    state::{PlayerState, ScriptedRandomness},
+};
+
+/// Replace the revealed randomness with the next value queued by `debug_script_randomness`.
+///
+/// The queue is passed to `move_reveal` as a writable remaining account, so the instruction's
+/// accounts stay the same as in production. Without it the revealed randomness is kept.
+pub fn use_scripted_randomness(
+    player: &Pubkey,
+    player_state: &mut Account<PlayerState>,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    let (scripted_randomness_key, _) =
+        Pubkey::find_program_address(&[SCRIPTED_RANDOMNESS_SEED, player.as_ref()], &crate::ID);
+    let Some(account_info) = remaining_accounts
+        .iter()
+        .find(|account_info| account_info.key() == scripted_randomness_key)
+    else {
+        return Ok(());
+    };
+
+    let mut scripted_randomness =
+        ScriptedRandomness::try_deserialize(&mut &account_info.data.borrow()[..])?;
+    if scripted_randomness.values.is_empty() {
+        return Ok(());
+    }
+
+    let value = scripted_randomness.values.remove(0);
+    scripted_randomness.try_serialize(&mut &mut account_info.data.borrow_mut()[..])?;
+
+    msg!("TEST MODE: Using scripted randomness");
+    player_state.randomness_value = Some(value.to_vec());
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::BlockrunnersError,
+    state::{CipherCarryOver, FeedPayload, GameConfig, GameState, PlayerFeed, PlayerState},
+    utils::{pay_from_reserve, reserve_available, save_and_emit_event},
+};
+
+/// Applies the game's carry-over policy to the ciphers a player didn't spend in the previous
+/// instance of the game, and records the outcome in the player's feed.
+pub fn settle_unspent_ciphers<'a>(
+    player_state: &mut PlayerState,
+    player_feed: &mut PlayerFeed,
+    game_state: &GameState,
+    game_config: &GameConfig,
+    system_program: AccountInfo<'a>,
+    reserve_vault: AccountInfo<'a>,
+    player: AccountInfo<'a>,
+) -> Result<()> {
+    let ciphers = player_state.ciphers;
+    if ciphers == 0 {
+        return Ok(());
+    }
+
+    let payload = match game_config.params.cipher_carry_over {
+        CipherCarryOver::CarryOver => FeedPayload::CiphersCarriedOver { ciphers },
+        CipherCarryOver::Forfeit => {
+            player_state.ciphers = 0;
+            FeedPayload::CiphersForfeited { ciphers }
+        }
+        CipherCarryOver::Refund { refund_percentage } => {
+            let owed = player_state
+                .ciphers_value(ciphers, refund_percentage)
+                .ok_or(BlockrunnersError::ArithmeticOverflow)?;
+            let refund = owed.min(reserve_available(&reserve_vault)?);
+            if refund > 0 {
+                pay_from_reserve(game_state, system_program, reserve_vault, player, refund)?;
+            }
+            player_state.ciphers = 0;
+
+            FeedPayload::CiphersRefunded {
+                ciphers,
+                refund,
+                owed,
+            }
+        }
+    };
+
+    save_and_emit_event(player_feed, game_config.params.max_feed_events, payload)
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::{errors::BlockrunnersError, state::PlayerState};
+
+/// Size of the length prefix of the SlotHashes sysvar data
+const SLOT_HASHES_LEN_SIZE: usize = 8;
+
+/// Size of a (slot, hash) entry of the SlotHashes sysvar
+const SLOT_HASH_ENTRY_SIZE: usize = 40;
+
+/// Bind the move to the hash of the slot it's committed in, which is only known once the slot ends
+pub fn slot_hash_request(player_state: &mut PlayerState) -> Result<()> {
+    let clock = Clock::get()?;
+    player_state.randomness_slot = Some(clock.slot);
+
+    msg!("Slot hash randomness requested - slot: {}", clock.slot);
+
+    Ok(())
+}
+
+/// Derive the move randomness from the hash of the first slot produced since the commitment
+pub fn slot_hash_reveal(
+    player_state: &PlayerState,
+    player_state_key: &Pubkey,
+    slot_hashes: &AccountInfo,
+) -> Result<[u8; 32]> {
+    let commit_slot = player_state
+        .randomness_slot
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?;
+    let slot_hash = find_slot_hash(slot_hashes, commit_slot)?;
+
+    Ok(hashv(&[
+        &slot_hash,
+        player_state_key.as_ref(),
+        &commit_slot.to_le_bytes(),
+    ])
+    .to_bytes())
+}
+
+/// The slot hash is public as soon as the commit slot has ended
+pub fn slot_hash_is_revealed(player_state: &PlayerState) -> Result<bool> {
+    let commit_slot = player_state
+        .randomness_slot
+        .ok_or(BlockrunnersError::RandomnessUnavailable)?;
+
+    Ok(Clock::get()?.slot > commit_slot)
+}
+
+/// Find the hash of the oldest slot at or after `commit_slot` in the SlotHashes sysvar,
+/// whose entries are sorted from the newest to the oldest slot
+fn find_slot_hash(slot_hashes: &AccountInfo, commit_slot: u64) -> Result<[u8; 32]> {
+    let data = slot_hashes.data.borrow();
+    require!(
+        data.len() >= SLOT_HASHES_LEN_SIZE,
+        BlockrunnersError::SlotHashUnavailable
+    );
+    let mut len_bytes = [0u8; SLOT_HASHES_LEN_SIZE];
+    len_bytes.copy_from_slice(&data[..SLOT_HASHES_LEN_SIZE]);
+    let entries = data[SLOT_HASHES_LEN_SIZE..]
+        .chunks_exact(SLOT_HASH_ENTRY_SIZE)
+        .take(u64::from_le_bytes(len_bytes) as usize);
+
+    let mut slot_hash = None;
+    for entry in entries {
+        let mut slot_bytes = [0u8; 8];
+        slot_bytes.copy_from_slice(&entry[..8]);
+        let slot = u64::from_le_bytes(slot_bytes);
+
+        if slot < commit_slot {
+            // Everything older was produced before the commitment
+            return slot_hash.ok_or(BlockrunnersError::RandomnessNotResolved.into());
+        }
+
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        slot_hash = Some(hash);
+
+        if slot == commit_slot {
+            return Ok(hash);
+        }
+    }
+
+    match slot_hash {
+        // The commit slot is older than the slots kept by the sysvar, so the first slot after it
+        // can't be told apart anymore
+        Some(_) => Err(BlockrunnersError::SlotHashUnavailable.into()),
+        None => Err(BlockrunnersError::RandomnessNotResolved.into()),
+    }
+}
@@ -0,0 +1,128 @@
+#![allow(unused_imports)]
+
+use anchor_lang::prelude::*;
+use switchboard_on_demand::RandomnessAccountData;
+
+use crate::{errors::BlockrunnersError, state::PlayerState, utils::verify_randomness_account};
+
+/// Bind the move to a Switchboard randomness account committed in the previous slot
+#[cfg(not(feature = "test"))]
+pub fn switchboard_request(
+    player_state: &mut PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<()> {
+    // Only accept randomness accounts of the Switchboard program
+    verify_randomness_account(randomness_account)?;
+
+    // Parse the randomness account data
+    let randomness_data = RandomnessAccountData::parse(randomness_account.data.borrow())
+        .map_err(|_| BlockrunnersError::RandomnessAccountParsing)?;
+
+    // The randomness must have been committed in the previous slot, so it's fresh
+    let clock = Clock::get()?;
+    require!(
+        randomness_data.seed_slot == clock.slot.saturating_sub(1),
+        BlockrunnersError::RandomnessStale
+    );
+
+    // The value must still be unknown to the player when committing to a move
+    require!(
+        randomness_data.reveal_slot <= randomness_data.seed_slot,
+        BlockrunnersError::RandomnessAlreadyRevealed
+    );
+
+    // Store the seed slot when the randomness was committed
+    player_state.randomness_slot = Some(randomness_data.seed_slot);
+
+    msg!(
+        "Randomness request successful - slot: {}",
+        randomness_data.seed_slot
+    );
+
+    Ok(())
+}
+
+/// Get the value revealed by the Switchboard oracle in the current slot
+#[cfg(not(feature = "test"))]
+pub fn switchboard_reveal(
+    player_state: &PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<[u8; 32]> {
+    verify_randomness_account(randomness_account)?;
+
+    // Parse the randomness account data
+    let randomness_data = RandomnessAccountData::parse(randomness_account.data.borrow())
+        .map_err(|_| BlockrunnersError::RandomnessAccountParsingReveal)?;
+
+    // Verify that the randomness slot matches what was stored at request time
+    require!(
+        Some(randomness_data.seed_slot) == player_state.randomness_slot,
+        BlockrunnersError::RandomnessExpired
+    );
+
+    let clock = Clock::get()?;
+
+    // Call the switchboard on-demand get_value function to get the revealed random value
+    let value = randomness_data.get_value(clock.slot).map_err(|_| {
+        msg!("Randomness not yet resolved");
+        BlockrunnersError::RandomnessNotResolved
+    })?;
+
+    Ok(value)
+}
+
+/// Check if the oracle has already published the value the move was committed to
+#[cfg(not(feature = "test"))]
+pub fn switchboard_is_revealed(
+    player_state: &PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<bool> {
+    verify_randomness_account(randomness_account)?;
+
+    let randomness_data = RandomnessAccountData::parse(randomness_account.data.borrow())
+        .map_err(|_| BlockrunnersError::RandomnessAccountParsingReveal)?;
+
+    // A re-committed account will never reveal the value for our seed slot
+    Ok(
+        Some(randomness_data.seed_slot) == player_state.randomness_slot
+            && randomness_data.reveal_slot > randomness_data.seed_slot,
+    )
+}
+
+#[cfg(feature = "test")]
+#[allow(unused_variables)]
+pub fn switchboard_request(
+    player_state: &mut PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<()> {
+    msg!("TEST MODE: Running switchboard_request");
+
+    player_state.randomness_slot = Some((Clock::get()?).slot - 1);
+
+    Ok(())
+}
+
+#[cfg(feature = "test")]
+#[allow(unused_variables)]
+pub fn switchboard_reveal(
+    player_state: &PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<[u8; 32]> {
+    msg!("TEST MODE: Running switchboard_reveal");
+
+    // All randomness values are 1 in test mode
+    // This means the move is always successful in test mode
+    // And the card given is always "Doubler"
+    Ok([1; 32])
+}
+
+#[cfg(feature = "test")]
+#[allow(unused_variables)]
+pub fn switchboard_is_revealed(
+    player_state: &PlayerState,
+    randomness_account: &AccountInfo,
+) -> Result<bool> {
+    msg!("TEST MODE: Running switchboard_is_revealed");
+
+    Ok(false)
+}
@@ -0,0 +1,24 @@
+#![allow(unused_imports)]
+
+use anchor_lang::prelude::*;
+use switchboard_on_demand::{RandomnessAccountData, ON_DEMAND_DEVNET_PID, ON_DEMAND_MAINNET_PID};
+
+use crate::errors::BlockrunnersError;
+
+/// Verify that the account is a randomness account of the Switchboard on-demand program,
+/// so its data can't be forged by an attacker-controlled account
+#[cfg(not(feature = "test"))]
+pub fn verify_randomness_account(randomness_account: &AccountInfo) -> Result<()> {
+    let owner = randomness_account.owner.to_bytes();
+    require!(
+        owner == ON_DEMAND_MAINNET_PID.to_bytes() || owner == ON_DEMAND_DEVNET_PID.to_bytes(),
+        BlockrunnersError::InvalidRandomnessAccountOwner
+    );
+
+    require!(
+        randomness_account.data_len() == RandomnessAccountData::size(),
+        BlockrunnersError::InvalidRandomnessAccountSize
+    );
+
+    Ok(())
+}
@@ -5,16 +5,34 @@ import { Keypair } from "@solana/web3.js";
 // Shared admin keypair for all tests
 export const ADMIN_KEYPAIR = Keypair.generate();
 
+// Id of the game instance shared by all tests
+export const GAME_ID = new BN(0);
+
 // Number constants
 export const CIPHER_COST = new BN(getConstantOrThrow("cipherCost")).toNumber();
 export const INITIAL_PATH_LENGTH = Number(getConstantOrThrow("initialPathLength"));
+export const MIN_PATH_LENGTH = Number(getConstantOrThrow("minPathLength"));
+export const MAX_PATH_LENGTH = Number(getConstantOrThrow("maxPathLength"));
 export const MAX_FEED_EVENTS = Number(getConstantOrThrow("maxFeedEvents"));
 export const INITIAL_PRIZE_POOL = new BN(getConstantOrThrow("initialPrizePool")).toNumber();
 export const PRIZE_POOL_PERCENTAGE = Number(getConstantOrThrow("prizePoolPercentage"));
+export const ADMIN_PERCENTAGE = Number(getConstantOrThrow("adminPercentage"));
+export const REDEEM_PERCENTAGE = Number(getConstantOrThrow("redeemPercentage"));
+export const MOVE_SUCCESS_PROBABILITY = Number(getConstantOrThrow("moveSuccessProbability"));
+export const MOVE_TIMEOUT_SLOTS = new BN(getConstantOrThrow("moveTimeoutSlots")).toNumber();
+export const BASE_MOVE_COST = new BN(getConstantOrThrow("baseMoveCost")).toNumber();
+export const CARD_COST = new BN(getConstantOrThrow("cardCost")).toNumber();
+export const SWIFT_DISCOUNT = new BN(getConstantOrThrow("swiftDiscount")).toNumber();
 
 // String constants stored as byte arrays
 export const GAME_STATE_SEED = getStringFromByteArray(getConstantOrThrow("gameStateSeed"));
+export const GAME_CONFIG_SEED = getStringFromByteArray(getConstantOrThrow("gameConfigSeed"));
+export const PRIZE_VAULT_SEED = getStringFromByteArray(getConstantOrThrow("prizeVaultSeed"));
 export const PLAYER_STATE_SEED = getStringFromByteArray(getConstantOrThrow("playerStateSeed"));
+export const PLAYER_FEED_SEED = getStringFromByteArray(getConstantOrThrow("playerFeedSeed"));
+export const SCRIPTED_RANDOMNESS_SEED = getStringFromByteArray(
+  getConstantOrThrow("scriptedRandomnessSeed")
+);
 
 // Error codes
 export const CONSTRAINT_SEEDS = "ConstraintSeeds";
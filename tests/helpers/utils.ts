@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { BorshCoder, EventParser, Program } from "@coral-xyz/anchor";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import { Blockrunners } from "../../target/types/blockrunners";
 
 // Access constants from IDL
@@ -129,7 +129,7 @@ export const getEventLogs = async (
     const events = eventParser.parseLogs(txDetails.meta.logMessages);
     let eventLogs: string[] = [];
     for (let event of events) {
-      eventLogs.push(`${event.name}: ${event.data?.message}`);
+      eventLogs.push(`${event.name}: ${JSON.stringify(event.data?.payload ?? event.data)}`);
     }
 
     return eventLogs || null;
@@ -161,6 +161,62 @@ export const giveCard = async (
     .rpc();
 };
 
+/**
+ * Opens the game feed pages ahead of the one the game writes to, players can't open them.
+ * @param program Anchor program client.
+ * @param adminKeypair Game authority's keypair, pays the rent of the pages.
+ * @param gameId Id of the game.
+ * @param gameStatePda PDA for the game state.
+ * @param count Number of pages to have open from the current one on.
+ */
+export const openFeedPages = async (
+  program: Program<Blockrunners>,
+  adminKeypair: Keypair,
+  gameId: anchor.BN,
+  gameStatePda: PublicKey,
+  count: number = 4
+) => {
+  const feedPageSeed = getStringFromByteArray(getConstantOrThrow("feedPageSeed"));
+  const { feedPage } = await program.account.gameState.fetch(gameStatePda);
+  for (let i = 0; i < count; i++) {
+    const pageIndex = feedPage.addn(i);
+    const [feedPagePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from(feedPageSeed),
+        gameId.toArrayLike(Buffer, "le", 8),
+        pageIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    if (await program.account.feedPage.fetchNullable(feedPagePda)) continue;
+
+    await program.methods
+      .openFeedPage(gameId, pageIndex)
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+  }
+};
+
+/**
+ * Decodes the entries of a player feed from the oldest to the newest.
+ * @param program Anchor program client.
+ * @param playerFeedPda PDA for player's feed.
+ */
+export const getPlayerFeedEntries = async (
+  program: Program<Blockrunners>,
+  playerFeedPda: anchor.web3.PublicKey
+) => {
+  const feed = await program.account.playerFeed.fetch(playerFeedPda);
+  // Once the feed is full, the oldest entry is at the head
+  const slots = feed.slots.slice(0, feed.len);
+  return [...slots.slice(feed.head), ...slots.slice(0, feed.head)].map((slot) =>
+    program.coder.types.decode("feedEntry", Buffer.from(slot.data))
+  );
+};
+
 export async function sleep(ms: number): Promise<void> {
   return new Promise((resolve) => setTimeout(resolve, ms));
 }
@@ -168,4 +224,42 @@ export async function sleep(ms: number): Promise<void> {
 // Helper function to calculate total cards from CardCounts structure
 export function getTotalCards(cards: any): number {
   return (cards?.shield || 0) + (cards?.doubler || 0) + (cards?.swift || 0);
-}
\ No newline at end of file
+}
+/**
+ * Builds a randomness value for a scripted move.
+ * Values are consumed with `swap_remove(0)`, so after the first byte they are read from the end.
+ * @param roll Consensus roll, the move fails unless `roll % 100` is below the success probability.
+ * @param pathByte Mixed with the path seed to decide the hidden step, odd is right in test mode.
+ * @param cardBytes Bytes deciding the cards given on a correct move (0 Shield, 1 Doubler, 2 Swift).
+ */
+export function buildRandomness(roll: number, pathByte: number, cardBytes: number[] = []): number[] {
+  const value = new Array(32).fill(0);
+  value[0] = roll;
+  value[31] = pathByte;
+  cardBytes.forEach((cardByte, i) => {
+    value[30 - i] = cardByte;
+  });
+  return value;
+}
+
+/**
+ * Queues randomness values consumed by the player's next moves in the test environment.
+ * The queue has to be passed to `moveReveal` as a writable remaining account.
+ * @param program Anchor program client.
+ * @param playerKeypair Player's keypair.
+ * @param values 32-byte randomness values, one per move.
+ */
+export const scriptRandomness = async (
+  program: Program<Blockrunners>,
+  playerKeypair: Keypair,
+  values: number[][]
+) => {
+  await program.methods
+    .debugScriptRandomness(values)
+    .accounts({
+      player: playerKeypair.publicKey,
+    })
+    .signers([playerKeypair])
+    .rpc();
+};
+
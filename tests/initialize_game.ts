@@ -1,10 +1,10 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { Blockrunners } from "../target/types/blockrunners";
 import { airdropSol, getEventLogs, getMsgLogs, getTxDetails } from "./helpers/utils";
-import { GAME_STATE_SEED, ADMIN_KEYPAIR, INITIAL_PATH_LENGTH } from "./helpers/constants";
+import { GAME_ID, GAME_STATE_SEED, ADMIN_KEYPAIR, INITIAL_PATH_LENGTH } from "./helpers/constants";
 
 describe("Initialize Game", () => {
   // Configure the client to use the local cluster
@@ -18,7 +18,7 @@ describe("Initialize Game", () => {
 
   // Game state PDA
   const [gameStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(GAME_STATE_SEED)],
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
@@ -35,7 +35,7 @@ describe("Initialize Game", () => {
     const gameStateBefore = await program.account.gameState.fetchNullable(gameStatePda);
     if (!gameStateBefore) {
       const tx = await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
@@ -59,7 +59,7 @@ describe("Initialize Game", () => {
   it("Fails if game state account already exists", async () => {
     try {
       await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
@@ -69,4 +69,28 @@ describe("Initialize Game", () => {
       expect(error.transactionLogs[3]).to.include("already in use");
     }
   });
+
+  it("Allows multiple game instances side by side", async () => {
+    const secondGameId = new BN(Date.now());
+    const [secondGameStatePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from(GAME_STATE_SEED), secondGameId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeGame(secondGameId)
+      .accounts({
+        admin: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const firstGameState = await program.account.gameState.fetch(gameStatePda);
+    const secondGameState = await program.account.gameState.fetch(secondGameStatePda);
+
+    // Verify both games exist independently
+    expect(firstGameState.gameId.toString()).to.equal(GAME_ID.toString());
+    expect(secondGameState.gameId.toString()).to.equal(secondGameId.toString());
+    expect(secondGameState.prizePool.toNumber()).to.equal(0);
+  });
 });
@@ -3,7 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { Blockrunners } from "../target/types/blockrunners";
-import { GAME_STATE_SEED, PLAYER_STATE_SEED, ADMIN_KEYPAIR } from "./helpers/constants";
+import { GAME_ID, GAME_STATE_SEED, PLAYER_STATE_SEED, ADMIN_KEYPAIR } from "./helpers/constants";
 import { airdropSol, getEventLogs, getMsgLogs, getTxDetails, sleep } from "./helpers/utils";
 
 describe("Initialize Player", () => {
@@ -19,7 +19,7 @@ describe("Initialize Player", () => {
 
   // Game state PDA
   const [gameStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(GAME_STATE_SEED)],
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
@@ -38,7 +38,7 @@ describe("Initialize Player", () => {
     const gameState = await program.account.gameState.fetchNullable(gameStatePda);
     if (!gameState) {
       const initTx = await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
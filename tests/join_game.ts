@@ -3,8 +3,22 @@ import { Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { Blockrunners } from "../target/types/blockrunners";
-import { ADMIN_KEYPAIR, GAME_STATE_SEED, PLAYER_STATE_SEED } from "./helpers/constants";
-import { airdropSol, getEventLogs, getMsgLogs, getTxDetails } from "./helpers/utils";
+import {
+  ADMIN_KEYPAIR,
+  GAME_CONFIG_SEED,
+  GAME_ID,
+  GAME_STATE_SEED,
+  PLAYER_FEED_SEED,
+  PLAYER_STATE_SEED,
+} from "./helpers/constants";
+import {
+  airdropSol,
+  getEventLogs,
+  getMsgLogs,
+  getPlayerFeedEntries,
+  getTxDetails,
+  openFeedPages,
+} from "./helpers/utils";
 
 describe("Join game", () => {
   // Configure the client to use the local cluster.
@@ -19,7 +33,13 @@ describe("Join game", () => {
 
   // Game state PDA
   const [gameStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(GAME_STATE_SEED)],
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  // Game config PDA
+  const [gameConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_CONFIG_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
@@ -29,6 +49,12 @@ describe("Join game", () => {
     program.programId
   );
 
+  // Player feed PDA
+  const [playerFeedPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PLAYER_FEED_SEED), playerKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
   before(async () => {
     // Airdrop SOL to admin and player
     await airdropSol(provider, adminKeypair);
@@ -38,7 +64,7 @@ describe("Join game", () => {
     const gameState = await program.account.gameState.fetchNullable(gameStatePda);
     if (!gameState) {
       const initTx = await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
@@ -48,6 +74,9 @@ describe("Join game", () => {
       console.log("Game initialization transaction signature", initTx);
     }
 
+    // Players write to feed pages the authority opened in advance
+    await openFeedPages(program, adminKeypair, GAME_ID, gameStatePda);
+
     // Initialize player
     const initPlayerTx = await program.methods
       .initializePlayer()
@@ -61,7 +90,7 @@ describe("Join game", () => {
 
   it("Allows player to join game", async () => {
     const socialFeedEventListener = program.addEventListener("socialFeedEvent", (event) => {
-      console.log("Join game events:", event.message);
+      console.log("Join game events:", event.payload);
     });
 
     // Get states before joining
@@ -73,7 +102,7 @@ describe("Join game", () => {
 
     // Join game
     const tx = await program.methods
-      .joinGame()
+      .joinGame(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
       })
@@ -95,13 +124,27 @@ describe("Join game", () => {
     expect(playerStateAfter.gameStart).to.not.be.null;
     expect(playerStateAfter.gameStart.toString()).to.equal(gameStateBefore.start.toString());
 
-    // Verify the player has the default cards
-    expect(playerStateAfter.cards.shield).to.equal(1);
-    expect(playerStateAfter.cards.doubler).to.equal(1);
-    expect(playerStateAfter.cards.swift).to.equal(1);
+    // Verify the player got the starting hand of the highest tier reached by the prize pool
+    const gameConfig = await program.account.gameConfig.fetch(gameConfigPda);
+    const startingHand = [...gameConfig.params.startingHandTiers]
+      .reverse()
+      .find((tier) => gameStateAfter.prizePool.gte(tier.minPrizePool)).cards;
+    expect(playerStateAfter.cards.shield).to.equal(startingHand.shield);
+    expect(playerStateAfter.cards.doubler).to.equal(startingHand.doubler);
+    expect(playerStateAfter.cards.swift).to.equal(startingHand.swift);
+
+    // Verify the starting hand was recorded in the player feed
+    const playerFeedEntries = await getPlayerFeedEntries(program, playerFeedPda);
+    expect(
+      playerFeedEntries.some(
+        (entry) => entry.event.payload.startingHandDealt !== undefined
+      )
+    ).to.be.true;
 
     // Verify game events were increased
-    expect(gameStateAfter.gameEvents.length).to.be.greaterThan(gameStateBefore.gameEvents.length);
+    expect(gameStateAfter.feedSequence.toNumber()).to.be.greaterThan(
+      gameStateBefore.feedSequence.toNumber()
+    );
 
     // Remove listener
     await program.removeEventListener(socialFeedEventListener);
@@ -111,7 +154,7 @@ describe("Join game", () => {
     try {
       // Try to join game again
       await program.methods
-        .joinGame()
+        .joinGame(GAME_ID)
         .accounts({
           player: playerKeypair.publicKey,
         })
@@ -3,7 +3,14 @@ import { BN, Program } from "@coral-xyz/anchor";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 import { Blockrunners } from "../target/types/blockrunners";
-import { ADMIN_KEYPAIR, GAME_STATE_SEED, PLAYER_STATE_SEED } from "./helpers/constants";
+import {
+  ADMIN_KEYPAIR,
+  BASE_MOVE_COST,
+  GAME_ID,
+  GAME_CONFIG_SEED,
+  GAME_STATE_SEED,
+  PLAYER_STATE_SEED,
+} from "./helpers/constants";
 import {
   airdropSol,
   getEventLogs,
@@ -12,6 +19,7 @@ import {
   giveCard,
   getTotalCards,
   sleep,
+  openFeedPages,
 } from "./helpers/utils";
 import { CARD_USAGE_EMPTY_MOCK } from "./mocks/card-usage";
 
@@ -29,7 +37,13 @@ describe("Move Commit-Reveal", () => {
 
   // Game state PDA
   const [gameStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(GAME_STATE_SEED)],
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  // Game config PDA
+  const [gameConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_CONFIG_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
@@ -56,7 +70,7 @@ describe("Move Commit-Reveal", () => {
     const gameState = await program.account.gameState.fetchNullable(gameStatePda);
     if (!gameState) {
       await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
@@ -66,6 +80,9 @@ describe("Move Commit-Reveal", () => {
       console.log("Game initialized");
     }
 
+    // Players write to feed pages the authority opened in advance
+    await openFeedPages(program, adminKeypair, GAME_ID, gameStatePda);
+
     // Initialize player state
     await program.methods
       .initializePlayer()
@@ -79,7 +96,7 @@ describe("Move Commit-Reveal", () => {
 
     // Join the game
     await program.methods
-      .joinGame()
+      .joinGame(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
       })
@@ -91,7 +108,7 @@ describe("Move Commit-Reveal", () => {
     // Purchase ciphers
     const ciphersToPurchase = 10;
     await program.methods
-      .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+      .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -106,10 +123,22 @@ describe("Move Commit-Reveal", () => {
     provider.connection.removeOnLogsListener(playerLogsSubscription);
   });
 
+  it("Returns the price of the next step", async () => {
+    const nextStepPrice = await program.methods
+      .getNextStepPrice(GAME_ID)
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .view();
+
+    // The default step price curve is flat
+    expect(nextStepPrice.toNumber()).to.equal(BASE_MOVE_COST);
+  });
+
   it("Allows successful player movement with correct choice and no cards", async () => {
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(1))
+      .purchaseCiphers(GAME_ID, new anchor.BN(1))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -118,7 +147,7 @@ describe("Move Commit-Reveal", () => {
       .rpc();
 
     const socialFeedEventListener = program.addEventListener("socialFeedEvent", (event) => {
-      console.log("Make move events:", event.message);
+      console.log("Make move events:", event.payload);
     });
 
     // Fetch player state to get the current position
@@ -129,7 +158,7 @@ describe("Move Commit-Reveal", () => {
     // Step 1: Commit the move
     const direction = { right: {} };
     const txCommit = await program.methods
-      .moveCommit(direction, CARD_USAGE_EMPTY_MOCK)
+      .moveCommit(GAME_ID, direction, CARD_USAGE_EMPTY_MOCK)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -145,7 +174,7 @@ describe("Move Commit-Reveal", () => {
 
     // Step 2: Reveal the move
     const txReveal = await program.methods
-      .moveReveal()
+      .moveReveal(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -196,7 +225,7 @@ describe("Move Commit-Reveal", () => {
   it("Makes multiple moves", async () => {
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(3))
+      .purchaseCiphers(GAME_ID, new anchor.BN(3))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -211,13 +240,13 @@ describe("Move Commit-Reveal", () => {
       const currentPosition = playerStateBefore.position;
       console.log(`Move ${i + 1}: Player position before move: ${currentPosition}`);
 
-      // Make a move (direction doesn't matter, the program will generate the correct direction)
-      const direction = { left: {} }; // Just pick a direction
+      // Make a move (the hidden path always goes right in test mode)
+      const direction = { right: {} };
       console.log(`Move ${i + 1}: Direction chosen: ${JSON.stringify(direction)}`);
 
       // Step 1: Commit the move
       await program.methods
-        .moveCommit(direction, CARD_USAGE_EMPTY_MOCK)
+        .moveCommit(GAME_ID, direction, CARD_USAGE_EMPTY_MOCK)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: randomnessKeypair.publicKey,
@@ -227,7 +256,7 @@ describe("Move Commit-Reveal", () => {
 
       // Step 2: Reveal the move
       await program.methods
-        .moveReveal()
+        .moveReveal(GAME_ID)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: randomnessKeypair.publicKey,
@@ -254,7 +283,7 @@ describe("Move Commit-Reveal", () => {
   it("Tests lastLogin update", async () => {
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(1))
+      .purchaseCiphers(GAME_ID, new anchor.BN(1))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -273,7 +302,7 @@ describe("Move Commit-Reveal", () => {
     // Move
     const direction = { right: {} };
     await program.methods
-      .moveCommit(direction, CARD_USAGE_EMPTY_MOCK)
+      .moveCommit(GAME_ID, direction, CARD_USAGE_EMPTY_MOCK)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -294,7 +323,7 @@ describe("Move Commit-Reveal", () => {
 
     // Complete the move by revealing
     await program.methods
-      .moveReveal()
+      .moveReveal(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -314,7 +343,7 @@ describe("Move Commit-Reveal", () => {
   it("Tests incorrect move behavior", async () => {
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(1))
+      .purchaseCiphers(GAME_ID, new anchor.BN(1))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -327,13 +356,13 @@ describe("Move Commit-Reveal", () => {
     const currentPosition = playerStateBefore.position;
     console.log(`Player position before move: ${currentPosition}`);
 
-    // Make a move (direction doesn't matter, the program will generate the correct direction)
-    const direction = { right: {} }; // Just pick a direction
+    // Make a move against the hidden path (it always goes right in test mode)
+    const direction = { left: {} };
     console.log(`Direction chosen: ${JSON.stringify(direction)}`);
 
     // Step 1: Commit the move
     await program.methods
-      .moveCommit(direction, CARD_USAGE_EMPTY_MOCK)
+      .moveCommit(GAME_ID, direction, CARD_USAGE_EMPTY_MOCK)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -343,7 +372,7 @@ describe("Move Commit-Reveal", () => {
 
     // Step 2: Reveal the move
     const txReveal = await program.methods
-      .moveReveal()
+      .moveReveal(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -397,7 +426,7 @@ describe("Move Commit-Reveal", () => {
 
     // Step 1: Commit the move
     await program.methods
-      .moveCommit(correctDirection, cards)
+      .moveCommit(GAME_ID, correctDirection, cards)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -407,7 +436,7 @@ describe("Move Commit-Reveal", () => {
 
     // Step 2: Reveal the move
     const txReveal = await program.methods
-      .moveReveal()
+      .moveReveal(GAME_ID)
       .accounts({
         player: playerKeypair.publicKey,
         randomnessAccount: randomnessKeypair.publicKey,
@@ -432,7 +461,7 @@ describe("Move Commit-Reveal", () => {
   it("Validates randomness account correctly", async () => {
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(1))
+      .purchaseCiphers(GAME_ID, new anchor.BN(1))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -446,7 +475,7 @@ describe("Move Commit-Reveal", () => {
     try {
       // Step 1: Commit the move
       await program.methods
-        .moveCommit({ left: {} }, CARD_USAGE_EMPTY_MOCK)
+        .moveCommit(GAME_ID, { left: {} }, CARD_USAGE_EMPTY_MOCK)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: randomnessKeypair.publicKey,
@@ -456,7 +485,7 @@ describe("Move Commit-Reveal", () => {
 
       // Step 2: Reveal the move - but supply wrong randomness account
       await program.methods
-        .moveReveal()
+        .moveReveal(GAME_ID)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: invalidRandomnessKeypair.publicKey,
@@ -472,6 +501,103 @@ describe("Move Commit-Reveal", () => {
     expect.fail("Expected an error but none was thrown");
   });
 
+  it("Doesn't allow cancelling a move before it expires", async () => {
+    // The previous test left a pending commitment, which can't be replaced by a new one
+    let commitError;
+    try {
+      await program.methods
+        .moveCommit(GAME_ID, { right: {} }, CARD_USAGE_EMPTY_MOCK)
+        .accounts({
+          player: playerKeypair.publicKey,
+          randomnessAccount: randomnessKeypair.publicKey,
+        })
+        .signers([playerKeypair])
+        .rpc();
+    } catch (error) {
+      commitError = error;
+    }
+    expect(commitError?.error?.errorCode.code).to.equal("MovePending");
+
+    try {
+      await program.methods
+        .cancelMove(GAME_ID)
+        .accounts({
+          player: playerKeypair.publicKey,
+          randomnessAccount: randomnessKeypair.publicKey,
+        })
+        .signers([playerKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("MoveCommitNotExpired");
+      return;
+    }
+
+    expect.fail("Expected an error but none was thrown");
+  });
+
+  it("Expires and cancels a stale move commitment", async () => {
+    // Shorten the move timeout to a single slot
+    const { params } = await program.account.gameConfig.fetch(gameConfigPda);
+    await program.methods
+      .updateConfig(GAME_ID, { ...params, moveTimeoutSlots: new anchor.BN(1) })
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    try {
+      // The move committed two tests earlier is still pending
+      const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+
+      // Wait for the commitment to expire
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+
+      // Revealing an expired move fails
+      let revealError;
+      try {
+        await program.methods
+          .moveReveal(GAME_ID)
+          .accounts({
+            player: playerKeypair.publicKey,
+            randomnessAccount: randomnessKeypair.publicKey,
+          })
+          .signers([playerKeypair])
+          .rpc();
+      } catch (error) {
+        revealError = error;
+      }
+      expect(revealError?.error?.errorCode.code).to.equal("MoveCommitExpired");
+
+      // Cancelling it clears the commitment, the price paid on commit isn't refunded
+      await program.methods
+        .cancelMove(GAME_ID)
+        .accounts({
+          player: playerKeypair.publicKey,
+          randomnessAccount: randomnessKeypair.publicKey,
+        })
+        .signers([playerKeypair])
+        .rpc();
+
+      const playerStateAfter = await program.account.playerState.fetch(playerStatePda);
+      expect(playerStateAfter.moveDirection).to.be.null;
+      expect(playerStateAfter.moveCards).to.be.null;
+      expect(playerStateAfter.randomnessAccount).to.be.null;
+      expect(playerStateAfter.randomnessSlot).to.be.null;
+      expect(playerStateAfter.ciphers.toString()).to.equal(playerStateBefore.ciphers.toString());
+      expect(playerStateAfter.position).to.equal(playerStateBefore.position);
+    } finally {
+      // Restore the move timeout
+      await program.methods
+        .updateConfig(GAME_ID, params)
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    }
+  });
+
   it("Verifies game completion and prize distribution", async () => {
     // Get initial balances
     const gameStateBefore = await program.account.gameState.fetch(gameStatePda);
@@ -484,7 +610,7 @@ describe("Move Commit-Reveal", () => {
     const socialFeedEventListener = program.addEventListener("socialFeedEvent", (event) => {
       if (event.eventType.gameWon) {
         winEventCaptured = true;
-        console.log("Win event captured:", event.message);
+        console.log("Win event captured:", event.payload);
       }
     });
 
@@ -494,7 +620,7 @@ describe("Move Commit-Reveal", () => {
 
     // Purchase ciphers for the test
     await program.methods
-      .purchaseCiphers(new anchor.BN(movesNeeded))
+      .purchaseCiphers(GAME_ID, new anchor.BN(movesNeeded))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -508,7 +634,7 @@ describe("Move Commit-Reveal", () => {
 
       // Step 1: Commit the move
       await program.methods
-        .moveCommit(direction, CARD_USAGE_EMPTY_MOCK)
+        .moveCommit(GAME_ID, direction, CARD_USAGE_EMPTY_MOCK)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: randomnessKeypair.publicKey,
@@ -518,7 +644,7 @@ describe("Move Commit-Reveal", () => {
 
       // Step 2: Reveal the move
       await program.methods
-        .moveReveal()
+        .moveReveal(GAME_ID)
         .accounts({
           player: playerKeypair.publicKey,
           randomnessAccount: randomnessKeypair.publicKey,
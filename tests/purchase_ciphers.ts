@@ -5,10 +5,13 @@ import { expect } from "chai";
 import { Blockrunners } from "../target/types/blockrunners";
 import {
   ADMIN_KEYPAIR,
+  GAME_ID,
   GAME_STATE_SEED,
+  PLAYER_FEED_SEED,
   PLAYER_STATE_SEED,
   CIPHER_COST,
   PRIZE_POOL_PERCENTAGE,
+  PRIZE_VAULT_SEED,
 } from "./helpers/constants";
 import {
   airdropSol,
@@ -17,6 +20,7 @@ import {
   getTotalCards,
   getTxDetails,
   sleep,
+  openFeedPages,
 } from "./helpers/utils";
 
 describe("Purchase ciphers", () => {
@@ -32,7 +36,13 @@ describe("Purchase ciphers", () => {
 
   // Game state PDA
   const [gameStatePda] = PublicKey.findProgramAddressSync(
-    [Buffer.from(GAME_STATE_SEED)],
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  // Prize vault PDA
+  const [prizeVaultPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PRIZE_VAULT_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
 
@@ -42,6 +52,12 @@ describe("Purchase ciphers", () => {
     program.programId
   );
 
+  // Player feed PDA
+  const [playerFeedPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PLAYER_FEED_SEED), playerKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
   let playerLogsSubscription: number;
 
   before(async () => {
@@ -59,7 +75,7 @@ describe("Purchase ciphers", () => {
     const gameState = await program.account.gameState.fetchNullable(gameStatePda);
     if (!gameState) {
       await program.methods
-        .initializeGame()
+        .initializeGame(GAME_ID)
         .accounts({
           admin: adminKeypair.publicKey,
         })
@@ -69,6 +85,9 @@ describe("Purchase ciphers", () => {
       console.log("Game initialized");
     }
 
+    // Players write to feed pages the authority opened in advance
+    await openFeedPages(program, adminKeypair, GAME_ID, gameStatePda);
+
     // Initialize player state
     await program.methods
       .initializePlayer()
@@ -79,6 +98,17 @@ describe("Purchase ciphers", () => {
       .rpc();
 
     console.log("Player initialized");
+
+    // Join the game, only its runners can buy ciphers
+    await program.methods
+      .joinGame(GAME_ID)
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    console.log("Player joined the game");
   });
 
   after(() => {
@@ -96,10 +126,11 @@ describe("Purchase ciphers", () => {
     // Get states before purchase
     const gameStateBefore = await program.account.gameState.fetch(gameStatePda);
     const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+    const playerFeedBefore = await program.account.playerFeed.fetch(playerFeedPda);
 
     // Get balances before purchase
     const playerBalanceBefore = await provider.connection.getBalance(playerKeypair.publicKey);
-    const gameBalanceBefore = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceBefore = await provider.connection.getBalance(prizeVaultPda);
     const adminBalanceBefore = await provider.connection.getBalance(adminKeypair.publicKey);
 
     console.log("=== PURCHASE CIPHERS TEST ===");
@@ -115,7 +146,7 @@ describe("Purchase ciphers", () => {
 
     // Purchase ciphers
     const tx = await program.methods
-      .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+      .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -130,11 +161,12 @@ describe("Purchase ciphers", () => {
 
     // Get balance after purchase
     const playerBalanceAfter = await provider.connection.getBalance(playerKeypair.publicKey);
-    const gameBalanceAfter = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceAfter = await provider.connection.getBalance(prizeVaultPda);
     const adminBalanceAfter = await provider.connection.getBalance(adminKeypair.publicKey);
 
     // Fetch player state to verify
     const playerStateAfter = await program.account.playerState.fetch(playerStatePda);
+    const playerFeedAfter = await program.account.playerFeed.fetch(playerFeedPda);
 
     // Fetch game state to verify
     const gameStateAfter = await program.account.gameState.fetch(gameStatePda);
@@ -160,9 +192,13 @@ describe("Purchase ciphers", () => {
     // Verify admin received their share
     expect(adminBalanceAfter - adminBalanceBefore).to.equal(expectedAdminShare.toNumber());
 
-    // Verify game balance increased by only the prize pool portion
+    // Verify prize vault balance increased by only the prize pool portion
     expect(gameBalanceAfter - gameBalanceBefore).to.equal(expectedPrizePoolIncrease.toNumber());
 
+    // Verify the prize vault holds exactly the prize pool plus its rent reserve
+    const vaultRent = await provider.connection.getMinimumBalanceForRentExemption(0);
+    expect(gameBalanceAfter).to.equal(gameStateAfter.prizePool.toNumber() + vaultRent);
+
     // Verify ciphers were increased by the correct amount
     expect(playerStateAfter.ciphers.toNumber()).to.equal(
       playerStateBefore.ciphers.toNumber() + ciphersToPurchase
@@ -174,17 +210,18 @@ describe("Purchase ciphers", () => {
     );
 
     // Verify player events were increased
-    expect(playerStateAfter.playerEvents.length).to.be.greaterThan(
-      playerStateBefore.playerEvents.length
+    expect(playerFeedAfter.nextSequence.toNumber()).to.be.greaterThan(
+      playerFeedBefore.nextSequence.toNumber()
     );
   });
 
   it("Allows player to purchase ciphers again", async () => {
     // Store current state for verification
     const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+    const playerFeedBefore = await program.account.playerFeed.fetch(playerFeedPda);
     const gameStateBefore = await program.account.gameState.fetch(gameStatePda);
     const playerBalanceBefore = await provider.connection.getBalance(playerKeypair.publicKey);
-    const gameBalanceBefore = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceBefore = await provider.connection.getBalance(prizeVaultPda);
     const adminBalanceBefore = await provider.connection.getBalance(adminKeypair.publicKey);
 
     const additionalCiphers = 3;
@@ -200,7 +237,7 @@ describe("Purchase ciphers", () => {
 
     // Purchase more ciphers as the first player
     const tx = await program.methods
-      .purchaseCiphers(new anchor.BN(additionalCiphers))
+      .purchaseCiphers(GAME_ID, new anchor.BN(additionalCiphers))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -215,9 +252,10 @@ describe("Purchase ciphers", () => {
 
     // Get updated states
     const playerStateAfter = await program.account.playerState.fetch(playerStatePda);
+    const playerFeedAfter = await program.account.playerFeed.fetch(playerFeedPda);
     const gameStateAfter = await program.account.gameState.fetch(gameStatePda);
     const playerBalanceAfter = await provider.connection.getBalance(playerKeypair.publicKey);
-    const gameBalanceAfter = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceAfter = await provider.connection.getBalance(prizeVaultPda);
     const adminBalanceAfter = await provider.connection.getBalance(adminKeypair.publicKey);
 
     // Log balance changes for debugging
@@ -246,19 +284,21 @@ describe("Purchase ciphers", () => {
     // Verify player balance reduced by at least the cost of the ciphers
     expect(playerBalanceBefore - playerBalanceAfter).to.be.greaterThan(expectedCost - 100); // Allow for small rounding
 
-    // Verify game balance increased by only the prize pool portion
+    // Verify prize vault balance increased by only the prize pool portion
     expect(gameBalanceAfter - gameBalanceBefore).to.equal(expectedPrizePoolIncrease.toNumber());
 
     // Verify the amount of cards did not increase
     expect(getTotalCards(playerStateAfter.cards)).to.equal(getTotalCards(playerStateBefore.cards));
 
     // Verify the amount of player events increased
-    expect(playerStateAfter.playerEvents.length).to.equal(
-      playerStateBefore.playerEvents.length + 1
+    expect(playerFeedAfter.nextSequence.toNumber()).to.equal(
+      playerFeedBefore.nextSequence.toNumber() + 1
     );
 
     // Verify the amount of game events increased due to funding surge
-    expect(gameStateAfter.gameEvents.length).to.equal(gameStateBefore.gameEvents.length + 1);
+    expect(gameStateAfter.feedSequence.toNumber()).to.equal(
+      gameStateBefore.feedSequence.toNumber() + 1
+    );
   });
 
   it("Allows second player to purchase ciphers", async () => {
@@ -285,12 +325,21 @@ describe("Purchase ciphers", () => {
 
     console.log("Player 2 initialization transaction signature", initPlayer2Tx);
 
+    // Join the game with the second player
+    await program.methods
+      .joinGame(GAME_ID)
+      .accounts({
+        player: player2Keypair.publicKey,
+      })
+      .signers([player2Keypair])
+      .rpc();
+
     // Get state before second player's transaction
     const gameStateBefore = await program.account.gameState.fetch(gameStatePda);
     const player2StateBefore = await program.account.playerState.fetch(player2StatePda);
 
     // Get balances before purchase
-    const gameBalanceBefore = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceBefore = await provider.connection.getBalance(prizeVaultPda);
     const player2BalanceBefore = await provider.connection.getBalance(player2Keypair.publicKey);
     const adminBalanceBefore = await provider.connection.getBalance(adminKeypair.publicKey);
 
@@ -307,7 +356,7 @@ describe("Purchase ciphers", () => {
     console.log(`Expected admin share: ${expectedAdminShare} lamports`);
 
     const tx = await program.methods
-      .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+      .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
       .accounts({
         player: player2Keypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -322,7 +371,7 @@ describe("Purchase ciphers", () => {
 
     // Get updated states
     const gameStateAfter = await program.account.gameState.fetch(gameStatePda);
-    const gameBalanceAfter = await provider.connection.getBalance(gameStatePda);
+    const gameBalanceAfter = await provider.connection.getBalance(prizeVaultPda);
     const player2BalanceAfter = await provider.connection.getBalance(player2Keypair.publicKey);
     const adminBalanceAfter = await provider.connection.getBalance(adminKeypair.publicKey);
 
@@ -347,7 +396,7 @@ describe("Purchase ciphers", () => {
     // Verify player balance reduced by at least the cost of the ciphers
     expect(player2BalanceBefore - player2BalanceAfter).to.be.greaterThan(expectedCost - 100); // Allow for small rounding
 
-    // Verify game balance increased by only the prize pool portion
+    // Verify prize vault balance increased by only the prize pool portion
     expect(gameBalanceAfter - gameBalanceBefore).to.equal(expectedPrizePoolIncrease.toNumber());
   });
 
@@ -363,7 +412,7 @@ describe("Purchase ciphers", () => {
     // Purchase ciphers
     const ciphersToPurchase = 1;
     await program.methods
-      .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+      .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
       .accounts({
         player: playerKeypair.publicKey,
         adminWallet: adminKeypair.publicKey,
@@ -385,7 +434,7 @@ describe("Purchase ciphers", () => {
       const ciphersToPurchase = LAMPORTS_PER_SOL / CIPHER_COST + 1;
 
       const tx = await program.methods
-        .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+        .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
         .accounts({
           player: playerKeypair.publicKey,
           adminWallet: adminKeypair.publicKey,
@@ -404,7 +453,7 @@ describe("Purchase ciphers", () => {
       const ciphersToPurchase = 0;
 
       const tx = await program.methods
-        .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+        .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
         .accounts({
           player: playerKeypair.publicKey,
           adminWallet: adminKeypair.publicKey,
@@ -418,6 +467,34 @@ describe("Purchase ciphers", () => {
     expect.fail("Expected an error but none was thrown");
   });
 
+  it("Fails if player who hasn't joined the game tries to purchase ciphers", async () => {
+    const player3Keypair = Keypair.generate();
+    await airdropSol(provider, player3Keypair);
+
+    await program.methods
+      .initializePlayer()
+      .accounts({
+        player: player3Keypair.publicKey,
+      })
+      .signers([player3Keypair])
+      .rpc();
+
+    try {
+      await program.methods
+        .purchaseCiphers(GAME_ID, new anchor.BN(1))
+        .accounts({
+          player: player3Keypair.publicKey,
+          adminWallet: adminKeypair.publicKey,
+        })
+        .signers([player3Keypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("PlayingInDifferentGame");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+
   it("Fails if player without player state account tries to purchase ciphers", async () => {
     try {
       const ciphersToPurchase = 1;
@@ -426,7 +503,7 @@ describe("Purchase ciphers", () => {
       const randomness2Keypair = Keypair.generate();
 
       const tx = await program.methods
-        .purchaseCiphers(new anchor.BN(ciphersToPurchase))
+        .purchaseCiphers(GAME_ID, new anchor.BN(ciphersToPurchase))
         .accounts({
           player: player2Keypair.publicKey,
           adminWallet: adminKeypair.publicKey,
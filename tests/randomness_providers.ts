@@ -0,0 +1,199 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SYSVAR_SLOT_HASHES_PUBKEY } from "@solana/web3.js";
+import { expect } from "chai";
+import { createHash, randomBytes } from "crypto";
+import { Blockrunners } from "../target/types/blockrunners";
+import {
+  ADMIN_KEYPAIR,
+  GAME_CONFIG_SEED,
+  GAME_STATE_SEED,
+  PLAYER_STATE_SEED,
+} from "./helpers/constants";
+import { airdropSol } from "./helpers/utils";
+import { CARD_USAGE_EMPTY_MOCK } from "./mocks/card-usage";
+
+describe("Randomness providers", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.blockrunners as Program<Blockrunners>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  // Generate test accounts
+  const adminKeypair = ADMIN_KEYPAIR;
+  const playerKeypair = Keypair.generate();
+
+  // A separate practice table, so the shared game keeps using Switchboard
+  const gameId = new BN(Date.now());
+
+  const [gameStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_STATE_SEED), gameId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const [gameConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_CONFIG_SEED), gameId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const [playerStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PLAYER_STATE_SEED), playerKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const sha256 = (data: Buffer) => Array.from(createHash("sha256").update(data).digest());
+
+  const setRandomnessProvider = async (randomnessProvider: object) => {
+    const { params } = await program.account.gameConfig.fetch(gameConfigPda);
+    await program.methods
+      .updateConfig(gameId, { ...params, randomnessProvider })
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+  };
+
+  const commitMove = (randomnessAccount: PublicKey) =>
+    program.methods
+      .moveCommit(gameId, { right: {} }, CARD_USAGE_EMPTY_MOCK)
+      .accounts({
+        player: playerKeypair.publicKey,
+        randomnessAccount,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+  const revealMove = (randomnessAccount: PublicKey) =>
+    program.methods
+      .moveReveal(gameId)
+      .accounts({
+        player: playerKeypair.publicKey,
+        randomnessAccount,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+  before(async () => {
+    await airdropSol(provider, adminKeypair);
+    await airdropSol(provider, playerKeypair);
+
+    await program.methods
+      .initializeGame(gameId)
+      .accounts({
+        admin: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    await program.methods
+      .initializePlayer()
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    await program.methods
+      .joinGame(gameId)
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    await program.methods
+      .purchaseCiphers(gameId, new BN(10))
+      .accounts({
+        player: playerKeypair.publicKey,
+        adminWallet: adminKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+  });
+
+  it("Resolves moves with the local oracle", async () => {
+    await setRandomnessProvider({ localOracle: {} });
+
+    // Moves can't be committed before the oracle commits to a secret
+    try {
+      await commitMove(gameStatePda);
+      expect.fail("Expected an error but none was thrown");
+    } catch (error) {
+      expect(error.error?.errorCode.code).to.equal("OracleCommitmentMissing");
+    }
+
+    const secret = randomBytes(32);
+    await program.methods
+      .oracleCommit(gameId, sha256(secret))
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    await commitMove(gameStatePda);
+
+    // The move can't be revealed before the oracle reveals its secret
+    try {
+      await revealMove(gameStatePda);
+      expect.fail("Expected an error but none was thrown");
+    } catch (error) {
+      expect(error.error?.errorCode.code).to.equal("RandomnessNotResolved");
+    }
+
+    // The oracle can't reveal a different secret
+    try {
+      await program.methods
+        .oracleReveal(gameId, Array.from(randomBytes(32)), null)
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+      expect.fail("Expected an error but none was thrown");
+    } catch (error) {
+      expect(error.error?.errorCode.code).to.equal("InvalidOracleSecret");
+    }
+
+    await program.methods
+      .oracleReveal(gameId, Array.from(secret), sha256(randomBytes(32)))
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+    await revealMove(gameStatePda);
+
+    const gameState = await program.account.gameState.fetch(gameStatePda);
+    expect(gameState.oracleReveal.secret).to.deep.equal(Array.from(secret));
+    expect(gameState.oracleCommitment).to.not.be.null;
+
+    const playerStateAfter = await program.account.playerState.fetch(playerStatePda);
+    expect(playerStateAfter.moveDirection).to.be.null;
+    expect(playerStateAfter.randomnessCommitment).to.be.null;
+    expect(playerStateAfter.ciphers.toNumber()).to.be.lessThan(playerStateBefore.ciphers.toNumber());
+  });
+
+  it("Resolves moves with slot hashes", async () => {
+    await setRandomnessProvider({ slotHash: {} });
+
+    // Only the SlotHashes sysvar is accepted as the randomness account
+    try {
+      await commitMove(gameStatePda);
+      expect.fail("Expected an error but none was thrown");
+    } catch (error) {
+      expect(error.error?.errorCode.code).to.equal("InvalidRandomnessAccount");
+    }
+
+    await commitMove(SYSVAR_SLOT_HASHES_PUBKEY);
+
+    // Wait for the commit slot to be hashed
+    await new Promise((resolve) => setTimeout(resolve, 1000));
+
+    await revealMove(SYSVAR_SLOT_HASHES_PUBKEY);
+  });
+});
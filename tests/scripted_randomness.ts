@@ -0,0 +1,163 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { Blockrunners } from "../target/types/blockrunners";
+import {
+  ADMIN_KEYPAIR,
+  GAME_ID,
+  GAME_STATE_SEED,
+  PLAYER_STATE_SEED,
+  SCRIPTED_RANDOMNESS_SEED,
+} from "./helpers/constants";
+import { airdropSol, buildRandomness, scriptRandomness, openFeedPages } from "./helpers/utils";
+import { CARD_USAGE_EMPTY_MOCK } from "./mocks/card-usage";
+
+describe("Scripted randomness", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.blockrunners as Program<Blockrunners>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  // Generate test accounts
+  const adminKeypair = ADMIN_KEYPAIR;
+  const playerKeypair = Keypair.generate();
+  const randomnessKeypair = Keypair.generate();
+
+  const [gameStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const [playerStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(PLAYER_STATE_SEED), playerKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
+  const [scriptedRandomnessPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SCRIPTED_RANDOMNESS_SEED), playerKeypair.publicKey.toBuffer()],
+    program.programId
+  );
+
+  // Commit to moving right and reveal with the next scripted randomness value
+  const makeMove = async (cardUsage = CARD_USAGE_EMPTY_MOCK) => {
+    await program.methods
+      .moveCommit(GAME_ID, { right: {} }, cardUsage)
+      .accounts({
+        player: playerKeypair.publicKey,
+        randomnessAccount: randomnessKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    await program.methods
+      .moveReveal(GAME_ID)
+      .accounts({
+        player: playerKeypair.publicKey,
+        randomnessAccount: randomnessKeypair.publicKey,
+      })
+      .remainingAccounts([{ pubkey: scriptedRandomnessPda, isSigner: false, isWritable: true }])
+      .signers([playerKeypair])
+      .rpc();
+
+    return program.account.playerState.fetch(playerStatePda);
+  };
+
+  before(async () => {
+    await airdropSol(provider, adminKeypair);
+    await airdropSol(provider, playerKeypair);
+
+    // Initialize the game if not already initialized
+    const gameState = await program.account.gameState.fetchNullable(gameStatePda);
+    if (!gameState) {
+      await program.methods
+        .initializeGame(GAME_ID)
+        .accounts({
+          admin: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    }
+
+    // Players write to feed pages the authority opened in advance
+    await openFeedPages(program, adminKeypair, GAME_ID, gameStatePda);
+
+    await program.methods
+      .initializePlayer()
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    await program.methods
+      .joinGame(GAME_ID)
+      .accounts({
+        player: playerKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+
+    await program.methods
+      .purchaseCiphers(GAME_ID, new anchor.BN(20))
+      .accounts({
+        player: playerKeypair.publicKey,
+        adminWallet: adminKeypair.publicKey,
+      })
+      .signers([playerKeypair])
+      .rpc();
+  });
+
+  it("Gives the scripted card on a correct move", async () => {
+    await scriptRandomness(program, playerKeypair, [buildRandomness(0, 1, [2])]);
+    const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+
+    const playerStateAfter = await makeMove();
+
+    expect(playerStateAfter.position).to.equal(playerStateBefore.position + 1);
+    expect(playerStateAfter.cards.swift).to.equal(playerStateBefore.cards.swift + 1);
+    expect(playerStateAfter.cards.shield).to.equal(playerStateBefore.cards.shield);
+    expect(playerStateAfter.cards.doubler).to.equal(playerStateBefore.cards.doubler);
+  });
+
+  it("Keeps the position when a shield is used on a wrong step", async () => {
+    await scriptRandomness(program, playerKeypair, [buildRandomness(0, 0)]);
+    const playerStateBefore = await program.account.playerState.fetch(playerStatePda);
+
+    const playerStateAfter = await makeMove({ ...CARD_USAGE_EMPTY_MOCK, shield: true });
+
+    expect(playerStateAfter.position).to.equal(playerStateBefore.position);
+    expect(playerStateAfter.cards.shield).to.equal(playerStateBefore.cards.shield - 1);
+    expect(playerStateAfter.shieldsUsed.toNumber()).to.equal(
+      playerStateBefore.shieldsUsed.toNumber() + 1
+    );
+  });
+
+  it("Resets the runner on a wrong step", async () => {
+    await scriptRandomness(program, playerKeypair, [buildRandomness(0, 1), buildRandomness(0, 0)]);
+
+    // Advance one step, then take a wrong one
+    const playerStateBefore = await makeMove();
+    expect(playerStateBefore.position).to.be.greaterThan(0);
+
+    const playerStateAfter = await makeMove();
+
+    expect(playerStateAfter.position).to.equal(0);
+    expect(playerStateAfter.totalResets.toNumber()).to.equal(
+      playerStateBefore.totalResets.toNumber() + 1
+    );
+  });
+
+  it("Resets the runner when the consensus traces a correct step", async () => {
+    await scriptRandomness(program, playerKeypair, [buildRandomness(0, 1), buildRandomness(99, 1)]);
+
+    const playerStateBefore = await makeMove();
+    const playerStateAfter = await makeMove();
+
+    expect(playerStateAfter.position).to.equal(0);
+    expect(playerStateAfter.totalResets.toNumber()).to.equal(
+      playerStateBefore.totalResets.toNumber() + 1
+    );
+  });
+});
@@ -0,0 +1,236 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { Blockrunners } from "../target/types/blockrunners";
+import {
+  ADMIN_KEYPAIR,
+  GAME_ID,
+  ADMIN_PERCENTAGE,
+  BASE_MOVE_COST,
+  CARD_COST,
+  CIPHER_COST,
+  GAME_CONFIG_SEED,
+  GAME_STATE_SEED,
+  INITIAL_PATH_LENGTH,
+  MAX_FEED_EVENTS,
+  MAX_PATH_LENGTH,
+  MIN_PATH_LENGTH,
+  MOVE_SUCCESS_PROBABILITY,
+  MOVE_TIMEOUT_SLOTS,
+  PRIZE_POOL_PERCENTAGE,
+  REDEEM_PERCENTAGE,
+  SWIFT_DISCOUNT,
+} from "./helpers/constants";
+import { airdropSol, getEventLogs, getMsgLogs, getTxDetails } from "./helpers/utils";
+
+describe("Update config", () => {
+  // Configure the client to use the local cluster.
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.blockrunners as Program<Blockrunners>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+
+  // Keypairs
+  const adminKeypair = ADMIN_KEYPAIR;
+  const strangerKeypair = Keypair.generate();
+
+  // Game state PDA
+  const [gameStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_STATE_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  // Game config PDA
+  const [gameConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from(GAME_CONFIG_SEED), GAME_ID.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+
+  const defaultParams = {
+    cipherCost: new anchor.BN(CIPHER_COST),
+    baseMoveCost: new anchor.BN(BASE_MOVE_COST),
+    cardCost: new anchor.BN(CARD_COST),
+    swiftDiscount: new anchor.BN(SWIFT_DISCOUNT),
+    stepPriceCurve: { flat: {} },
+    pathLength: INITIAL_PATH_LENGTH,
+    pathLengthRule: { fixed: {} },
+    minPathLength: MIN_PATH_LENGTH,
+    maxPathLength: MAX_PATH_LENGTH,
+    moveSuccessProbability: MOVE_SUCCESS_PROBABILITY,
+    moveTimeoutSlots: new anchor.BN(MOVE_TIMEOUT_SLOTS),
+    randomnessProvider: { switchboard: {} },
+    prizePoolPercentage: PRIZE_POOL_PERCENTAGE,
+    adminPercentage: ADMIN_PERCENTAGE,
+    maxFeedEvents: MAX_FEED_EVENTS,
+    startingHandTiers: [0, 1_000_000, 10_000_000, 100_000_000].map((minPrizePool, i) => ({
+      minPrizePool: new anchor.BN(minPrizePool),
+      cards: { shield: i + 1, doubler: i + 1, swift: i + 1 },
+    })),
+    cipherCarryOver: { carryOver: {} },
+    redeemPercentage: REDEEM_PERCENTAGE,
+  };
+
+  before(async () => {
+    // Airdrop SOL to the admin and the stranger
+    await airdropSol(provider, adminKeypair);
+    await airdropSol(provider, strangerKeypair);
+
+    // Initialize the game if not already initialized
+    const gameState = await program.account.gameState.fetchNullable(gameStatePda);
+    if (!gameState) {
+      await program.methods
+        .initializeGame(GAME_ID)
+        .accounts({
+          admin: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+
+      console.log("Game initialized");
+    }
+  });
+
+  after(async () => {
+    // Restore the defaults for other test suites
+    await program.methods
+      .updateConfig(GAME_ID, defaultParams)
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+  });
+
+  it("Allows admin to update the config", async () => {
+    const newParams = {
+      ...defaultParams,
+      cipherCost: new anchor.BN(2_000),
+      moveSuccessProbability: 60,
+      prizePoolPercentage: 90,
+      adminPercentage: 10,
+    };
+
+    const tx = await program.methods
+      .updateConfig(GAME_ID, newParams)
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+    const txDetails = await getTxDetails(provider, tx);
+    const logs = await getMsgLogs(txDetails);
+    console.log("Update config logs -> ", logs);
+    const events = await getEventLogs(txDetails);
+    console.log("Update config events -> ", events);
+
+    const gameConfig = await program.account.gameConfig.fetch(gameConfigPda);
+    expect(gameConfig.params.cipherCost.toNumber()).to.equal(2_000);
+    expect(gameConfig.params.moveSuccessProbability).to.equal(60);
+    expect(gameConfig.params.prizePoolPercentage).to.equal(90);
+    expect(gameConfig.params.adminPercentage).to.equal(10);
+  });
+
+  it("Allows admin to set an escalating step price curve", async () => {
+    await program.methods
+      .updateConfig(GAME_ID, {
+        ...defaultParams,
+        stepPriceCurve: { linear: { increment: new anchor.BN(2) } },
+      })
+      .accounts({
+        authority: adminKeypair.publicKey,
+      })
+      .signers([adminKeypair])
+      .rpc();
+
+    const gameConfig = await program.account.gameConfig.fetch(gameConfigPda);
+    expect(gameConfig.params.stepPriceCurve.linear.increment.toNumber()).to.equal(2);
+  });
+
+  it("Fails if the path length is out of its bounds", async () => {
+    try {
+      await program.methods
+        .updateConfig(GAME_ID, { ...defaultParams, pathLength: MAX_PATH_LENGTH + 1 })
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidPathLength");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+
+  it("Fails if the signer is not the game authority", async () => {
+    try {
+      await program.methods
+        .updateConfig(GAME_ID, defaultParams)
+        .accounts({
+          authority: strangerKeypair.publicKey,
+        })
+        .signers([strangerKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("Unauthorized");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+
+  it("Fails if the move success probability is out of range", async () => {
+    try {
+      await program.methods
+        .updateConfig(GAME_ID, { ...defaultParams, moveSuccessProbability: 100 })
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidMoveSuccessProbability");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+
+  it("Fails if the revenue percentages don't sum up to 100", async () => {
+    try {
+      await program.methods
+        .updateConfig(GAME_ID, {
+          ...defaultParams,
+          prizePoolPercentage: 90,
+          adminPercentage: 12,
+        })
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidRevenueSplit");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+
+  it("Fails if the starting hand tiers are not in ascending order", async () => {
+    try {
+      await program.methods
+        .updateConfig(GAME_ID, {
+          ...defaultParams,
+          startingHandTiers: [...defaultParams.startingHandTiers].reverse(),
+        })
+        .accounts({
+          authority: adminKeypair.publicKey,
+        })
+        .signers([adminKeypair])
+        .rpc();
+    } catch (error) {
+      expect(error.error.errorCode.code).to.equal("InvalidStartingHandTiers");
+      return;
+    }
+    expect.fail("Expected an error but none was thrown");
+  });
+});